@@ -0,0 +1,131 @@
+use crate::*;
+use cosmwasm_std::{
+    ensure, to_json_vec, Addr, Binary, Deps, DepsMut, Env, Event, Response, StdResult,
+};
+use error::ContractError;
+use sha2::{Digest, Sha256};
+use state::{Attestation, AGENT_KEYS, USED_NONCES};
+
+/// Registers the secp256k1 public key the calling agent signs attestations with.
+/// Registering a new key rotates it, invalidating attestations signed with the previous one.
+pub fn register_agent_key(
+    deps: DepsMut,
+    caller: Addr,
+    public_key: Binary,
+) -> Result<Response, ContractError> {
+    whitelist::check_agent(deps.as_ref(), &caller)?;
+
+    // accept compressed and uncompressed keys
+    ensure!(
+        public_key.len() == 33 || public_key.len() == 65,
+        ContractError::InvalidAgentKey
+    );
+
+    AGENT_KEYS.save(deps.storage, &caller, &public_key)?;
+
+    Ok(Response::new().add_event(
+        Event::new("agent_key_registered")
+            .add_attribute("agent", caller)
+            .add_attribute("public_key", public_key.to_base64()),
+    ))
+}
+
+/// Removes the calling agent's public key, invalidating all their outstanding attestations.
+pub fn revoke_agent_key(deps: DepsMut, caller: Addr) -> Result<Response, ContractError> {
+    ensure!(
+        AGENT_KEYS.has(deps.storage, &caller),
+        ContractError::AgentKeyNotFound
+    );
+    AGENT_KEYS.remove(deps.storage, &caller);
+
+    Ok(Response::new().add_event(Event::new("agent_key_revoked").add_attribute("agent", caller)))
+}
+
+/// Sets the caller's status from an attestation signed by an agent.
+pub fn self_whitelist(
+    deps: DepsMut,
+    env: Env,
+    caller: Addr,
+    attestation: Attestation,
+    signature: Binary,
+) -> Result<Response, ContractError> {
+    whitelist::check_local_backend(deps.as_ref())?;
+
+    // a blacklisted user cannot lift their status with an attestation issued before they were blacklisted
+    whitelist::check_not_frozen(deps.as_ref(), &caller)?;
+
+    // check that the attestation is for the caller, on this chain and contract
+    ensure!(
+        deps.api.addr_validate(&attestation.user)? == caller,
+        ContractError::InvalidAttestation
+    );
+    ensure!(
+        attestation.chain_id == env.block.chain_id
+            && attestation.contract_address == env.contract.address.as_str(),
+        ContractError::InvalidAttestation
+    );
+    ensure!(
+        env.block.time.seconds() < attestation.expires_at,
+        ContractError::AttestationExpired
+    );
+
+    // check that the attestation is signed by the current key of an agent
+    let agent = deps.api.addr_validate(&attestation.agent)?;
+    ensure!(
+        whitelist::is_agent(deps.as_ref(), &agent)?,
+        ContractError::CallerIsNotAgent
+    );
+    let public_key = AGENT_KEYS
+        .may_load(deps.storage, &agent)?
+        .ok_or(ContractError::AgentKeyNotFound)?;
+    let is_valid = deps
+        .api
+        .secp256k1_verify(&attestation_hash(&attestation)?, &signature, &public_key)
+        .map_err(|_| ContractError::InvalidAttestationSignature)?;
+    ensure!(is_valid, ContractError::InvalidAttestationSignature);
+
+    // consume the nonce to prevent replays
+    ensure!(
+        !USED_NONCES.has(deps.storage, (&agent, attestation.nonce)),
+        ContractError::AttestationNonceUsed
+    );
+    USED_NONCES.save(deps.storage, (&agent, attestation.nonce), &())?;
+
+    if let Some(valid_until) = attestation.valid_until {
+        ensure!(
+            valid_until > env.block.time.seconds(),
+            ContractError::InvalidWhitelistExpiry
+        );
+    }
+    let status_event = whitelist::update_user_status(
+        deps,
+        &env,
+        &agent,
+        &attestation.user,
+        attestation.status,
+        attestation.valid_until,
+        None,
+    )?;
+
+    Ok(Response::new().add_event(status_event).add_event(
+        Event::new("self_whitelisted")
+            .add_attribute("user", caller)
+            .add_attribute("agent", agent)
+            .add_attribute("nonce", attestation.nonce.to_string()),
+    ))
+}
+
+/// Gets the public key an agent signs attestations with.
+pub fn get_agent_key(deps: Deps, agent: &Addr) -> StdResult<Option<Binary>> {
+    AGENT_KEYS.may_load(deps.storage, agent)
+}
+
+/// Checks whether an agent has used an attestation nonce.
+pub fn is_nonce_used(deps: Deps, agent: &Addr, nonce: u64) -> bool {
+    USED_NONCES.has(deps.storage, (agent, nonce))
+}
+
+/// The message agents sign: the SHA-256 hash of the JSON encoded attestation.
+pub fn attestation_hash(attestation: &Attestation) -> StdResult<Vec<u8>> {
+    Ok(Sha256::digest(to_json_vec(attestation)?).to_vec())
+}
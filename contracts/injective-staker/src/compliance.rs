@@ -0,0 +1,165 @@
+use crate::contract::{check_owner, emit_event_msg};
+use crate::*;
+use cosmwasm_std::{
+    ensure, Addr, Deps, DepsMut, Env, Event, MessageInfo, Response, StdResult, Storage, Uint128,
+};
+use cw20_base::contract::{execute_burn, execute_transfer, query_balance};
+use error::ContractError;
+use state::{CLAIMS, PENDING_CLAIMS, RESERVE};
+
+/// Recovers TruINJ from a frozen account under a legal process.
+/// The TruINJ is transferred to `to` if given and burned otherwise. Burning extinguishes the frozen
+/// holder's stake without paying it out to anyone: the underlying INJ stays staked, so its value accrues
+/// to the remaining holders pro rata. A process that requires the value to be handed over uses `to` instead.
+/// If `recover_claims` is set, the pending claims of the frozen account are moved to `to`, or cancelled when
+/// burning. The INJ of a cancelled claim is added to the reserve once its undelegation completes.
+/// If `recover_escrow` is set, the principal the frozen account has locked in escrowed allocations is
+/// recovered like its TruINJ, while the rewards owed to the recipients stay in escrow.
+#[allow(clippy::too_many_arguments)]
+pub fn compliance_recover(
+    mut deps: DepsMut,
+    env: Env,
+    caller: Addr,
+    from: String,
+    to: Option<String>,
+    amount: Uint128,
+    recover_claims: bool,
+    recover_escrow: bool,
+) -> Result<Response, ContractError> {
+    // check that the caller is the owner
+    check_owner(deps.as_ref(), &caller)?;
+
+    let from_addr = deps.api.addr_validate(&from)?;
+    ensure!(
+        whitelist::is_user_blacklisted(deps.as_ref(), &from_addr)?,
+        ContractError::AccountNotFrozen
+    );
+
+    let to_addr = to.map(|to| deps.api.addr_validate(&to)).transpose()?;
+    if let Some(to_addr) = &to_addr {
+        whitelist::check_not_frozen(deps.as_ref(), to_addr)?;
+    }
+
+    let pending_claims = if recover_claims {
+        CLAIMS.query_claims(deps.as_ref(), &from_addr)?.claims
+    } else {
+        vec![]
+    };
+    let (escrowed_shares, escrowed_allocations) = if recover_escrow {
+        escrow::release_principal(deps.branch(), &env, &from_addr)?
+    } else {
+        (Uint128::zero(), 0)
+    };
+    ensure!(
+        !amount.is_zero() || !pending_claims.is_empty() || escrowed_allocations > 0,
+        ContractError::NothingToRecover
+    );
+
+    let mut response = Response::new();
+    let from_info = MessageInfo {
+        sender: from_addr.clone(),
+        funds: vec![],
+    };
+
+    // move or burn the frozen TruINJ
+    if !amount.is_zero() {
+        let cw20_response = match &to_addr {
+            Some(to_addr) => execute_transfer(
+                deps.branch(),
+                env.clone(),
+                from_info,
+                to_addr.to_string(),
+                amount,
+            )?,
+            None => execute_burn(deps.branch(), env.clone(), from_info, amount)?,
+        };
+        response = response.add_message(emit_event_msg(&env, cw20_response.attributes)?);
+    }
+
+    // move or burn the escrowed principal, which is held by the contract
+    if !escrowed_shares.is_zero() {
+        let contract_info = MessageInfo {
+            sender: env.contract.address.clone(),
+            funds: vec![],
+        };
+        let cw20_response = match &to_addr {
+            Some(to_addr) => execute_transfer(
+                deps.branch(),
+                env.clone(),
+                contract_info,
+                to_addr.to_string(),
+                escrowed_shares,
+            )?,
+            None => execute_burn(deps.branch(), env.clone(), contract_info, escrowed_shares)?,
+        };
+        response = response.add_message(emit_event_msg(&env, cw20_response.attributes)?);
+    }
+
+    // move the pending claims, keeping their release times. Cancelled claims are held by the contract
+    // until their undelegation completes, when their INJ is credited to the reserve
+    let claims_amount: Uint128 = pending_claims.iter().map(|claim| claim.amount).sum();
+    if recover_claims {
+        PENDING_CLAIMS.remove(deps.storage, &from_addr);
+        let claims_to = to_addr.as_ref().unwrap_or(&env.contract.address);
+        for claim in &pending_claims {
+            CLAIMS.create_claim(deps.storage, claims_to, claim.amount, claim.release_at)?;
+        }
+    }
+
+    let remaining_balance = query_balance(deps.as_ref(), from_addr.to_string())?.balance;
+
+    Ok(response.add_event(
+        Event::new("compliance_recovered")
+            .add_attribute("operator", caller)
+            .add_attribute("from", from_addr)
+            .add_attribute(
+                "to",
+                to_addr.as_ref().map(Addr::to_string).unwrap_or_default(),
+            )
+            .add_attribute(
+                "action",
+                if to_addr.is_some() {
+                    "transfer"
+                } else {
+                    "burn"
+                },
+            )
+            .add_attribute("amount", amount)
+            .add_attribute("claims_recovered", pending_claims.len().to_string())
+            .add_attribute("claims_amount", claims_amount)
+            .add_attribute(
+                "escrowed_allocations_recovered",
+                escrowed_allocations.to_string(),
+            )
+            .add_attribute("escrowed_shares", escrowed_shares)
+            .add_attribute("remaining_balance", remaining_balance),
+    ))
+}
+
+/// Credits the INJ of cancelled claims whose undelegation has completed to the reserve.
+/// Returns the amount credited.
+pub(crate) fn credit_cancelled_claims(storage: &mut dyn Storage, env: &Env) -> StdResult<Uint128> {
+    if !PENDING_CLAIMS.has(storage, &env.contract.address) {
+        return Ok(Uint128::zero());
+    }
+
+    let matured = CLAIMS.claim_tokens(storage, &env.contract.address, &env.block, None)?;
+    if !matured.is_zero() {
+        let reserve = RESERVE.may_load(storage)?.unwrap_or_default();
+        RESERVE.save(storage, &(reserve + matured))?;
+    }
+    Ok(matured)
+}
+
+/// Returns the INJ of cancelled claims yet to be credited to the reserve,
+/// split into those whose undelegation has completed and those still undelegating.
+pub(crate) fn get_cancelled_claims(deps: Deps, env: &Env) -> StdResult<(Uint128, Uint128)> {
+    let claims = CLAIMS.query_claims(deps, &env.contract.address)?.claims;
+    let (matured, pending): (Vec<_>, Vec<_>) = claims
+        .iter()
+        .partition(|claim| claim.release_at.is_expired(&env.block));
+    Ok((
+        matured.iter().map(|claim| claim.amount).sum(),
+        pending.iter().map(|claim| claim.amount).sum(),
+    ))
+}
@@ -2,6 +2,37 @@ pub const ONE_INJ: u128 = 1_000_000_000_000_000_000;
 pub const FEE_PRECISION: u16 = 10_000;
 pub const SHARE_PRICE_SCALING_FACTOR: u128 = 1_000_000_000_000_000_000;
 pub const INJ: &str = "inj";
+/// The default and maximum number of validators processed by a single CompoundRewards call.
+pub const DEFAULT_COMPOUND_LIMIT: u32 = 30;
+pub const MAX_COMPOUND_LIMIT: u32 = 100;
+/// The maximum timelock delay, in seconds. Currently set to 30 days.
+pub const MAX_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60;
+/// The default and maximum number of parameter changes returned by GetPendingChanges.
+pub const DEFAULT_PENDING_CHANGE_LIMIT: u32 = 10;
+pub const MAX_PENDING_CHANGE_LIMIT: u32 = 30;
+/// The default and maximum number of proposals returned by ListProposals.
+pub const DEFAULT_PROPOSAL_LIMIT: u32 = 10;
+pub const MAX_PROPOSAL_LIMIT: u32 = 30;
+/// The maximum combined basis points of a distributor's percentage allocations.
+pub const MAX_ALLOCATION_BPS: u16 = 10_000;
+/// The default and maximum number of allocations returned by GetAllocationsToRecipient and
+/// scanned by GetPendingRewardsForRecipient.
+pub const DEFAULT_ALLOCATION_QUERY_LIMIT: u32 = 10;
+pub const MAX_ALLOCATION_QUERY_LIMIT: u32 = 30;
+/// The default and maximum number of allocations processed by a single DistributeAll call.
+pub const DEFAULT_DISTRIBUTE_ALL_LIMIT: u32 = 10;
+pub const MAX_DISTRIBUTE_ALL_LIMIT: u32 = 30;
+/// The default and maximum number of entries returned by the whitelist queries.
+pub const DEFAULT_WHITELIST_QUERY_LIMIT: u32 = 10;
+pub const MAX_WHITELIST_QUERY_LIMIT: u32 = 30;
+/// The maximum length of the reason code of a user status change.
+pub const MAX_STATUS_REASON_LENGTH: usize = 64;
+/// The maximum length of the name of a KYC tier.
+pub const MAX_KYC_TIER_NAME_LENGTH: usize = 32;
+/// The maximum number of entries in a BatchUpdateUserStatus call.
+pub const MAX_USER_STATUS_BATCH_SIZE: usize = 100;
+/// The time, in seconds, a pending owner has to claim ownership. Currently set to 7 days.
+pub const PENDING_OWNER_VALIDITY: u64 = 7 * 24 * 60 * 60;
 /// The required time period for unbonding operations, as specified by the network.
 /// Currently set to 21 days.
 pub const UNBONDING_PERIOD: cw_utils::Duration = cw_utils::Duration::Time(21 * 24 * 60 * 60);
@@ -1,8 +1,9 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, to_json_binary, Addr, BankMsg, Binary, Coin, Deps, DepsMut, Env, Event, MessageInfo,
-    Response, StakingMsg, StdResult, Uint128, Uint256, Uint512, WasmMsg,
+    ensure, to_json_binary, to_json_string, Addr, Attribute, BankMsg, Binary, Coin, Deps, DepsMut,
+    Env, Event, MessageInfo, Order, QuerierWrapper, Response, StakingMsg, StdResult, Storage,
+    Uint128, Uint256, Uint512, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw20::{LogoInfo, MarketingInfoResponse};
@@ -11,7 +12,9 @@ use cw20_base::contract::{
     query_marketing_info, query_token_info,
 };
 use cw20_base::state::{MinterData, TokenInfo, MARKETING_INFO, TOKEN_INFO};
-use execute::{set_distribution_fee, set_fee, set_min_deposit};
+use execute::{
+    set_deposit_fee, set_distribution_fee, set_fee, set_min_deposit, set_withdrawal_fee,
+};
 use query::get_total_allocated;
 
 use crate::error::ContractError;
@@ -20,18 +23,46 @@ use crate::msg::{
     InstantiateMsg, MigrateMsg, QueryMsg,
 };
 use crate::state::{
-    allocations, Allocation, GetValueTrait, StakerInfo, ValidatorState, CLAIMS, CONTRACT_REWARDS,
-    DEFAULT_VALIDATOR, IS_PAUSED, OWNER, STAKER_INFO, VALIDATORS,
+    allocations, Allocation, ClaimApproval, FeeRecipient, GetValueTrait, PausableOperation,
+    PauseStatus, Role, StakerInfo, ValidatorState, CLAIMS, CLAIM_APPROVALS, CONTRACT_REWARDS,
+    DEFAULT_VALIDATOR, DELEGATIONS, IS_PAUSED, LEGACY_STAKER_INFO, OWNER, PAUSED_OPERATIONS,
+    PAUSE_STATUS, PENDING_CLAIMS, RESERVE, RESERVE_SUBSIDIES, STAKER_INFO, TOTAL_DELEGATED,
+    VALIDATORS,
+};
+use crate::{
+    attestation, compliance, escrow, multisig, roles, tiers, timelock, whitelist, FEE_PRECISION,
+    INJ, MAX_ALLOCATION_BPS, ONE_INJ, SHARE_PRICE_SCALING_FACTOR, UNBONDING_PERIOD,
 };
-use crate::{whitelist, FEE_PRECISION, INJ, ONE_INJ, SHARE_PRICE_SCALING_FACTOR, UNBONDING_PERIOD};
 
 // version info for contract migrations
 const CONTRACT_NAME: &str = "crates.io:injective-staker";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     cw2::ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // a contract paused before the introduction of pause modes stays fully paused
+    if IS_PAUSED.may_load(deps.storage)?.unwrap_or_default() {
+        PAUSE_STATUS.save(deps.storage, &PauseStatus::HardPaused)?;
+    }
+    IS_PAUSED.remove(deps.storage);
+
+    // send all fees to the treasury of a contract that predates fee recipients
+    migrate_staker_info(deps.storage)?;
+
+    // index the whitelist by status
+    whitelist::rebuild_user_status_index(deps.storage)?;
+
+    // index the allocations by recipient
+    rebuild_allocation_recipient_index(deps.storage)?;
+
+    // fund the rounding reserve from the INJ not owed to anyone
+    migrate_reserve(deps.storage, deps.querier, &env.contract.address)?;
+
+    // fill the delegation ledger from the delegations on chain
+    sync_delegation_ledger(deps.storage, deps.querier, &env.contract.address)?;
+
     Ok(Response::default())
 }
 
@@ -55,12 +86,17 @@ pub fn instantiate(
     }
 
     // ensure we pay a reserve amount into the staker to make up for rounding errors i.e. when unbonding.
-    cw_utils::must_pay(&info, INJ)?;
+    let reserve = cw_utils::must_pay(&info, INJ)?;
 
     let staker_info = StakerInfo {
-        treasury: treasury_addr,
+        fee_recipients: vec![FeeRecipient {
+            address: treasury_addr,
+            bps: FEE_PRECISION,
+        }],
         fee: 0,
         distribution_fee: 0,
+        deposit_fee: 0,
+        withdrawal_fee: 0,
         min_deposit: ONE_INJ,
     };
 
@@ -69,8 +105,10 @@ pub fn instantiate(
 
     DEFAULT_VALIDATOR.save(deps.storage, &default_validator_addr)?;
     OWNER.save(deps.storage, &owner_addr)?;
-    IS_PAUSED.save(deps.storage, &false)?;
+    PAUSE_STATUS.save(deps.storage, &PauseStatus::Unpaused)?;
     CONTRACT_REWARDS.save(deps.storage, &Uint128::zero())?;
+    TOTAL_DELEGATED.save(deps.storage, &Uint128::zero())?;
+    RESERVE.save(deps.storage, &reserve)?;
 
     // store token info
     let data = TokenInfo {
@@ -119,29 +157,58 @@ pub fn execute(
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    // privileged actions must go through a multisig proposal once the multisig is enabled
+    multisig::check_not_multisig_guarded(deps.as_ref(), &msg)?;
+
+    dispatch(deps, env, info, msg)
+}
+
+/// Dispatches an execute message to its handler.
+pub(crate) fn dispatch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::SetFee { new_fee } => set_fee(deps, info.sender, new_fee),
         ExecuteMsg::SetDistributionFee {
             new_distribution_fee,
         } => set_distribution_fee(deps, info.sender, new_distribution_fee),
+        ExecuteMsg::SetDepositFee { new_deposit_fee } => {
+            set_deposit_fee(deps, info.sender, new_deposit_fee)
+        }
+        ExecuteMsg::SetWithdrawalFee { new_withdrawal_fee } => {
+            set_withdrawal_fee(deps, info.sender, new_withdrawal_fee)
+        }
         ExecuteMsg::SetMinimumDeposit { new_min_deposit } => {
             set_min_deposit(deps, info.sender, new_min_deposit)
         }
-        ExecuteMsg::SetTreasury { new_treasury_addr } => {
-            execute::set_treasury(deps, info.sender, &new_treasury_addr)
+        ExecuteMsg::SetFeeRecipients { new_fee_recipients } => {
+            execute::set_fee_recipients(deps, info.sender, new_fee_recipients)
         }
         ExecuteMsg::SetDefaultValidator {
             new_default_validator_addr,
         } => execute::set_default_validator(deps, info.sender, &new_default_validator_addr),
         ExecuteMsg::Transfer { recipient, amount } => {
+            check_not_paused(deps.as_ref())?;
+            check_operation_not_paused(deps.as_ref(), PausableOperation::Transfer)?;
+            whitelist::check_not_frozen(deps.as_ref(), &info.sender)?;
+            whitelist::check_not_frozen(deps.as_ref(), &deps.api.addr_validate(&recipient)?)?;
             Ok(execute_transfer(deps, env, info, recipient, amount)?)
         }
         ExecuteMsg::Send {
             contract,
             amount,
             msg,
-        } => Ok(execute_send(deps, env, info, contract, amount, msg)?),
+        } => {
+            check_not_paused(deps.as_ref())?;
+            check_operation_not_paused(deps.as_ref(), PausableOperation::Transfer)?;
+            whitelist::check_not_frozen(deps.as_ref(), &info.sender)?;
+            whitelist::check_not_frozen(deps.as_ref(), &deps.api.addr_validate(&contract)?)?;
+            Ok(execute_send(deps, env, info, contract, amount, msg)?)
+        }
         ExecuteMsg::Stake {} => execute::stake(deps, env, info),
         ExecuteMsg::StakeToSpecificValidator { validator_addr } => {
             execute::stake_to_specific_validator(deps, env, info, validator_addr)
@@ -167,9 +234,13 @@ pub fn execute(
         ),
         ExecuteMsg::Claim {} => execute::claim(deps, env, info.sender),
         ExecuteMsg::SetPendingOwner { new_owner } => {
-            execute::set_pending_owner(deps, info.sender, &new_owner)
+            execute::set_pending_owner(deps, env, info.sender, &new_owner)
+        }
+        ExecuteMsg::CancelPendingOwner {} => execute::cancel_pending_owner(deps, info.sender),
+        ExecuteMsg::ClaimOwnership {} => execute::claim_ownership(deps, env, info.sender),
+        ExecuteMsg::RenounceOwnership { confirm } => {
+            execute::renounce_ownership(deps, env, info.sender, confirm)
         }
-        ExecuteMsg::ClaimOwnership {} => execute::claim_ownership(deps, info.sender),
         ExecuteMsg::AddValidator { validator } => {
             execute::add_validator(deps, info.sender, validator)
         }
@@ -180,20 +251,116 @@ pub fn execute(
             execute::enable_validator(deps, info.sender, validator)
         }
         ExecuteMsg::Pause => execute::pause(deps, info.sender),
+        ExecuteMsg::SoftPause => execute::soft_pause(deps, info.sender),
+        ExecuteMsg::PauseOperations { operations } => {
+            execute::pause_operations(deps, info.sender, operations)
+        }
+        ExecuteMsg::UnpauseOperations { operations } => {
+            execute::unpause_operations(deps, info.sender, operations)
+        }
         ExecuteMsg::Unpause => execute::unpause(deps, info.sender),
+        ExecuteMsg::SetMultisigConfig {
+            signers,
+            threshold,
+            proposal_duration,
+        } => {
+            multisig::set_multisig_config(deps, info.sender, signers, threshold, proposal_duration)
+        }
+        ExecuteMsg::Propose { msg } => multisig::propose(deps, env, info.sender, *msg),
+        ExecuteMsg::ApproveProposal { id } => {
+            multisig::approve_proposal(deps, env, info.sender, id)
+        }
+        ExecuteMsg::ExecuteProposal { id } => multisig::execute_proposal(deps, env, id),
+        ExecuteMsg::ScheduleParameterChange { change, eta } => {
+            timelock::schedule_parameter_change(deps, env, info.sender, change, eta)
+        }
+        ExecuteMsg::ExecuteParameterChange { id } => {
+            timelock::execute_parameter_change(deps, env, id)
+        }
+        ExecuteMsg::CancelParameterChange { id } => {
+            timelock::cancel_parameter_change(deps, env, info.sender, id)
+        }
 
+        ExecuteMsg::GrantRole { role, account } => {
+            roles::grant_role(deps, info.sender, role, &account)
+        }
+        ExecuteMsg::RevokeRole { role, account } => {
+            roles::revoke_role(deps, info.sender, role, &account)
+        }
         ExecuteMsg::AddAgent { agent } => whitelist::add_agent(deps, info.sender, &agent),
         ExecuteMsg::RemoveAgent { agent } => whitelist::remove_agent(deps, info.sender, &agent),
-        ExecuteMsg::AddUserToWhitelist { user } => {
-            whitelist::add_user_to_whitelist(deps, info.sender, &user)
+        ExecuteMsg::AddUserToWhitelist {
+            user,
+            valid_until,
+            reason,
+        } => whitelist::add_user_to_whitelist(deps, env, info.sender, &user, valid_until, reason),
+        ExecuteMsg::AddUserToBlacklist { user, reason } => {
+            whitelist::add_user_to_blacklist(deps, env, info.sender, &user, reason)
+        }
+        ExecuteMsg::ClearUserStatus { user, reason } => {
+            whitelist::clear_user_status(deps, env, info.sender, &user, reason)
+        }
+        ExecuteMsg::RegisterAgentKey { public_key } => {
+            attestation::register_agent_key(deps, info.sender, public_key)
+        }
+        ExecuteMsg::RevokeAgentKey {} => attestation::revoke_agent_key(deps, info.sender),
+        ExecuteMsg::SelfWhitelist {
+            attestation,
+            signature,
+        } => attestation::self_whitelist(deps, env, info.sender, attestation, signature),
+        ExecuteMsg::SetKycTier { name, tier } => tiers::set_kyc_tier(deps, info.sender, name, tier),
+        ExecuteMsg::SetUserTier { user, tier } => {
+            tiers::set_user_tier(deps, info.sender, &user, tier)
+        }
+        ExecuteMsg::ComplianceRecover {
+            from,
+            to,
+            amount,
+            recover_claims,
+            recover_escrow,
+        } => compliance::compliance_recover(
+            deps,
+            env,
+            info.sender,
+            from,
+            to,
+            amount,
+            recover_claims,
+            recover_escrow,
+        ),
+        ExecuteMsg::SetWhitelistBackend { backend } => {
+            whitelist::set_whitelist_backend(deps, info.sender, backend)
+        }
+        ExecuteMsg::BatchUpdateUserStatus {
+            entries,
+            skip_invalid,
+        } => whitelist::batch_update_user_status(deps, env, info.sender, entries, skip_invalid),
+        ExecuteMsg::CompoundRewards {
+            start_after,
+            limit,
+            min_reward,
+        } => execute::compound_rewards(deps, env, info.sender, start_after, limit, min_reward),
+        ExecuteMsg::TopUpReserve {} => execute::top_up_reserve(deps, env, info),
+        ExecuteMsg::WithdrawReserve { amount } => {
+            execute::withdraw_reserve(deps, env, info.sender, amount)
         }
-        ExecuteMsg::AddUserToBlacklist { user } => {
-            whitelist::add_user_to_blacklist(deps, info.sender, &user)
+        ExecuteMsg::SetReserveLowWaterMark { low_water_mark } => {
+            execute::set_reserve_low_water_mark(deps, info.sender, low_water_mark)
         }
-        ExecuteMsg::ClearUserStatus { user } => {
-            whitelist::clear_user_status(deps, info.sender, &user)
+        ExecuteMsg::ReconcileDelegations {} => {
+            execute::reconcile_delegations(deps, env, info.sender)
         }
-        ExecuteMsg::CompoundRewards => execute::compound_rewards(deps, env),
+        ExecuteMsg::SetCompoundConfig {
+            keeper_bounty,
+            min_compound_interval,
+            keepers_only,
+        } => execute::set_compound_config(
+            deps,
+            info.sender,
+            keeper_bounty,
+            min_compound_interval,
+            keepers_only,
+        ),
         ExecuteMsg::Restake {
             amount,
             validator_addr,
@@ -203,7 +370,42 @@ pub fn execute(
             execute::allocate(deps, env, info.sender, &recipient, amount)
         }
         ExecuteMsg::Deallocate { recipient, amount } => {
-            execute::deallocate(deps, info.sender, &recipient, amount)
+            execute::deallocate(deps, env, info.sender, &recipient, amount)
+        }
+        ExecuteMsg::ApproveRecipientClaims {
+            recipient,
+            max_per_period,
+            period,
+        } => execute::approve_recipient_claims(
+            deps,
+            env,
+            info.sender,
+            &recipient,
+            max_per_period,
+            period,
+        ),
+        ExecuteMsg::RevokeRecipientClaims { recipient } => {
+            execute::revoke_recipient_claims(deps, info.sender, &recipient)
+        }
+        ExecuteMsg::ClaimAllocationRewards { distributor } => {
+            execute::claim_allocation_rewards(deps, env, info.sender, &distributor)
+        }
+        ExecuteMsg::AllocatePercentage { recipient, bps } => {
+            execute::allocate_percentage(deps, env, info.sender, &recipient, bps)
+        }
+        ExecuteMsg::DistributeAll {
+            in_inj,
+            start_after,
+            limit,
+        } => execute::distribute_all(deps, env, info, in_inj, start_after, limit),
+        ExecuteMsg::AllocateEscrowed { recipient, shares } => {
+            escrow::allocate_escrowed(deps, env, info.sender, &recipient, shares)
+        }
+        ExecuteMsg::DeallocateEscrowed { recipient, amount } => {
+            escrow::deallocate_escrowed(deps, env, info.sender, &recipient, amount)
+        }
+        ExecuteMsg::ClaimEscrowedRewards { allocator } => {
+            escrow::claim_escrowed_rewards(deps, env, info.sender, &allocator)
         }
         ExecuteMsg::DistributeRewards { recipient, in_inj } => {
             execute::distribute_rewards(deps, env, info, &recipient, in_inj)
@@ -232,13 +434,37 @@ pub mod execute {
 
     use crate::FEE_PRECISION;
 
-    use crate::state::{Allocation, IS_PAUSED, PENDING_OWNER};
+    use crate::state::{
+        Allocation, CompoundConfig, TimelockedParameter, COMPOUND_CONFIG, COMPOUND_CURSOR,
+        COMPOUND_SHARE_PRICE, LAST_COMPOUND_TIME, PENDING_OWNER, PENDING_OWNER_EXPIRES_AT,
+        RESERVE_LOW_WATER_MARK, TOTAL_DELEGATED,
+    };
+    use crate::{
+        DEFAULT_COMPOUND_LIMIT, DEFAULT_DISTRIBUTE_ALL_LIMIT, MAX_COMPOUND_LIMIT,
+        MAX_DISTRIBUTE_ALL_LIMIT, PENDING_OWNER_VALIDITY,
+    };
+    use cosmwasm_std::Order;
+    use cw_storage_plus::Bound;
 
     /// Sets the treasury fee charged on rewards.
     pub fn set_fee(deps: DepsMut, sender: Addr, new_fee: u16) -> Result<Response, ContractError> {
-        check_owner(deps.as_ref(), &sender)?;
+        roles::check_role(deps.as_ref(), Role::FeeManager, &sender)?;
+        timelock::check_not_timelocked(deps.as_ref(), TimelockedParameter::Fee)?;
+
+        update_fee(deps, new_fee)
+    }
 
+    /// Updates the treasury fee charged on rewards. Does not check the caller.
+    pub(crate) fn update_fee(deps: DepsMut, new_fee: u16) -> Result<Response, ContractError> {
         ensure!(new_fee < FEE_PRECISION, ContractError::FeeTooLarge);
+        let keeper_bounty = COMPOUND_CONFIG
+            .may_load(deps.storage)?
+            .unwrap_or_default()
+            .keeper_bounty;
+        ensure!(
+            keeper_bounty <= new_fee,
+            ContractError::KeeperBountyAboveFee
+        );
 
         let old_fee = STAKER_INFO.load(deps.storage)?.fee;
 
@@ -260,8 +486,17 @@ pub mod execute {
         sender: Addr,
         new_distribution_fee: u16,
     ) -> Result<Response, ContractError> {
-        check_owner(deps.as_ref(), &sender)?;
+        roles::check_role(deps.as_ref(), Role::FeeManager, &sender)?;
+        timelock::check_not_timelocked(deps.as_ref(), TimelockedParameter::DistributionFee)?;
 
+        update_distribution_fee(deps, new_distribution_fee)
+    }
+
+    /// Updates the treasury fee charged on rewards distribution. Does not check the caller.
+    pub(crate) fn update_distribution_fee(
+        deps: DepsMut,
+        new_distribution_fee: u16,
+    ) -> Result<Response, ContractError> {
         ensure!(
             new_distribution_fee < FEE_PRECISION,
             ContractError::FeeTooLarge
@@ -281,13 +516,82 @@ pub mod execute {
         ))
     }
 
+    /// Sets the treasury fee charged on deposits.
+    pub fn set_deposit_fee(
+        deps: DepsMut,
+        sender: Addr,
+        new_deposit_fee: u16,
+    ) -> Result<Response, ContractError> {
+        roles::check_role(deps.as_ref(), Role::FeeManager, &sender)?;
+        timelock::check_not_timelocked(deps.as_ref(), TimelockedParameter::DepositFee)?;
+
+        update_deposit_fee(deps, new_deposit_fee)
+    }
+
+    /// Updates the treasury fee charged on deposits. Does not check the caller.
+    pub(crate) fn update_deposit_fee(
+        deps: DepsMut,
+        new_deposit_fee: u16,
+    ) -> Result<Response, ContractError> {
+        ensure!(new_deposit_fee < FEE_PRECISION, ContractError::FeeTooLarge);
+
+        let old_deposit_fee = STAKER_INFO.load(deps.storage)?.deposit_fee;
+
+        STAKER_INFO.update(deps.storage, |mut state| -> Result<_, ContractError> {
+            state.deposit_fee = new_deposit_fee;
+            Ok(state)
+        })?;
+
+        Ok(Response::new().add_event(
+            Event::new("set_deposit_fee")
+                .add_attribute("old_deposit_fee", old_deposit_fee.to_string())
+                .add_attribute("new_deposit_fee", new_deposit_fee.to_string()),
+        ))
+    }
+
+    /// Sets the treasury fee charged on withdrawals.
+    pub fn set_withdrawal_fee(
+        deps: DepsMut,
+        sender: Addr,
+        new_withdrawal_fee: u16,
+    ) -> Result<Response, ContractError> {
+        roles::check_role(deps.as_ref(), Role::FeeManager, &sender)?;
+        timelock::check_not_timelocked(deps.as_ref(), TimelockedParameter::WithdrawalFee)?;
+
+        update_withdrawal_fee(deps, new_withdrawal_fee)
+    }
+
+    /// Updates the treasury fee charged on withdrawals. Does not check the caller.
+    pub(crate) fn update_withdrawal_fee(
+        deps: DepsMut,
+        new_withdrawal_fee: u16,
+    ) -> Result<Response, ContractError> {
+        ensure!(
+            new_withdrawal_fee < FEE_PRECISION,
+            ContractError::FeeTooLarge
+        );
+
+        let old_withdrawal_fee = STAKER_INFO.load(deps.storage)?.withdrawal_fee;
+
+        STAKER_INFO.update(deps.storage, |mut state| -> Result<_, ContractError> {
+            state.withdrawal_fee = new_withdrawal_fee;
+            Ok(state)
+        })?;
+
+        Ok(Response::new().add_event(
+            Event::new("set_withdrawal_fee")
+                .add_attribute("old_withdrawal_fee", old_withdrawal_fee.to_string())
+                .add_attribute("new_withdrawal_fee", new_withdrawal_fee.to_string()),
+        ))
+    }
+
     /// Sets the minimum INJ amount a user can deposit.
     pub fn set_min_deposit(
         deps: DepsMut,
         sender: Addr,
         new_min_deposit: Uint128,
     ) -> Result<Response, ContractError> {
-        check_owner(deps.as_ref(), &sender)?;
+        roles::check_role(deps.as_ref(), Role::FeeManager, &sender)?;
 
         ensure!(
             new_min_deposit.u128() >= ONE_INJ,
@@ -308,27 +612,36 @@ pub mod execute {
         ))
     }
 
-    /// Sets the treasury address.
-    pub fn set_treasury(
+    /// Sets the recipients of the treasury fees.
+    pub fn set_fee_recipients(
         deps: DepsMut,
         sender: Addr,
-        new_treasury: &String,
+        new_fee_recipients: Vec<(String, u16)>,
     ) -> Result<Response, ContractError> {
-        check_owner(deps.as_ref(), &sender)?;
+        roles::check_role(deps.as_ref(), Role::FeeManager, &sender)?;
+        timelock::check_not_timelocked(deps.as_ref(), TimelockedParameter::FeeRecipients)?;
+
+        update_fee_recipients(deps, new_fee_recipients)
+    }
 
-        let treasury_addr = deps.api.addr_validate(new_treasury)?;
+    /// Updates the recipients of the treasury fees. Does not check the caller.
+    pub(crate) fn update_fee_recipients(
+        deps: DepsMut,
+        new_fee_recipients: Vec<(String, u16)>,
+    ) -> Result<Response, ContractError> {
+        let fee_recipients = validate_fee_recipients(deps.as_ref(), &new_fee_recipients)?;
 
-        let old_treasury_addr = STAKER_INFO.load(deps.storage)?.treasury;
+        let old_fee_recipients = STAKER_INFO.load(deps.storage)?.fee_recipients;
 
         STAKER_INFO.update(deps.storage, |mut state| -> Result<_, ContractError> {
-            state.treasury = treasury_addr;
+            state.fee_recipients = fee_recipients.clone();
             Ok(state)
         })?;
 
         Ok(Response::new().add_event(
-            Event::new("set_treasury")
-                .add_attribute("new_treasury_addr", new_treasury)
-                .add_attribute("old_treasury_addr", old_treasury_addr),
+            Event::new("set_fee_recipients")
+                .add_attribute("new_fee_recipients", to_json_string(&fee_recipients)?)
+                .add_attribute("old_fee_recipients", to_json_string(&old_fee_recipients)?),
         ))
     }
 
@@ -338,7 +651,17 @@ pub mod execute {
         sender: Addr,
         new_default_validator_addr: &String,
     ) -> Result<Response, ContractError> {
-        check_owner(deps.as_ref(), &sender)?;
+        roles::check_role(deps.as_ref(), Role::ValidatorManager, &sender)?;
+        timelock::check_not_timelocked(deps.as_ref(), TimelockedParameter::DefaultValidator)?;
+
+        update_default_validator(deps, new_default_validator_addr)
+    }
+
+    /// Updates a given validator as the new default validator. Does not check the caller.
+    pub(crate) fn update_default_validator(
+        deps: DepsMut,
+        new_default_validator_addr: &String,
+    ) -> Result<Response, ContractError> {
         check_validator(deps.as_ref(), new_default_validator_addr)?;
 
         let old_default_validator_addr = DEFAULT_VALIDATOR.load(deps.storage)?;
@@ -352,10 +675,141 @@ pub mod execute {
         ))
     }
 
+    /// Tops up the rounding reserve with the attached INJ.
+    pub fn top_up_reserve(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        check_owner(deps.as_ref(), &info.sender)?;
+        let amount = cw_utils::must_pay(&info, INJ)?;
+        compliance::credit_cancelled_claims(deps.storage, &env)?;
+
+        let reserve = RESERVE.may_load(deps.storage)?.unwrap_or_default() + amount;
+        RESERVE.save(deps.storage, &reserve)?;
+
+        Ok(Response::new().add_event(
+            Event::new("reserve_topped_up")
+                .add_attribute("amount", amount)
+                .add_attribute("reserve_balance", reserve),
+        ))
+    }
+
+    /// Withdraws INJ from the rounding reserve to the owner.
+    pub fn withdraw_reserve(
+        deps: DepsMut,
+        env: Env,
+        sender: Addr,
+        amount: Uint128,
+    ) -> Result<Response, ContractError> {
+        check_owner(deps.as_ref(), &sender)?;
+        compliance::credit_cancelled_claims(deps.storage, &env)?;
+
+        let reserve = RESERVE.may_load(deps.storage)?.unwrap_or_default();
+        ensure!(amount <= reserve, ContractError::InsufficientReserve);
+        let contract_balance = deps
+            .querier
+            .query_balance(&env.contract.address, INJ)?
+            .amount;
+        ensure!(
+            amount <= contract_balance,
+            ContractError::InsufficientStakerFunds
+        );
+
+        let reserve = reserve - amount;
+        RESERVE.save(deps.storage, &reserve)?;
+
+        Ok(Response::new()
+            .add_message(BankMsg::Send {
+                to_address: sender.to_string(),
+                amount: vec![Coin {
+                    denom: INJ.to_string(),
+                    amount,
+                }],
+            })
+            .add_event(
+                Event::new("reserve_withdrawn")
+                    .add_attribute("amount", amount)
+                    .add_attribute("reserve_balance", reserve),
+            ))
+    }
+
+    /// Sets the reserve balance below which the reserve is reported as low.
+    pub fn set_reserve_low_water_mark(
+        deps: DepsMut,
+        sender: Addr,
+        low_water_mark: Uint128,
+    ) -> Result<Response, ContractError> {
+        check_owner(deps.as_ref(), &sender)?;
+
+        let old_low_water_mark = RESERVE_LOW_WATER_MARK
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+        RESERVE_LOW_WATER_MARK.save(deps.storage, &low_water_mark)?;
+
+        Ok(Response::new().add_event(
+            Event::new("set_reserve_low_water_mark")
+                .add_attribute("old_low_water_mark", old_low_water_mark)
+                .add_attribute("new_low_water_mark", low_water_mark),
+        ))
+    }
+
+    /// Re-syncs the delegation ledger with the delegations queried from the chain and reports any drift.
+    pub fn reconcile_delegations(
+        deps: DepsMut,
+        env: Env,
+        caller: Addr,
+    ) -> Result<Response, ContractError> {
+        roles::check_role(deps.as_ref(), Role::Keeper, &caller)?;
+
+        let old_total_delegated = TOTAL_DELEGATED.may_load(deps.storage)?.unwrap_or_default();
+        let (new_total_delegated, drift_events) =
+            sync_delegation_ledger(deps.storage, deps.querier, &env.contract.address)?;
+
+        Ok(Response::new().add_events(drift_events).add_event(
+            Event::new("delegations_reconciled")
+                .add_attribute("old_total_delegated", old_total_delegated)
+                .add_attribute("new_total_delegated", new_total_delegated),
+        ))
+    }
+
+    /// Sets the keeper bounty, the minimum interval between compounds and whether compounding is restricted to keepers.
+    pub fn set_compound_config(
+        deps: DepsMut,
+        sender: Addr,
+        keeper_bounty: u16,
+        min_compound_interval: u64,
+        keepers_only: bool,
+    ) -> Result<Response, ContractError> {
+        roles::check_role(deps.as_ref(), Role::FeeManager, &sender)?;
+        ensure!(keeper_bounty < FEE_PRECISION, ContractError::FeeTooLarge);
+        ensure!(
+            keeper_bounty <= STAKER_INFO.load(deps.storage)?.fee,
+            ContractError::KeeperBountyAboveFee
+        );
+
+        COMPOUND_CONFIG.save(
+            deps.storage,
+            &CompoundConfig {
+                keeper_bounty,
+                min_compound_interval,
+                keepers_only,
+            },
+        )?;
+
+        Ok(Response::new().add_event(
+            Event::new("set_compound_config")
+                .add_attribute("keeper_bounty", keeper_bounty.to_string())
+                .add_attribute("min_compound_interval", min_compound_interval.to_string())
+                .add_attribute("keepers_only", keepers_only.to_string()),
+        ))
+    }
+
     /// Stakes INJ to the default validator.
     pub fn stake(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
         check_not_paused(deps.as_ref())?;
-        whitelist::check_whitelisted(deps.as_ref(), &info.sender)?;
+        check_operation_not_paused(deps.as_ref(), PausableOperation::Stake)?;
+        whitelist::check_whitelisted(deps.as_ref(), &env, &info.sender)?;
 
         let validator_addr = DEFAULT_VALIDATOR.load(deps.storage)?;
 
@@ -371,21 +825,24 @@ pub mod execute {
         validator_addr: String,
     ) -> Result<Response, ContractError> {
         check_not_paused(deps.as_ref())?;
-        whitelist::check_whitelisted(deps.as_ref(), &info.sender)?;
+        check_operation_not_paused(deps.as_ref(), PausableOperation::StakeToSpecificValidator)?;
+        whitelist::check_whitelisted(deps.as_ref(), &env, &info.sender)?;
 
         let stake_res = internal_stake(deps, env, info, validator_addr)?;
         Ok(stake_res)
     }
 
     /// Unstakes a certain amount of INJ from the default validator.
+    /// Remains available while the contract is soft paused, as an emergency exit.
     pub fn unstake(
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
         amount: u128,
     ) -> Result<Response, ContractError> {
-        check_not_paused(deps.as_ref())?;
-        whitelist::check_whitelisted(deps.as_ref(), &info.sender)?;
+        check_not_hard_paused(deps.as_ref())?;
+        check_operation_not_paused(deps.as_ref(), PausableOperation::Unstake)?;
+        whitelist::check_whitelisted(deps.as_ref(), &env, &info.sender)?;
 
         let validator_addr = DEFAULT_VALIDATOR.load(deps.storage)?;
         let unstake_res = internal_unstake(deps, env, info, validator_addr, amount)?;
@@ -401,7 +858,11 @@ pub mod execute {
         amount: u128,
     ) -> Result<Response, ContractError> {
         check_not_paused(deps.as_ref())?;
-        whitelist::check_whitelisted(deps.as_ref(), &info.sender)?;
+        check_operation_not_paused(
+            deps.as_ref(),
+            PausableOperation::UnstakeFromSpecificValidator,
+        )?;
+        whitelist::check_whitelisted(deps.as_ref(), &env, &info.sender)?;
 
         ensure!(
             VALIDATORS.has(deps.storage, &validator_addr),
@@ -420,7 +881,8 @@ pub mod execute {
         dst_validator_addr: String,
         assets: u128,
     ) -> Result<Response, ContractError> {
-        check_owner(deps.as_ref(), &sender)?;
+        roles::check_role(deps.as_ref(), Role::ValidatorManager, &sender)?;
+        check_operation_not_paused(deps.as_ref(), PausableOperation::Redelegate)?;
 
         let redelegate_res = internal_redelegate(
             deps,
@@ -432,24 +894,76 @@ pub mod execute {
         Ok(redelegate_res)
     }
 
-    /// Sets a pending owner. The pending owner has no contract privileges.
+    /// Sets a pending owner. The pending owner has no contract privileges
+    /// and must claim ownership before the pending owner expires.
     pub fn set_pending_owner(
         deps: DepsMut,
+        env: Env,
         sender: Addr,
         new_owner: &String,
     ) -> Result<Response, ContractError> {
         check_owner(deps.as_ref(), &sender)?;
         let new_owner_addr = deps.api.addr_validate(new_owner)?;
 
+        let expires_at = env.block.time.seconds() + PENDING_OWNER_VALIDITY;
         PENDING_OWNER.save(deps.storage, &new_owner_addr)?;
+        PENDING_OWNER_EXPIRES_AT.save(deps.storage, &expires_at)?;
 
         Ok(Response::new().add_event(
             Event::new("set_pending_owner")
                 .add_attribute("current_owner", sender)
-                .add_attribute("pending_owner", new_owner),
+                .add_attribute("pending_owner", new_owner)
+                .add_attribute("expires_at", expires_at.to_string()),
+        ))
+    }
+
+    /// Removes the pending owner.
+    pub fn cancel_pending_owner(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+        check_owner(deps.as_ref(), &sender)?;
+
+        let pending_owner = PENDING_OWNER
+            .may_load(deps.storage)?
+            .ok_or(ContractError::NoPendingOwnerSet)?;
+
+        PENDING_OWNER.remove(deps.storage);
+        PENDING_OWNER_EXPIRES_AT.remove(deps.storage);
+
+        Ok(Response::new().add_event(
+            Event::new("cancelled_pending_owner")
+                .add_attribute("current_owner", sender)
+                .add_attribute("pending_owner", pending_owner),
         ))
     }
 
+    /// Permanently gives up ownership of the contract, leaving it without an owner.
+    /// The caller must confirm by passing the address of the contract.
+    pub fn renounce_ownership(
+        deps: DepsMut,
+        env: Env,
+        sender: Addr,
+        confirm: String,
+    ) -> Result<Response, ContractError> {
+        check_owner(deps.as_ref(), &sender)?;
+        ensure!(
+            confirm == env.contract.address.as_str(),
+            ContractError::InvalidRenounceConfirmation
+        );
+        // proposals are executed with the authority of the owner, so the multisig must be disabled first
+        ensure!(
+            multisig::get_multisig_config(deps.as_ref())?
+                .signers
+                .is_empty(),
+            ContractError::MultisigEnabled
+        );
+
+        OWNER.remove(deps.storage);
+        PENDING_OWNER.remove(deps.storage);
+        PENDING_OWNER_EXPIRES_AT.remove(deps.storage);
+
+        Ok(Response::new()
+            .add_event(Event::new("renounced_ownership").add_attribute("old_owner", sender)))
+    }
+
     /// Allocates INJ staking rewards to the recipient.
     pub fn allocate(
         deps: DepsMut,
@@ -459,7 +973,9 @@ pub mod execute {
         amount: Uint128,
     ) -> Result<Response, ContractError> {
         check_not_paused(deps.as_ref())?;
-        whitelist::check_whitelisted(deps.as_ref(), &sender)?;
+        check_operation_not_paused(deps.as_ref(), PausableOperation::Allocate)?;
+        whitelist::check_whitelisted(deps.as_ref(), &env, &sender)?;
+        tiers::check_can_allocate(deps.as_ref(), &sender)?;
         let recipient_addr = deps.api.addr_validate(recipient)?;
 
         ensure!(recipient_addr != sender, ContractError::InvalidRecipient {});
@@ -481,9 +997,14 @@ pub mod execute {
                             inj_amount: amount,
                             share_price_num: share_price_response.numerator,
                             share_price_denom: share_price_response.denominator,
+                            bps: None,
                         })
                     },
                     |allocation| {
+                        ensure!(
+                            allocation.bps.is_none(),
+                            ContractError::AllocationModeMismatch
+                        );
                         // if the user has an allocation to the recipient, update it to reflect the new amount and share price
                         let updated_allocation = calculate_updated_allocation(
                             &allocation,
@@ -497,7 +1018,8 @@ pub mod execute {
             },
         )?;
 
-        let total_allocated_response = get_total_allocated(deps.as_ref(), sender.clone())?;
+        let total_allocated_response =
+            get_total_allocated(deps.as_ref(), sender.clone(), &share_price_response)?;
 
         Ok(Response::new().add_event(
             Event::new("allocated")
@@ -525,18 +1047,24 @@ pub mod execute {
     /// Deallocates INJ staking rewards from the recipient.
     pub fn deallocate(
         deps: DepsMut,
+        env: Env,
         sender: Addr,
         recipient: &String,
         amount: Uint128,
     ) -> Result<Response, ContractError> {
         check_not_paused(deps.as_ref())?;
-        whitelist::check_whitelisted(deps.as_ref(), &sender)?;
+        check_operation_not_paused(deps.as_ref(), PausableOperation::Deallocate)?;
+        whitelist::check_whitelisted(deps.as_ref(), &env, &sender)?;
 
         let recipient_addr = deps.api.addr_validate(recipient)?;
 
         let mut allocation = allocations()
             .load(deps.storage, (sender.clone(), recipient_addr.clone()))
             .map_err(|_| ContractError::NoAllocationToRecipient)?;
+        ensure!(
+            allocation.bps.is_none(),
+            ContractError::AllocationModeMismatch
+        );
 
         ensure!(
             allocation.inj_amount >= amount,
@@ -568,7 +1096,9 @@ pub mod execute {
             )?;
         }
 
-        let total_allocated_response = get_total_allocated(deps.as_ref(), sender.clone())?;
+        let share_price = get_share_price(deps.as_ref(), &env.contract.address);
+        let total_allocated_response =
+            get_total_allocated(deps.as_ref(), sender.clone(), &share_price)?;
 
         Ok(Response::new().add_event(
             Event::new("deallocated")
@@ -593,28 +1123,123 @@ pub mod execute {
         ))
     }
 
-    /// Distribute allocation rewards from the caller to the specified recipient.
-    pub fn distribute_rewards(
-        mut deps: DepsMut,
+    /// Allocates a share of the rewards on the sender's TruINJ balance to the recipient, in basis points.
+    /// Updating the share of an existing percentage allocation keeps its share price, so rewards not yet
+    /// distributed are owed at the new share. A share of zero removes the allocation.
+    pub fn allocate_percentage(
+        deps: DepsMut,
         env: Env,
-        info: MessageInfo,
-        recipient: &str,
-        in_inj: bool,
+        sender: Addr,
+        recipient: &String,
+        bps: u16,
     ) -> Result<Response, ContractError> {
         check_not_paused(deps.as_ref())?;
-        let distributor = info.sender.clone();
-        whitelist::check_whitelisted(deps.as_ref(), &distributor)?;
-
+        check_operation_not_paused(deps.as_ref(), PausableOperation::Allocate)?;
+        whitelist::check_whitelisted(deps.as_ref(), &env, &sender)?;
+        tiers::check_can_allocate(deps.as_ref(), &sender)?;
         let recipient_addr = deps.api.addr_validate(recipient)?;
 
+        ensure!(recipient_addr != sender, ContractError::InvalidRecipient {});
+
+        let key = (sender.clone(), recipient_addr.clone());
+        let existing = allocations().may_load(deps.storage, key.clone())?;
+        if let Some(existing) = &existing {
+            ensure!(
+                existing.bps.is_some(),
+                ContractError::AllocationModeMismatch
+            );
+        }
+
+        // the combined share of all the sender's percentage allocations must not exceed 100%
+        let other_bps = allocations()
+            .idx
+            .allocator
+            .prefix(sender.clone())
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, allocation)| allocation))
+            .collect::<StdResult<Vec<_>>>()?
+            .into_iter()
+            .filter(|allocation| allocation.recipient != recipient_addr)
+            .map(|allocation| u32::from(allocation.bps.unwrap_or_default()))
+            .sum::<u32>();
+        let total_bps = other_bps + u32::from(bps);
         ensure!(
-            !allocations()
-                .prefix(distributor.clone())
-                .is_empty(deps.storage),
-            ContractError::NoAllocations
+            total_bps <= u32::from(MAX_ALLOCATION_BPS),
+            ContractError::ExcessiveAllocationPercentage {
+                max: MAX_ALLOCATION_BPS
+            }
         );
 
-        // get the allocation to the recipient
+        if bps == 0 {
+            ensure!(existing.is_some(), ContractError::NoAllocationToRecipient);
+            allocations().remove(deps.storage, key)?;
+        } else {
+            let share_price_response = get_share_price(deps.as_ref(), &env.contract.address);
+            let allocation = match existing {
+                // scale the recorded INJ value to the new share
+                Some(existing) => Allocation {
+                    inj_amount: existing
+                        .inj_amount
+                        .multiply_ratio(bps, existing.bps.unwrap_or(bps)),
+                    bps: Some(bps),
+                    ..existing
+                },
+                // record the INJ value of the allocated share of the sender's balance
+                None => {
+                    let balance = query_balance(deps.as_ref(), sender.to_string())?.balance;
+                    let inj_amount = convert_to_assets(
+                        balance.multiply_ratio(bps, FEE_PRECISION).u128(),
+                        share_price_response.numerator,
+                        share_price_response.denominator,
+                        false,
+                    )?;
+                    Allocation {
+                        allocator: sender.clone(),
+                        recipient: recipient_addr.clone(),
+                        inj_amount: inj_amount.into(),
+                        share_price_num: share_price_response.numerator,
+                        share_price_denom: share_price_response.denominator,
+                        bps: Some(bps),
+                    }
+                }
+            };
+            allocations().save(deps.storage, key, &allocation)?;
+        }
+
+        Ok(Response::new().add_event(
+            Event::new("allocated_percentage")
+                .add_attribute("user", sender)
+                .add_attribute("recipient", recipient)
+                .add_attribute("bps", bps.to_string())
+                .add_attribute("total_bps", total_bps.to_string()),
+        ))
+    }
+
+    /// Distribute allocation rewards from the caller to the specified recipient.
+    pub fn distribute_rewards(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        recipient: &str,
+        in_inj: bool,
+    ) -> Result<Response, ContractError> {
+        check_not_paused(deps.as_ref())?;
+        check_operation_not_paused(deps.as_ref(), PausableOperation::DistributeRewards)?;
+        let distributor = info.sender.clone();
+        whitelist::check_whitelisted(deps.as_ref(), &env, &distributor)?;
+        tiers::check_can_distribute(deps.as_ref(), &distributor)?;
+
+        let recipient_addr = deps.api.addr_validate(recipient)?;
+        whitelist::check_not_frozen(deps.as_ref(), &recipient_addr)?;
+
+        ensure!(
+            !allocations()
+                .prefix(distributor.clone())
+                .is_empty(deps.storage),
+            ContractError::NoAllocations
+        );
+
+        // get the allocation to the recipient
         let allocation = allocations()
             .load(deps.storage, (distributor.clone(), recipient_addr))
             .map_err(|_| ContractError::NoAllocationToRecipient)?;
@@ -622,15 +1247,14 @@ pub mod execute {
         // distribute rewards for the current share price
         let contract_addr = env.contract.address.clone();
         let share_price = get_share_price(deps.as_ref(), &contract_addr);
+        let allocation = effective_allocation(deps.as_ref(), allocation, &share_price)?;
         let attached_inj_amount = cw_utils::may_pay(&info, INJ)?.u128();
 
         let mut response = Response::new();
 
         // No distribution is needed if the share price of the allocation is the same as the global share price,
         // or if it's higher due to slashing.
-        if allocation.share_price_num / allocation.share_price_denom
-            >= share_price.numerator / share_price.denominator
-        {
+        if !has_rewards_to_distribute(&allocation, &share_price) {
             if attached_inj_amount > 0 {
                 response = response.add_message(BankMsg::Send {
                     to_address: distributor.to_string(),
@@ -658,10 +1282,278 @@ pub mod execute {
         Ok(distribution_response)
     }
 
+    /// Distribute the rewards of all the caller's allocations.
+    /// Allocations without rewards to distribute, and allocations to frozen recipients, are skipped.
+    pub fn distribute_all(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        in_inj: bool,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<Response, ContractError> {
+        check_not_paused(deps.as_ref())?;
+        check_operation_not_paused(deps.as_ref(), PausableOperation::DistributeRewards)?;
+        let distributor = info.sender.clone();
+        whitelist::check_whitelisted(deps.as_ref(), &env, &distributor)?;
+        tiers::check_can_distribute(deps.as_ref(), &distributor)?;
+
+        // select the page of allocations to process
+        let limit = limit
+            .unwrap_or(DEFAULT_DISTRIBUTE_ALL_LIMIT)
+            .min(MAX_DISTRIBUTE_ALL_LIMIT) as usize;
+        let start = start_after
+            .map(|recipient| deps.api.addr_validate(&recipient))
+            .transpose()?
+            .map(|recipient| Bound::exclusive((distributor.clone(), recipient)));
+        let share_price = get_share_price(deps.as_ref(), &env.contract.address);
+        let distributor_allocations = allocations()
+            .idx
+            .allocator
+            .prefix(distributor.clone())
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| effective_allocation(deps.as_ref(), item?.1, &share_price))
+            .collect::<Result<Vec<_>, _>>()?;
+        ensure!(
+            !distributor_allocations.is_empty(),
+            ContractError::NoAllocations
+        );
+
+        let attached_inj_amount = cw_utils::may_pay(&info, INJ)?.u128();
+        let staker_info = STAKER_INFO.load(deps.storage)?;
+
+        let last_recipient = distributor_allocations
+            .last()
+            .map(|allocation| allocation.recipient.to_string())
+            .unwrap_or_default();
+
+        // compute the amounts to distribute to each recipient and their totals
+        let mut distributions = vec![];
+        let (mut total_inj_amount, mut total_truinj_amount, mut total_fees) = (0, 0, 0);
+        for allocation in distributor_allocations {
+            if !has_rewards_to_distribute(&allocation, &share_price)
+                || whitelist::is_user_blacklisted(deps.as_ref(), &allocation.recipient)?
+            {
+                continue;
+            }
+            let amounts = calculate_distribution_amounts(
+                &allocation,
+                share_price.numerator,
+                share_price.denominator,
+                staker_info.distribution_fee,
+            )?;
+            total_inj_amount += amounts.0;
+            total_truinj_amount += amounts.1;
+            total_fees +=
+                distribution_fee_transfers(deps.as_ref(), amounts.2, &staker_info.fee_recipients)?
+                    .iter()
+                    .map(|(_, fee_shares)| fee_shares.u128())
+                    .sum::<u128>();
+            distributions.push((allocation, amounts));
+        }
+
+        // check that the distributor can fund all distributions at once
+        check_distribution_funds(
+            deps.as_ref(),
+            &distributor,
+            in_inj,
+            attached_inj_amount,
+            (total_inj_amount, total_truinj_amount, total_fees),
+        )?;
+
+        let mut response = Response::new();
+        let recipients = distributions.len();
+        for (allocation, amounts) in distributions {
+            let distribution_response = apply_distribution(
+                deps.branch(),
+                &env,
+                allocation,
+                in_inj,
+                &share_price,
+                &share_price,
+                amounts,
+                &staker_info,
+            )?;
+            response = response
+                .add_submessages(distribution_response.messages)
+                .add_events(distribution_response.events);
+        }
+
+        // refund any unused INJ
+        let refund_amount = if in_inj {
+            attached_inj_amount - total_inj_amount
+        } else {
+            attached_inj_amount
+        };
+        if refund_amount > 0 {
+            response = response.add_message(BankMsg::Send {
+                to_address: distributor.to_string(),
+                amount: vec![Coin {
+                    denom: INJ.to_string(),
+                    amount: refund_amount.into(),
+                }],
+            });
+        }
+
+        Ok(response.add_event(
+            Event::new("distributed_all_rewards")
+                .add_attribute("user", distributor)
+                .add_attribute("recipients", recipients.to_string())
+                .add_attribute("inj_amount", total_inj_amount.to_string())
+                .add_attribute("shares", total_truinj_amount.to_string())
+                .add_attribute("fees", total_fees.to_string())
+                .add_attribute("in_inj", in_inj.to_string())
+                .add_attribute("last_recipient", last_recipient),
+        ))
+    }
+
+    /// Approves a recipient to claim the rewards of the sender's allocation to them, up to an allowance per period.
+    /// Approving a recipient again replaces the approval and starts a new period.
+    pub fn approve_recipient_claims(
+        deps: DepsMut,
+        env: Env,
+        sender: Addr,
+        recipient: &str,
+        max_per_period: Uint128,
+        period: u64,
+    ) -> Result<Response, ContractError> {
+        check_not_paused(deps.as_ref())?;
+        whitelist::check_whitelisted(deps.as_ref(), &env, &sender)?;
+        let recipient_addr = deps.api.addr_validate(recipient)?;
+
+        ensure!(
+            !max_per_period.is_zero() && period > 0,
+            ContractError::InvalidClaimApproval
+        );
+
+        CLAIM_APPROVALS.save(
+            deps.storage,
+            (&sender, &recipient_addr),
+            &ClaimApproval {
+                max_per_period,
+                period,
+                period_start: env.block.time.seconds(),
+                claimed_in_period: Uint128::zero(),
+            },
+        )?;
+
+        Ok(Response::new().add_event(
+            Event::new("recipient_claims_approved")
+                .add_attribute("user", sender)
+                .add_attribute("recipient", recipient_addr)
+                .add_attribute("max_per_period", max_per_period)
+                .add_attribute("period", period.to_string()),
+        ))
+    }
+
+    /// Revokes the approval of a recipient to claim the rewards of the sender's allocation to them.
+    pub fn revoke_recipient_claims(
+        deps: DepsMut,
+        sender: Addr,
+        recipient: &str,
+    ) -> Result<Response, ContractError> {
+        let recipient_addr = deps.api.addr_validate(recipient)?;
+        ensure!(
+            CLAIM_APPROVALS.has(deps.storage, (&sender, &recipient_addr)),
+            ContractError::NoClaimApproval
+        );
+        CLAIM_APPROVALS.remove(deps.storage, (&sender, &recipient_addr));
+
+        Ok(Response::new().add_event(
+            Event::new("recipient_claims_revoked")
+                .add_attribute("user", sender)
+                .add_attribute("recipient", recipient_addr),
+        ))
+    }
+
+    /// Claims the rewards of the distributor's allocation to the sender, if the distributor has approved them.
+    /// The rewards and distribution fees are paid in TruINJ out of the distributor's balance, as in a distribution.
+    /// A claim is capped at the allowance left in the current period, and the rewards left unpaid stay owed.
+    pub fn claim_allocation_rewards(
+        mut deps: DepsMut,
+        env: Env,
+        sender: Addr,
+        distributor: &str,
+    ) -> Result<Response, ContractError> {
+        check_not_paused(deps.as_ref())?;
+        check_operation_not_paused(deps.as_ref(), PausableOperation::DistributeRewards)?;
+        whitelist::check_not_frozen(deps.as_ref(), &sender)?;
+        let distributor_addr = deps.api.addr_validate(distributor)?;
+        whitelist::check_whitelisted(deps.as_ref(), &env, &distributor_addr)?;
+        tiers::check_can_distribute(deps.as_ref(), &distributor_addr)?;
+
+        let approval = CLAIM_APPROVALS
+            .may_load(deps.storage, (&distributor_addr, &sender))?
+            .ok_or(ContractError::NoClaimApproval)?;
+
+        let allocation = allocations()
+            .load(deps.storage, (distributor_addr.clone(), sender.clone()))
+            .map_err(|_| ContractError::NoAllocationToRecipient)?;
+
+        let share_price = get_share_price(deps.as_ref(), &env.contract.address);
+        let allocation = effective_allocation(deps.as_ref(), allocation, &share_price)?;
+        ensure!(
+            has_rewards_to_distribute(&allocation, &share_price),
+            ContractError::NoAllocationRewards
+        );
+
+        let staker_info = STAKER_INFO.load(deps.storage)?;
+        let amounts = calculate_distribution_amounts(
+            &allocation,
+            share_price.numerator,
+            share_price.denominator,
+            staker_info.distribution_fee,
+        )?;
+
+        // the claim, including fees, is capped at the allowance left for the current period
+        let mut approval = current_claim_period(approval, env.block.time.seconds());
+        let remaining_allowance = approval.max_per_period - approval.claimed_in_period;
+        ensure!(
+            !remaining_allowance.is_zero(),
+            ContractError::ClaimAllowanceExceeded
+        );
+        let (amounts, allocation_share_price) =
+            if amounts.1 + amounts.2 <= remaining_allowance.u128() {
+                (amounts, share_price.clone())
+            } else {
+                capped_distribution_amounts(
+                    &allocation,
+                    &share_price,
+                    remaining_allowance,
+                    staker_info.distribution_fee,
+                )?
+            };
+        approval.claimed_in_period += Uint128::new(amounts.1 + amounts.2);
+        CLAIM_APPROVALS.save(deps.storage, (&distributor_addr, &sender), &approval)?;
+
+        check_distribution_funds(deps.as_ref(), &distributor_addr, false, 0, amounts)?;
+        let response = apply_distribution(
+            deps.branch(),
+            &env,
+            allocation,
+            false,
+            &share_price,
+            &allocation_share_price,
+            amounts,
+            &staker_info,
+        )?;
+
+        Ok(response.add_event(
+            Event::new("claimed_allocation_rewards")
+                .add_attribute("user", sender)
+                .add_attribute("distributor", distributor_addr)
+                .add_attribute("shares", amounts.1.to_string())
+                .add_attribute("fees", amounts.2.to_string())
+                .add_attribute("claimed_in_period", approval.claimed_in_period),
+        ))
+    }
+
     /// Allows a user to withdraw all their expired claims.
     pub fn claim(deps: DepsMut, env: Env, user: Addr) -> Result<Response, ContractError> {
-        check_not_paused(deps.as_ref())?;
-        whitelist::check_whitelisted(deps.as_ref(), &user)?;
+        check_not_hard_paused(deps.as_ref())?;
+        check_operation_not_paused(deps.as_ref(), PausableOperation::Claim)?;
+        whitelist::check_whitelisted(deps.as_ref(), &env, &user)?;
 
         // check if the user has a pending claim
         let claimed_amount = CLAIMS.claim_tokens(deps.storage, &user, &env.block, None)?;
@@ -683,8 +1575,22 @@ pub mod execute {
             ContractError::InsufficientStakerFunds
         );
 
+        // any shortfall, e.g. due to rounding errors, is covered by the reserve.
+        // neither the contract rewards nor the reserve itself count towards the unreserved assets
+        compliance::credit_cancelled_claims(deps.storage, &env)?;
+        let reserve = RESERVE.may_load(deps.storage)?.unwrap_or_default();
+        let unreserved_assets = contract_balance
+            .saturating_sub(contract_rewards)
+            .saturating_sub(reserve);
+        let shortfall = claimed_amount.saturating_sub(unreserved_assets);
+
+        let mut response = Response::new();
+        if !shortfall.is_zero() {
+            response = response.add_event(pay_rounding_subsidy(deps.storage, shortfall)?);
+        }
+
         // transfer the assets to the user
-        Ok(Response::new()
+        Ok(response
             .add_message(BankMsg::Send {
                 to_address: user.to_string(),
                 amount: vec![Coin {
@@ -700,13 +1606,25 @@ pub mod execute {
     }
 
     /// Allows the pending owner to claim ownership of the contract.
-    pub fn claim_ownership(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+    pub fn claim_ownership(
+        deps: DepsMut,
+        env: Env,
+        sender: Addr,
+    ) -> Result<Response, ContractError> {
         let pending_owner = PENDING_OWNER
             .load(deps.storage)
             .map_err(|_| ContractError::NoPendingOwnerSet)?;
 
         ensure!(sender == pending_owner, ContractError::NotPendingOwner);
 
+        // pending owners set before expiries were introduced do not expire
+        if let Some(expires_at) = PENDING_OWNER_EXPIRES_AT.may_load(deps.storage)? {
+            ensure!(
+                env.block.time.seconds() < expires_at,
+                ContractError::PendingOwnerExpired
+            );
+        }
+
         let old_owner = OWNER.load(deps.storage)?;
 
         // set new owner
@@ -714,6 +1632,7 @@ pub mod execute {
 
         // remove pending owner
         PENDING_OWNER.remove(deps.storage);
+        PENDING_OWNER_EXPIRES_AT.remove(deps.storage);
 
         Ok(Response::new().add_event(
             Event::new("claimed_ownership")
@@ -728,7 +1647,7 @@ pub mod execute {
         sender: Addr,
         validator_addr: String,
     ) -> Result<Response, ContractError> {
-        check_owner(deps.as_ref(), &sender)?;
+        roles::check_role(deps.as_ref(), Role::ValidatorManager, &sender)?;
         ensure!(
             !VALIDATORS.has(deps.storage, &validator_addr),
             ContractError::ValidatorAlreadyExists
@@ -754,7 +1673,7 @@ pub mod execute {
         sender: Addr,
         validator_addr: String,
     ) -> Result<Response, ContractError> {
-        check_owner(deps.as_ref(), &sender)?;
+        roles::check_role(deps.as_ref(), Role::ValidatorManager, &sender)?;
         VALIDATORS.update(
             deps.storage,
             &validator_addr,
@@ -783,7 +1702,7 @@ pub mod execute {
         sender: Addr,
         validator_addr: String,
     ) -> Result<Response, ContractError> {
-        check_owner(deps.as_ref(), &sender)?;
+        roles::check_role(deps.as_ref(), Role::ValidatorManager, &sender)?;
         VALIDATORS.update(
             deps.storage,
             &validator_addr,
@@ -805,50 +1724,195 @@ pub mod execute {
         ))
     }
 
-    /// Pauses the contract to prevent user operations.
+    /// Hard pauses the contract to prevent all user operations.
     pub fn pause(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+        roles::check_role(deps.as_ref(), Role::Pauser, &sender)?;
+        check_not_hard_paused(deps.as_ref())?;
+
+        let old_status = load_pause_status(deps.as_ref())?;
+        PAUSE_STATUS.save(deps.storage, &PauseStatus::HardPaused)?;
+
+        Ok(Response::new()
+            .add_event(Event::new("paused").add_attribute("old_status", old_status.to_string())))
+    }
+
+    /// Soft pauses the contract to prevent deposits, allocations and transfers
+    /// while still allowing users to unstake and claim.
+    /// Relaxing a hard pause to a soft pause is restricted to the owner.
+    pub fn soft_pause(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+        roles::check_role(deps.as_ref(), Role::Pauser, &sender)?;
+
+        let old_status = load_pause_status(deps.as_ref())?;
+        ensure!(
+            old_status != PauseStatus::SoftPaused,
+            ContractError::AlreadySoftPaused
+        );
+        if old_status == PauseStatus::HardPaused {
+            check_owner(deps.as_ref(), &sender)?;
+        }
+        PAUSE_STATUS.save(deps.storage, &PauseStatus::SoftPaused)?;
+
+        Ok(Response::new().add_event(
+            Event::new("soft_paused").add_attribute("old_status", old_status.to_string()),
+        ))
+    }
+
+    /// Pauses the given operations individually.
+    pub fn pause_operations(
+        deps: DepsMut,
+        sender: Addr,
+        operations: Vec<PausableOperation>,
+    ) -> Result<Response, ContractError> {
+        roles::check_role(deps.as_ref(), Role::Pauser, &sender)?;
+        ensure!(!operations.is_empty(), ContractError::NoOperationsProvided);
+
+        for operation in &operations {
+            PAUSED_OPERATIONS.save(deps.storage, &operation.to_string(), &())?;
+        }
+
+        Ok(Response::new().add_event(
+            Event::new("operations_paused")
+                .add_attribute("operations", to_json_string(&operations)?),
+        ))
+    }
+
+    /// Unpauses the given operations.
+    pub fn unpause_operations(
+        deps: DepsMut,
+        sender: Addr,
+        operations: Vec<PausableOperation>,
+    ) -> Result<Response, ContractError> {
         check_owner(deps.as_ref(), &sender)?;
-        check_not_paused(deps.as_ref())?;
+        ensure!(!operations.is_empty(), ContractError::NoOperationsProvided);
 
-        IS_PAUSED.save(deps.storage, &true)?;
+        for operation in &operations {
+            PAUSED_OPERATIONS.remove(deps.storage, &operation.to_string());
+        }
 
-        Ok(Response::new().add_event(Event::new("paused")))
+        Ok(Response::new().add_event(
+            Event::new("operations_unpaused")
+                .add_attribute("operations", to_json_string(&operations)?),
+        ))
     }
 
     /// Unpauses the contract to allow user operations.
     pub fn unpause(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
         check_owner(deps.as_ref(), &sender)?;
 
-        // set paused to false if the contract is paused
-        IS_PAUSED.update(deps.storage, |paused| -> Result<_, ContractError> {
-            ensure!(paused, ContractError::NotPaused);
-            Ok(false)
-        })?;
+        let old_status = load_pause_status(deps.as_ref())?;
+        ensure!(
+            old_status != PauseStatus::Unpaused,
+            ContractError::NotPaused
+        );
+        PAUSE_STATUS.save(deps.storage, &PauseStatus::Unpaused)?;
 
-        Ok(Response::new().add_event(Event::new("unpaused")))
+        Ok(Response::new()
+            .add_event(Event::new("unpaused").add_attribute("old_status", old_status.to_string())))
     }
 
-    /// Restakes rewards on all validators and sweeps contract rewards back into the default validator.
-    pub fn compound_rewards(mut deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    /// Restakes rewards on a slice of the validators and sweeps contract rewards back into the default validator.
+    /// A full sweep of the validators can span several calls, with progress persisted between them.
+    /// The caller is paid a keeper bounty out of the treasury fees.
+    pub fn compound_rewards(
+        mut deps: DepsMut,
+        env: Env,
+        caller: Addr,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        min_reward: Option<Uint128>,
+    ) -> Result<Response, ContractError> {
+        check_operation_not_paused(deps.as_ref(), PausableOperation::CompoundRewards)?;
+        let compound_config = COMPOUND_CONFIG.may_load(deps.storage)?.unwrap_or_default();
+        if compound_config.keepers_only {
+            roles::check_role(deps.as_ref(), Role::Keeper, &caller)?;
+        }
+
+        // check that the minimum interval since the last compound has elapsed when starting a new sweep,
+        // and that a sweep in progress is resumed from its cursor rather than restarted
+        let compound_cursor = COMPOUND_CURSOR.may_load(deps.storage)?;
+        if compound_cursor.is_some() {
+            ensure!(
+                start_after.is_none() || start_after == compound_cursor,
+                ContractError::CompoundSweepInProgress
+            );
+        } else {
+            let last_compound_time = LAST_COMPOUND_TIME
+                .may_load(deps.storage)?
+                .unwrap_or_default();
+            ensure!(
+                env.block.time.seconds()
+                    >= last_compound_time + compound_config.min_compound_interval,
+                ContractError::CompoundTooSoon
+            );
+        }
+
+        // select the slice of validators to process
+        let start_after = start_after.or(compound_cursor.clone());
+        let limit = limit
+            .unwrap_or(DEFAULT_COMPOUND_LIMIT)
+            .min(MAX_COMPOUND_LIMIT) as usize;
+        let mut validators = VALIDATORS
+            .keys(
+                deps.storage,
+                start_after.as_ref().map(Bound::exclusive),
+                None,
+                cosmwasm_std::Order::Ascending,
+            )
+            .take(limit + 1)
+            .collect::<StdResult<Vec<_>>>()?;
+        let sweep_complete = validators.len() <= limit;
+        validators.truncate(limit);
+
         let contract_addr = env.contract.address.clone();
+        let staker_info = STAKER_INFO.load(deps.storage)?;
+
+        // the fees of every page are priced at the global share price when the sweep started,
+        // which the fee mints and restakes of the sweep leave unchanged
+        let (share_price_num, share_price_denom) = match COMPOUND_SHARE_PRICE
+            .may_load(deps.storage)?
+            .filter(|_| compound_cursor.is_some())
+        {
+            Some(share_price) => share_price,
+            None => {
+                let (total_staked, total_rewards) =
+                    get_total_staked_and_rewards(deps.as_ref(), &contract_addr)?;
+                internal_share_price(
+                    total_staked,
+                    CONTRACT_REWARDS.load(deps.storage)?.u128(),
+                    total_rewards,
+                    TOKEN_INFO.load(deps.storage)?.total_supply.u128(),
+                    staker_info.fee,
+                )
+            }
+        };
+
+        // persist the progress of the sweep
+        match validators.last() {
+            Some(last_validator) if !sweep_complete => {
+                COMPOUND_CURSOR.save(deps.storage, last_validator)?;
+                COMPOUND_SHARE_PRICE.save(deps.storage, &(share_price_num, share_price_denom))?;
+            }
+            _ => {
+                COMPOUND_CURSOR.remove(deps.storage);
+                COMPOUND_SHARE_PRICE.remove(deps.storage);
+            }
+        }
+
+        let min_reward = min_reward.unwrap_or_default();
         let mut total_rewards = 0u128;
-        let mut total_staked = 0u128;
 
         let mut collect_rewards_messages = Vec::new();
         let mut restake_messages = Vec::new();
 
-        for validator in VALIDATORS.range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        {
-            let (validator_addr, _) = validator?;
+        for validator_addr in validators {
             if let Some(delegation) = deps
                 .querier
                 .query_delegation(contract_addr.clone(), validator_addr.clone())?
             {
-                total_staked += delegation.amount.amount.u128();
                 if let Some(reward) = delegation
                     .accumulated_rewards
                     .iter()
-                    .find(|coin| coin.denom == INJ)
+                    .find(|coin| coin.denom == INJ && coin.amount >= min_reward)
                 {
                     total_rewards += reward.amount.u128();
                     collect_rewards_messages.push(DistributionMsg::WithdrawDelegatorReward {
@@ -871,45 +1935,51 @@ pub mod execute {
             return Ok(Response::new());
         }
 
-        let staker_info = STAKER_INFO.load(deps.storage)?;
+        LAST_COMPOUND_TIME.save(deps.storage, &env.block.time.seconds())?;
 
         let fees: u128 = total_rewards * u128::from(staker_info.fee) / u128::from(FEE_PRECISION);
         let mut treasury_share_increase = Uint128::from(0u128);
+        let mut keeper_bounty_shares = Uint128::from(0u128);
 
-        let mut res = if fees > 0 {
-            let shares_supply = TOKEN_INFO.load(deps.storage)?.total_supply;
-
-            let contract_rewards: Uint128 = CONTRACT_REWARDS.load(deps.storage)?;
+        let fee_mint_msgs = if fees > 0 {
+            let fee_shares = convert_to_shares((fees).into(), share_price_num, share_price_denom)?;
 
-            let (share_price_num, share_price_denom) = internal_share_price(
-                total_staked,
-                contract_rewards.u128(),
-                total_rewards,
-                shares_supply.u128(),
-                staker_info.fee,
-            );
+            // the keeper bounty is carved out of the treasury fees so that the share price is unaffected
+            let keeper_bounty = total_rewards * u128::from(compound_config.keeper_bounty)
+                / u128::from(FEE_PRECISION);
+            keeper_bounty_shares =
+                convert_to_shares(keeper_bounty.into(), share_price_num, share_price_denom)?;
+            treasury_share_increase = fee_shares - keeper_bounty_shares;
 
-            treasury_share_increase =
-                convert_to_shares((fees).into(), share_price_num, share_price_denom)?;
-
-            let minter_info = MessageInfo {
-                sender: contract_addr,
-                funds: vec![],
-            };
-
-            // mint TruINJ to the treasury
-            execute_mint(
+            // mint TruINJ to the fee recipients
+            let mut msgs = mint_fee_shares(
                 deps.branch(),
-                env,
-                minter_info,
-                staker_info.treasury.clone().into_string(),
+                &env,
+                &staker_info.fee_recipients,
                 treasury_share_increase,
-            )?
+            )?;
+
+            // mint the keeper bounty to the caller
+            if !keeper_bounty_shares.is_zero() {
+                let bounty_mint = execute_mint(
+                    deps.branch(),
+                    env.clone(),
+                    MessageInfo {
+                        sender: contract_addr.clone(),
+                        funds: vec![],
+                    },
+                    caller.to_string(),
+                    keeper_bounty_shares,
+                )?;
+                msgs.push(emit_event_msg(&env, bounty_mint.attributes)?);
+            }
+            msgs
         } else {
-            Response::new()
+            vec![]
         };
 
-        res = res
+        let res = Response::new()
+            .add_messages(fee_mint_msgs)
             .add_messages(collect_rewards_messages)
             .add_messages(restake_messages)
             .add_event(
@@ -917,9 +1987,12 @@ pub mod execute {
                     .add_attribute("amount", Uint128::from(total_rewards))
                     .add_attribute("treasury_shares_minted", treasury_share_increase)
                     .add_attribute(
-                        "treasury_balance",
-                        query_balance(deps.as_ref(), staker_info.treasury.into_string())?.balance,
-                    ),
+                        "fee_recipient_balances",
+                        fee_recipient_balances(deps.as_ref(), &staker_info.fee_recipients)?,
+                    )
+                    .add_attribute("keeper", caller)
+                    .add_attribute("keeper_bounty", keeper_bounty_shares)
+                    .add_attribute("sweep_complete", sweep_complete.to_string()),
             );
         Ok(res)
     }
@@ -942,6 +2015,8 @@ pub mod execute {
             CONTRACT_REWARDS.save(deps.storage, &Uint128::zero())?;
         }
 
+        record_delegation(deps.storage, &restake_validator, restake_amount)?;
+
         let res = Response::new().add_message(StakingMsg::Delegate {
             validator: restake_validator,
             amount: Coin {
@@ -971,7 +2046,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::TokenInfo {} => to_json_binary(&query_token_info(deps)?),
         QueryMsg::MarketingInfo {} => to_json_binary(&query_marketing_info(deps)?),
         QueryMsg::Balance { address } => to_json_binary(&query_balance(deps, address)?),
-        QueryMsg::GetValidators {} => to_json_binary(&query::get_validators(deps, env)?),
+        QueryMsg::GetValidators {} => to_json_binary(&query::get_validators(deps)?),
         QueryMsg::GetTotalSupply {} => to_json_binary(&query::get_total_supply(deps)?),
         QueryMsg::GetTotalStaked {} => {
             to_json_binary(&query::get_total_staked(deps, env.contract.address)?)
@@ -982,11 +2057,19 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::IsAgent { agent } => {
             to_json_binary(&query::is_agent(deps, deps.api.addr_validate(&agent)?)?)
         }
+        QueryMsg::HasRole { role, account } => to_json_binary(&query::has_role(
+            deps,
+            role,
+            deps.api.addr_validate(&account)?,
+        )?),
+        QueryMsg::GetRoleMembers { role } => to_json_binary(&query::get_role_members(deps, role)?),
         QueryMsg::IsOwner { addr } => {
             to_json_binary(&query::is_owner(deps, deps.api.addr_validate(&addr)?)?)
         }
+        QueryMsg::GetPendingOwner {} => to_json_binary(&query::get_pending_owner(deps)?),
         QueryMsg::IsWhitelisted { user } => to_json_binary(&query::is_user_whitelisted(
             deps,
+            env,
             deps.api.addr_validate(&user)?,
         )?),
         QueryMsg::IsBlacklisted { user } => to_json_binary(&query::is_user_blacklisted(
@@ -997,6 +2080,58 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             deps,
             deps.api.addr_validate(&user)?,
         )?),
+        QueryMsg::GetAgentKey { agent } => to_json_binary(&query::get_agent_key(
+            deps,
+            deps.api.addr_validate(&agent)?,
+        )?),
+        QueryMsg::IsNonceUsed { agent, nonce } => to_json_binary(&query::is_nonce_used(
+            deps,
+            deps.api.addr_validate(&agent)?,
+            nonce,
+        )?),
+        QueryMsg::GetWhitelistBackend {} => {
+            to_json_binary(&whitelist::get_whitelist_backend(deps)?)
+        }
+        QueryMsg::ListAgents { start_after, limit } => {
+            to_json_binary(&query::list_agents(deps, start_after, limit)?)
+        }
+        QueryMsg::ListUsersByStatus {
+            status,
+            start_after,
+            limit,
+        } => to_json_binary(&query::list_users_by_status(
+            deps,
+            status,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::CountUsersByStatus { status } => {
+            to_json_binary(&query::count_users_by_status(deps, status)?)
+        }
+        QueryMsg::ListKycTiers {} => to_json_binary(&query::list_kyc_tiers(deps)?),
+        QueryMsg::GetUserTier { user } => {
+            to_json_binary(&query::get_user_tier(deps, deps.api.addr_validate(&user)?)?)
+        }
+        QueryMsg::GetUserStatusHistory {
+            user,
+            start_after,
+            limit,
+        } => to_json_binary(&query::get_user_status_history(
+            deps,
+            deps.api.addr_validate(&user)?,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::GetExpiringWhitelist {
+            before,
+            start_after,
+            limit,
+        } => to_json_binary(&query::get_expiring_whitelist(
+            deps,
+            before,
+            start_after,
+            limit,
+        )?),
         QueryMsg::GetSharePrice {} => {
             to_json_binary(&query::get_share_price(deps, &env.contract.address))
         }
@@ -1021,9 +2156,60 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             deps,
             deps.api.addr_validate(&user)?,
         )?),
+        QueryMsg::GetAllocationsToRecipient {
+            recipient,
+            start_after,
+            limit,
+        } => to_json_binary(&query::get_allocations_to_recipient(
+            deps,
+            deps.api.addr_validate(&recipient)?,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::GetClaimApproval {
+            distributor,
+            recipient,
+        } => to_json_binary(&query::get_claim_approval(
+            deps,
+            &env,
+            &deps.api.addr_validate(&distributor)?,
+            &deps.api.addr_validate(&recipient)?,
+        )?),
+        QueryMsg::GetEscrowedAllocation {
+            allocator,
+            recipient,
+        } => to_json_binary(&escrow::get_escrowed_allocation(
+            deps,
+            &env,
+            &deps.api.addr_validate(&allocator)?,
+            &deps.api.addr_validate(&recipient)?,
+        )?),
+        QueryMsg::GetEscrowedAllocationsToRecipient {
+            recipient,
+            start_after,
+            limit,
+        } => to_json_binary(&escrow::get_escrowed_allocations_to_recipient(
+            deps,
+            &env,
+            deps.api.addr_validate(&recipient)?,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::GetPendingRewardsForRecipient {
+            recipient,
+            start_after,
+            limit,
+        } => to_json_binary(&query::get_pending_rewards_for_recipient(
+            deps,
+            env.contract.address,
+            deps.api.addr_validate(&recipient)?,
+            start_after,
+            limit,
+        )?),
         QueryMsg::GetTotalAllocated { user } => to_json_binary(&query::get_total_allocated(
             deps,
             deps.api.addr_validate(&user)?,
+            &query::get_share_price(deps, &env.contract.address),
         )?),
         QueryMsg::GetDistributionAmounts {
             distributor,
@@ -1034,72 +2220,91 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             &distributor,
             recipient.as_ref(),
         )?),
+        QueryMsg::GetPendingChanges { start_after, limit } => {
+            to_json_binary(&query::get_pending_changes(deps, start_after, limit)?)
+        }
+        QueryMsg::GetTimelockConfig {} => to_json_binary(&query::get_timelock_config(deps)?),
+        QueryMsg::GetMultisigConfig {} => to_json_binary(&query::get_multisig_config(deps)?),
+        QueryMsg::GetProposal { id } => to_json_binary(&query::get_proposal(deps, id)?),
+        QueryMsg::ListProposals { start_after, limit } => {
+            to_json_binary(&query::list_proposals(deps, start_after, limit)?)
+        }
+        QueryMsg::GetCompoundConfig {} => to_json_binary(&query::get_compound_config(deps)?),
+        QueryMsg::GetReserveStatus {} => to_json_binary(&query::get_reserve_status(deps, env)?),
+        QueryMsg::GetPauseMatrix {} => to_json_binary(&query::get_pause_matrix(deps)?),
     }
 }
 
 pub mod query {
-    use cosmwasm_std::Order;
+    use cosmwasm_std::{Order, StdError};
     use cw_controllers::ClaimsResponse;
+    use cw_storage_plus::Bound;
 
     use crate::msg::{
-        GetAllocationsResponse, GetClaimableAmountResponse, GetMaxWithdrawResponse,
+        GetAllocationsResponse, GetClaimApprovalResponse, GetClaimableAmountResponse,
+        GetCompoundConfigResponse, GetMaxWithdrawResponse, GetMultisigConfigResponse,
+        GetPauseMatrixResponse, GetPendingChangesResponse, GetPendingOwnerResponse,
+        GetProposalResponse, GetReserveStatusResponse, GetTimelockConfigResponse,
         GetTotalAllocatedResponse, GetTotalAssetsResponse, GetTotalRewardsResponse,
         GetTotalStakedResponse, GetTotalSupplyResponse, GetValidatorResponse,
+        ListProposalsResponse, OperationPauseState,
     };
 
     use super::*;
     use crate::msg::{
-        GetCurrentUserStatusResponse, GetIsAgentResponse, GetIsBlacklistedResponse,
-        GetIsOwnerResponse, GetIsWhitelistedResponse,
+        CountUsersByStatusResponse, GetAgentKeyResponse, GetCurrentUserStatusResponse,
+        GetExpiringWhitelistResponse, GetHasRoleResponse, GetIsAgentResponse,
+        GetIsBlacklistedResponse, GetIsNonceUsedResponse, GetIsOwnerResponse,
+        GetIsWhitelistedResponse, GetPendingRewardsForRecipientResponse, GetRoleMembersResponse,
+        GetUserStatusHistoryResponse, GetUserTierResponse, ListAgentsResponse,
+        ListKycTiersResponse, ListUsersByStatusResponse, PendingRewards,
+    };
+    use crate::state::{
+        Allocation, UserStatus, ValidatorInfo, COMPOUND_CONFIG, COMPOUND_CURSOR, DELEGATIONS,
+        LAST_COMPOUND_TIME, PENDING_OWNER, PENDING_OWNER_EXPIRES_AT, PROPOSALS,
+        RESERVE_LOW_WATER_MARK, RESERVE_SUBSIDIES, VALIDATORS,
     };
-    use crate::state::{Allocation, ValidatorInfo, VALIDATORS};
+    use crate::{DEFAULT_ALLOCATION_QUERY_LIMIT, MAX_ALLOCATION_QUERY_LIMIT};
     use cosmwasm_std::Addr;
 
     /// Returns staker info.
     pub fn get_staker_info(deps: Deps) -> StdResult<GetStakerInfoResponse> {
         let staker_info = STAKER_INFO.load(deps.storage)?;
         Ok(GetStakerInfoResponse {
-            owner: OWNER.load(deps.storage)?.to_string(),
+            owner: OWNER
+                .may_load(deps.storage)?
+                .map(|owner| owner.to_string())
+                .unwrap_or_default(),
             default_validator: DEFAULT_VALIDATOR.load(deps.storage)?,
-            treasury: staker_info.treasury.to_string(),
+            fee_recipients: staker_info.fee_recipients,
             fee: staker_info.fee,
             distribution_fee: staker_info.distribution_fee,
+            deposit_fee: staker_info.deposit_fee,
+            withdrawal_fee: staker_info.withdrawal_fee,
             min_deposit: staker_info.min_deposit.into(),
-            is_paused: IS_PAUSED.load(deps.storage)?,
+            pause_status: load_pause_status(deps)?,
         })
     }
 
     /// Returns all available validators and their info.
-    pub fn get_validators(deps: Deps, env: Env) -> StdResult<GetValidatorResponse> {
-        let contract = env.contract.address;
-
+    /// The total staked on each validator is read from the delegation ledger.
+    pub fn get_validators(deps: Deps) -> StdResult<GetValidatorResponse> {
         let validators = VALIDATORS
             .range(deps.storage, None, None, Order::Ascending)
-            .collect::<StdResult<Vec<(_, ValidatorState)>>>()
-            .map(|validators| {
-                validators
-                    .into_iter()
-                    .map(|(validator_addr, validator_state)| {
-                        let mut total_staked = Uint128::zero();
-                        if let Some(delegation) = deps
-                            .querier
-                            .query_delegation(contract.clone(), validator_addr.clone())
-                            .unwrap()
-                        {
-                            total_staked = delegation.amount.amount;
-                        }
-                        ValidatorInfo {
-                            addr: validator_addr,
-                            total_staked,
-                            state: validator_state,
-                        }
-                    })
-                    .collect()
-            });
+            .map(|item| {
+                let (validator_addr, validator_state) = item?;
+                let total_staked = DELEGATIONS
+                    .may_load(deps.storage, &validator_addr)?
+                    .unwrap_or_default();
+                Ok(ValidatorInfo {
+                    addr: validator_addr,
+                    total_staked,
+                    state: validator_state,
+                })
+            })
+            .collect::<StdResult<Vec<_>>>()?;
 
-        Ok(GetValidatorResponse {
-            validators: validators?,
-        })
+        Ok(GetValidatorResponse { validators })
     }
 
     /// Returns the total supply of TruINJ.
@@ -1176,25 +2381,50 @@ pub mod query {
         })
     }
 
+    /// Returns whether the account holds the role, either explicitly or as the owner.
+    pub fn has_role(deps: Deps, role: Role, account: Addr) -> StdResult<GetHasRoleResponse> {
+        Ok(GetHasRoleResponse {
+            has_role: roles::has_role(deps, role, &account)?,
+        })
+    }
+
+    /// Returns the accounts that have been granted the role.
+    pub fn get_role_members(deps: Deps, role: Role) -> StdResult<GetRoleMembersResponse> {
+        Ok(GetRoleMembersResponse {
+            members: roles::get_role_members(deps, role)?,
+        })
+    }
+
     /// Returns whether the user is the owner.
     pub fn is_owner(deps: Deps, addr: Addr) -> StdResult<GetIsOwnerResponse> {
-        let owner = OWNER.load(deps.storage)?;
         Ok(GetIsOwnerResponse {
-            is_owner: addr == owner,
+            is_owner: super::is_owner(deps, &addr)?,
+        })
+    }
+
+    /// Returns the pending owner and when it expires.
+    pub fn get_pending_owner(deps: Deps) -> StdResult<GetPendingOwnerResponse> {
+        Ok(GetPendingOwnerResponse {
+            pending_owner: PENDING_OWNER.may_load(deps.storage)?,
+            expires_at: PENDING_OWNER_EXPIRES_AT.may_load(deps.storage)?,
         })
     }
 
     /// Returns whether the user is whitelisted.
-    pub fn is_user_whitelisted(deps: Deps, user: Addr) -> StdResult<GetIsWhitelistedResponse> {
+    pub fn is_user_whitelisted(
+        deps: Deps,
+        env: Env,
+        user: Addr,
+    ) -> StdResult<GetIsWhitelistedResponse> {
         Ok(GetIsWhitelistedResponse {
-            is_whitelisted: whitelist::is_user_whitelisted(deps, &user),
+            is_whitelisted: whitelist::is_user_whitelisted(deps, &env, &user)?,
         })
     }
 
     /// Returns whether the user is blacklisted.
     pub fn is_user_blacklisted(deps: Deps, user: Addr) -> StdResult<GetIsBlacklistedResponse> {
         Ok(GetIsBlacklistedResponse {
-            is_blacklisted: whitelist::is_user_blacklisted(deps, &user),
+            is_blacklisted: whitelist::is_user_blacklisted(deps, &user)?,
         })
     }
 
@@ -1204,7 +2434,103 @@ pub mod query {
         user: Addr,
     ) -> StdResult<GetCurrentUserStatusResponse> {
         Ok(GetCurrentUserStatusResponse {
-            user_status: whitelist::get_current_user_status(deps, &user).unwrap(),
+            user_status: whitelist::get_current_user_status(deps, &user)?,
+            valid_until: whitelist::get_whitelist_expiry(deps, &user)?,
+        })
+    }
+
+    /// Returns the public key an agent signs attestations with.
+    pub fn get_agent_key(deps: Deps, agent: Addr) -> StdResult<GetAgentKeyResponse> {
+        Ok(GetAgentKeyResponse {
+            public_key: attestation::get_agent_key(deps, &agent)?,
+        })
+    }
+
+    /// Returns whether an agent has used an attestation nonce.
+    pub fn is_nonce_used(deps: Deps, agent: Addr, nonce: u64) -> StdResult<GetIsNonceUsedResponse> {
+        Ok(GetIsNonceUsedResponse {
+            is_used: attestation::is_nonce_used(deps, &agent, nonce),
+        })
+    }
+
+    /// Returns a page of the agents.
+    pub fn list_agents(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<ListAgentsResponse> {
+        let start_after = start_after
+            .map(|agent| deps.api.addr_validate(&agent))
+            .transpose()?;
+        Ok(ListAgentsResponse {
+            agents: whitelist::list_agents(deps, start_after, limit)?,
+        })
+    }
+
+    /// Returns a page of the users with the given status.
+    pub fn list_users_by_status(
+        deps: Deps,
+        status: UserStatus,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<ListUsersByStatusResponse> {
+        let start_after = start_after
+            .map(|user| deps.api.addr_validate(&user))
+            .transpose()?;
+        Ok(ListUsersByStatusResponse {
+            users: whitelist::list_users_by_status(deps, status, start_after, limit)?,
+        })
+    }
+
+    /// Returns the number of users with the given status.
+    pub fn count_users_by_status(
+        deps: Deps,
+        status: UserStatus,
+    ) -> StdResult<CountUsersByStatusResponse> {
+        Ok(CountUsersByStatusResponse {
+            count: whitelist::count_users_by_status(deps, status)?,
+        })
+    }
+
+    /// Returns all KYC tiers, ordered by name.
+    pub fn list_kyc_tiers(deps: Deps) -> StdResult<ListKycTiersResponse> {
+        Ok(ListKycTiersResponse {
+            tiers: tiers::list_kyc_tiers(deps)?,
+        })
+    }
+
+    /// Returns the name of a user's KYC tier, if they are assigned to one.
+    pub fn get_user_tier(deps: Deps, user: Addr) -> StdResult<GetUserTierResponse> {
+        Ok(GetUserTierResponse {
+            tier: tiers::get_user_tier(deps, &user)?.map(|(name, _)| name),
+        })
+    }
+
+    /// Returns the status changes of a user, oldest first.
+    pub fn get_user_status_history(
+        deps: Deps,
+        user: Addr,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<GetUserStatusHistoryResponse> {
+        Ok(GetUserStatusHistoryResponse {
+            history: whitelist::get_user_status_history(deps, &user, start_after, limit)?,
+        })
+    }
+
+    /// Returns the whitelisted users whose KYC approval expires before the given time,
+    /// ordered by expiry.
+    pub fn get_expiring_whitelist(
+        deps: Deps,
+        before: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<GetExpiringWhitelistResponse> {
+        let start_after = start_after
+            .map(|user| deps.api.addr_validate(&user))
+            .transpose()?;
+        Ok(GetExpiringWhitelistResponse {
+            entries: whitelist::get_expiring_whitelist(deps, before, start_after, limit)?,
         })
     }
 
@@ -1249,33 +2575,133 @@ pub mod query {
             numerator: share_price_num,
             denominator: share_price_denom,
         }
-    }
-
-    /// Returns all allocations for a given user.
-    pub fn get_allocations(deps: Deps, allocator: Addr) -> StdResult<GetAllocationsResponse> {
-        let allocations: Vec<Allocation> = allocations()
-            .idx
-            .allocator
-            .prefix(allocator)
-            .range(deps.storage, None, None, Order::Ascending)
-            .map(|item| item.map(|(_, allocation)| allocation))
-            .collect::<StdResult<Vec<_>>>()?;
+    }
+
+    /// Returns all allocations for a given user.
+    pub fn get_allocations(deps: Deps, allocator: Addr) -> StdResult<GetAllocationsResponse> {
+        let allocations: Vec<Allocation> = allocations()
+            .idx
+            .allocator
+            .prefix(allocator)
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, allocation)| allocation))
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(GetAllocationsResponse { allocations })
+    }
+
+    /// Returns the allocations made to a recipient, ordered by allocator.
+    pub fn get_allocations_to_recipient(
+        deps: Deps,
+        recipient: Addr,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<GetAllocationsResponse> {
+        let limit = limit
+            .unwrap_or(DEFAULT_ALLOCATION_QUERY_LIMIT)
+            .min(MAX_ALLOCATION_QUERY_LIMIT) as usize;
+        let start = start_after
+            .map(|allocator| deps.api.addr_validate(&allocator))
+            .transpose()?
+            .map(|allocator| Bound::exclusive((allocator, recipient.clone())));
+
+        let allocations: Vec<Allocation> = allocations()
+            .idx
+            .recipient
+            .prefix(recipient)
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| item.map(|(_, allocation)| allocation))
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(GetAllocationsResponse { allocations })
+    }
+
+    /// Returns the rewards each distributor currently owes a recipient, net of distribution fees,
+    /// ordered by distributor. Allocations without rewards to distribute are scanned but left out.
+    pub fn get_pending_rewards_for_recipient(
+        deps: Deps,
+        contract_address: Addr,
+        recipient: Addr,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<GetPendingRewardsForRecipientResponse> {
+        let share_price = get_share_price(deps, &contract_address);
+        let fee = STAKER_INFO.load(deps.storage)?.distribution_fee;
+
+        let limit = limit
+            .unwrap_or(DEFAULT_ALLOCATION_QUERY_LIMIT)
+            .min(MAX_ALLOCATION_QUERY_LIMIT) as usize;
+        let start = start_after
+            .map(|distributor| deps.api.addr_validate(&distributor))
+            .transpose()?
+            .map(|distributor| Bound::exclusive((distributor, recipient.clone())));
+
+        let mut inj_amount = Uint128::zero();
+        let mut truinj_amount = Uint128::zero();
+        let mut pending_rewards = vec![];
+        let mut last_distributor = None;
+        for item in allocations()
+            .idx
+            .recipient
+            .prefix(recipient)
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+        {
+            let (_, allocation) = item?;
+            last_distributor = Some(allocation.allocator.clone());
+            let allocation = effective_allocation(deps, allocation, &share_price)
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+            if !has_rewards_to_distribute(&allocation, &share_price) {
+                continue;
+            }
+
+            let (inj, shares, _) = calculate_distribution_amounts(
+                &allocation,
+                share_price.numerator,
+                share_price.denominator,
+                fee,
+            )
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+            inj_amount += Uint128::new(inj);
+            truinj_amount += Uint128::new(shares);
+            pending_rewards.push(PendingRewards {
+                distributor: allocation.allocator,
+                inj_amount: inj.into(),
+                truinj_amount: shares.into(),
+            });
+        }
 
-        Ok(GetAllocationsResponse { allocations })
+        Ok(GetPendingRewardsForRecipientResponse {
+            inj_amount,
+            truinj_amount,
+            pending_rewards,
+            last_distributor,
+        })
     }
 
     /// Returns the total amount of INJ allocated by a user and their average allocation share price.
-    pub fn get_total_allocated(deps: Deps, user: Addr) -> StdResult<GetTotalAllocatedResponse> {
+    pub fn get_total_allocated(
+        deps: Deps,
+        user: Addr,
+        share_price: &GetSharePriceResponse,
+    ) -> StdResult<GetTotalAllocatedResponse> {
         let allocations = allocations()
             .idx
             .allocator
             .prefix(user)
             .range(deps.storage, None, None, Order::Ascending)
-            .map(|item| item.map(|(_, allocation)| allocation))
+            .map(|item| {
+                effective_allocation(deps, item?.1, share_price)
+                    .map_err(|err| StdError::generic_err(err.to_string()))
+            })
             .collect::<StdResult<Vec<_>>>()?;
 
+        // percentage allocations of an empty balance don't count towards the total
         let total_allocation = allocations
             .into_iter()
+            .filter(|allocation| !allocation.inj_amount.is_zero())
             .reduce(|acc, allocation| {
                 calculate_updated_allocation(
                     &acc,
@@ -1308,45 +2734,38 @@ pub mod query {
         let share_price = get_share_price(deps, &contract_address);
         let fee = STAKER_INFO.load(deps.storage)?.distribution_fee;
 
-        let (total_inj_amount, total_truinj_amount, total_fees) = if let Some(recipient) = recipient
-        {
-            let allocation = match allocations().may_load(deps.storage, (distributor, recipient))? {
-                Some(allocation) => allocation,
-                None => {
-                    return Ok(GetDistributionAmountsResponse {
-                        inj_amount: Uint128::zero(),
-                        truinj_amount: Uint128::zero(),
-                        distribution_fee: Uint128::zero(),
-                    });
-                }
-            };
-
-            let (inj_amount, truinj_amount, fees) = calculate_distribution_amounts(
+        // allocations without rewards to distribute, e.g. due to slashing, owe nothing
+        let distribution_amounts = |allocation: Allocation| -> StdResult<(u128, u128, u128)> {
+            let allocation = effective_allocation(deps, allocation, &share_price)
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+            if !has_rewards_to_distribute(&allocation, &share_price) {
+                return Ok((0, 0, 0));
+            }
+            calculate_distribution_amounts(
                 &allocation,
                 share_price.numerator,
                 share_price.denominator,
                 fee,
             )
-            .unwrap();
+            .map_err(|err| StdError::generic_err(err.to_string()))
+        };
 
-            (inj_amount, truinj_amount, fees)
+        let (total_inj_amount, total_truinj_amount, total_fees) = if let Some(recipient) = recipient
+        {
+            match allocations().may_load(deps.storage, (distributor, recipient))? {
+                Some(allocation) => distribution_amounts(allocation)?,
+                None => (0, 0, 0),
+            }
         } else {
             let (total_inj_amount, total_truinj_amount, total_fees) = allocations()
                 .idx
                 .allocator
                 .prefix(distributor)
                 .range(deps.storage, None, None, Order::Ascending)
-                .fold((0, 0, 0), |acc, item| {
-                    let (_, allocation) = item.unwrap();
-                    let (inj_amount, truinj_amount, fees) = calculate_distribution_amounts(
-                        &allocation,
-                        share_price.numerator,
-                        share_price.denominator,
-                        fee,
-                    )
-                    .unwrap();
-                    (acc.0 + inj_amount, acc.1 + truinj_amount, acc.2 + fees)
-                });
+                .try_fold((0, 0, 0), |acc, item| -> StdResult<_> {
+                    let (inj_amount, truinj_amount, fees) = distribution_amounts(item?.1)?;
+                    Ok((acc.0 + inj_amount, acc.1 + truinj_amount, acc.2 + fees))
+                })?;
 
             (total_inj_amount, total_truinj_amount, total_fees)
         };
@@ -1357,19 +2776,175 @@ pub mod query {
             distribution_fee: total_fees.into(),
         })
     }
+
+    /// Returns a recipient's approval to claim a distributor's allocation rewards and the allowance left in the current period.
+    pub fn get_claim_approval(
+        deps: Deps,
+        env: &Env,
+        distributor: &Addr,
+        recipient: &Addr,
+    ) -> StdResult<GetClaimApprovalResponse> {
+        let approval = CLAIM_APPROVALS
+            .may_load(deps.storage, (distributor, recipient))?
+            .map(|approval| current_claim_period(approval, env.block.time.seconds()));
+        let remaining_allowance = approval
+            .as_ref()
+            .map(|approval| approval.max_per_period - approval.claimed_in_period)
+            .unwrap_or_default();
+
+        Ok(GetClaimApprovalResponse {
+            approval,
+            remaining_allowance,
+        })
+    }
+
+    /// Returns the parameter changes scheduled through the timelock, ordered by id.
+    pub fn get_pending_changes(
+        deps: Deps,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<GetPendingChangesResponse> {
+        Ok(GetPendingChangesResponse {
+            changes: timelock::list_pending_changes(deps, start_after, limit)?,
+        })
+    }
+
+    /// Returns the multisig signers, threshold and proposal duration.
+    pub fn get_multisig_config(deps: Deps) -> StdResult<GetMultisigConfigResponse> {
+        let config = multisig::get_multisig_config(deps)?;
+
+        Ok(GetMultisigConfigResponse {
+            signers: config.signers,
+            threshold: config.threshold,
+            proposal_duration: config.proposal_duration,
+        })
+    }
+
+    /// Returns a multisig proposal.
+    pub fn get_proposal(deps: Deps, id: u64) -> StdResult<GetProposalResponse> {
+        Ok(GetProposalResponse {
+            proposal: PROPOSALS.load(deps.storage, id)?,
+        })
+    }
+
+    /// Returns a page of multisig proposals.
+    pub fn list_proposals(
+        deps: Deps,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<ListProposalsResponse> {
+        Ok(ListProposalsResponse {
+            proposals: multisig::list_proposals(deps, start_after, limit)?,
+        })
+    }
+
+    /// Returns the timelock delay and the parameters that require the timelock.
+    pub fn get_timelock_config(deps: Deps) -> StdResult<GetTimelockConfigResponse> {
+        let config = timelock::get_timelock_config(deps)?;
+
+        Ok(GetTimelockConfigResponse {
+            min_delay: config.min_delay,
+            timelocked_parameters: config.timelocked_parameters,
+        })
+    }
+
+    /// Returns the pause status of the contract and whether each operation is individually paused.
+    pub fn get_pause_matrix(deps: Deps) -> StdResult<GetPauseMatrixResponse> {
+        let operations = PausableOperation::ALL
+            .iter()
+            .map(|operation| OperationPauseState {
+                operation: *operation,
+                is_paused: PAUSED_OPERATIONS.has(deps.storage, &operation.to_string()),
+            })
+            .collect();
+
+        Ok(GetPauseMatrixResponse {
+            pause_status: load_pause_status(deps)?,
+            operations,
+        })
+    }
+
+    /// Returns the reserve balance, the rounding subsidies paid from it and its low-water mark.
+    /// The balance includes the INJ of cancelled claims whose undelegation has completed.
+    pub fn get_reserve_status(deps: Deps, env: Env) -> StdResult<GetReserveStatusResponse> {
+        let (matured_cancelled_claims, pending_cancelled_claims) =
+            compliance::get_cancelled_claims(deps, &env)?;
+        let balance =
+            RESERVE.may_load(deps.storage)?.unwrap_or_default() + matured_cancelled_claims;
+        let low_water_mark = RESERVE_LOW_WATER_MARK
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+
+        Ok(GetReserveStatusResponse {
+            balance,
+            total_subsidies: RESERVE_SUBSIDIES
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
+            low_water_mark,
+            is_below_low_water_mark: balance < low_water_mark,
+            pending_cancelled_claims,
+        })
+    }
+
+    /// Returns the keeper bounty and access policy of CompoundRewards, and the time of the last compound.
+    pub fn get_compound_config(deps: Deps) -> StdResult<GetCompoundConfigResponse> {
+        let config = COMPOUND_CONFIG.may_load(deps.storage)?.unwrap_or_default();
+
+        Ok(GetCompoundConfigResponse {
+            keeper_bounty: config.keeper_bounty,
+            min_compound_interval: config.min_compound_interval,
+            keepers_only: config.keepers_only,
+            last_compound_time: LAST_COMPOUND_TIME
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
+            compound_cursor: COMPOUND_CURSOR.may_load(deps.storage)?,
+        })
+    }
 }
 
 /// Checks that the caller is the owner of the contract.
-fn check_owner(deps: Deps, user_address: &Addr) -> Result<(), ContractError> {
-    let owner = OWNER.load(deps.storage)?;
-    ensure!(user_address == owner, ContractError::OnlyOwner);
+pub(crate) fn check_owner(deps: Deps, user_address: &Addr) -> Result<(), ContractError> {
+    ensure!(is_owner(deps, user_address)?, ContractError::OnlyOwner);
+    Ok(())
+}
+
+/// Checks whether an address is the owner. Always false once ownership has been renounced.
+pub(crate) fn is_owner(deps: Deps, addr: &Addr) -> StdResult<bool> {
+    Ok(OWNER.may_load(deps.storage)?.as_ref() == Some(addr))
+}
+
+/// Returns the current pause status of the contract.
+pub(crate) fn load_pause_status(deps: Deps) -> StdResult<PauseStatus> {
+    Ok(PAUSE_STATUS.may_load(deps.storage)?.unwrap_or_default())
+}
+
+/// Checks that the contract is neither soft nor hard paused.
+pub(crate) fn check_not_paused(deps: Deps) -> Result<(), ContractError> {
+    ensure!(
+        load_pause_status(deps)? == PauseStatus::Unpaused,
+        ContractError::ContractPaused
+    );
     Ok(())
 }
 
-/// Checks that the contract is not paused.
-fn check_not_paused(deps: Deps) -> Result<(), ContractError> {
+/// Checks that the given operation has not been paused individually.
+pub(crate) fn check_operation_not_paused(
+    deps: Deps,
+    operation: PausableOperation,
+) -> Result<(), ContractError> {
+    ensure!(
+        !PAUSED_OPERATIONS.has(deps.storage, &operation.to_string()),
+        ContractError::OperationPaused {
+            operation: operation.to_string()
+        }
+    );
+    Ok(())
+}
+
+/// Checks that the contract is not hard paused.
+fn check_not_hard_paused(deps: Deps) -> Result<(), ContractError> {
     ensure!(
-        !IS_PAUSED.load(deps.storage)?,
+        load_pause_status(deps)? != PauseStatus::HardPaused,
         ContractError::ContractPaused
     );
     Ok(())
@@ -1387,23 +2962,30 @@ fn check_validator(deps: Deps, validator_addr: &String) -> Result<(), ContractEr
     Ok(())
 }
 
+/// Function to get the total amount staked across all validators, without querying their rewards.
+fn get_total_staked(deps: Deps) -> Result<u128, ContractError> {
+    Ok(TOTAL_DELEGATED
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .u128())
+}
+
 /// Function to get the total staked and reward amounts across all validators.
+/// The total staked is read from the delegation ledger, and only the validators with a delegation are queried for rewards.
 fn get_total_staked_and_rewards(
     deps: Deps,
     contract_address: &Addr,
 ) -> Result<(u128, u128), ContractError> {
-    let mut total_staked = 0u128;
+    let total_staked = get_total_staked(deps)?;
     let mut total_rewards = 0u128;
 
-    for validator in VALIDATORS.range(deps.storage, None, None, cosmwasm_std::Order::Ascending) {
-        let (validator_addr, _) = validator?;
+    for validator in DELEGATIONS.keys(deps.storage, None, None, cosmwasm_std::Order::Ascending) {
+        let validator_addr = validator?;
 
         if let Some(delegation) = deps
             .querier
             .query_delegation(contract_address.clone(), validator_addr)?
         {
-            total_staked += delegation.amount.amount.u128();
-
             if let Some(reward) = delegation
                 .accumulated_rewards
                 .iter()
@@ -1417,6 +2999,102 @@ fn get_total_staked_and_rewards(
     Ok((total_staked, total_rewards))
 }
 
+/// Re-syncs the delegation ledger with the delegations queried from the chain.
+/// Returns the new total delegated and a drift event for each validator whose recorded amount was off.
+fn sync_delegation_ledger(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    contract_address: &Addr,
+) -> Result<(Uint128, Vec<Event>), ContractError> {
+    let mut total_delegated = Uint128::zero();
+    let mut drift_events = vec![];
+
+    let validators = VALIDATORS
+        .keys(storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for validator_addr in validators {
+        let recorded = DELEGATIONS
+            .may_load(storage, &validator_addr)?
+            .unwrap_or_default();
+        let actual = querier
+            .query_delegation(contract_address.clone(), validator_addr.clone())?
+            .map(|d| d.amount.amount)
+            .unwrap_or_default();
+
+        if actual.is_zero() {
+            DELEGATIONS.remove(storage, &validator_addr);
+        } else {
+            DELEGATIONS.save(storage, &validator_addr, &actual)?;
+        }
+        total_delegated += actual;
+
+        if recorded != actual {
+            drift_events.push(
+                Event::new("delegation_drift")
+                    .add_attribute("validator_addr", validator_addr)
+                    .add_attribute("recorded_amount", recorded)
+                    .add_attribute("actual_amount", actual),
+            );
+        }
+    }
+    TOTAL_DELEGATED.save(storage, &total_delegated)?;
+
+    Ok((total_delegated, drift_events))
+}
+
+/// Records an amount delegated to a validator in the delegation ledger.
+fn record_delegation(
+    storage: &mut dyn Storage,
+    validator_addr: &String,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    DELEGATIONS.update(storage, validator_addr, |delegated| -> StdResult<_> {
+        Ok(delegated.unwrap_or_default() + amount)
+    })?;
+    let total_delegated = TOTAL_DELEGATED.may_load(storage)?.unwrap_or_default();
+    TOTAL_DELEGATED.save(storage, &(total_delegated + amount))?;
+    Ok(())
+}
+
+/// Pays a rounding subsidy out of the reserve and records it.
+fn pay_rounding_subsidy(
+    storage: &mut dyn Storage,
+    amount: Uint128,
+) -> Result<Event, ContractError> {
+    let reserve = RESERVE
+        .may_load(storage)?
+        .unwrap_or_default()
+        .saturating_sub(amount);
+    RESERVE.save(storage, &reserve)?;
+    let total_subsidies = RESERVE_SUBSIDIES.may_load(storage)?.unwrap_or_default() + amount;
+    RESERVE_SUBSIDIES.save(storage, &total_subsidies)?;
+
+    Ok(Event::new("rounding_subsidy_paid")
+        .add_attribute("amount", amount)
+        .add_attribute("reserve_balance", reserve)
+        .add_attribute("total_subsidies", total_subsidies))
+}
+
+/// Records an amount undelegated from a validator in the delegation ledger.
+fn record_undelegation(
+    storage: &mut dyn Storage,
+    validator_addr: &String,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let delegated = DELEGATIONS
+        .may_load(storage, validator_addr)?
+        .unwrap_or_default()
+        .saturating_sub(amount);
+    if delegated.is_zero() {
+        DELEGATIONS.remove(storage, validator_addr);
+    } else {
+        DELEGATIONS.save(storage, validator_addr, &delegated)?;
+    }
+    let total_delegated = TOTAL_DELEGATED.may_load(storage)?.unwrap_or_default();
+    TOTAL_DELEGATED.save(storage, &total_delegated.saturating_sub(amount))?;
+    Ok(())
+}
+
 /// Stakes the attached INJ to the specified validator.
 fn internal_stake(
     mut deps: DepsMut,
@@ -1469,8 +3147,23 @@ fn internal_stake(
 
     CONTRACT_REWARDS.save(deps.storage, &validator_total_rewards.into())?;
 
-    // calculate the shares to mint to the user
-    let user_shares_increase = convert_to_shares(stake_amount, share_price_num, share_price_denom)?;
+    // calculate the shares to mint to the user, net of the deposit fee which is minted to the treasury
+    let shares_increase = convert_to_shares(stake_amount, share_price_num, share_price_denom)?;
+    let deposit_fee_shares = shares_increase.multiply_ratio(staker_info.deposit_fee, FEE_PRECISION);
+    let user_shares_increase = shares_increase - deposit_fee_shares;
+
+    // enforce the limits of the user's KYC tier
+    if let Some((_, tier)) = tiers::get_user_tier(deps.as_ref(), &info.sender)? {
+        let user_shares =
+            query_balance(deps.as_ref(), user.clone())?.balance + user_shares_increase;
+        let holding = convert_to_assets(
+            user_shares.u128(),
+            share_price_num,
+            share_price_denom,
+            false,
+        )?;
+        tiers::check_deposit_limits(&tier, stake_amount, holding.into())?;
+    }
 
     // mint shares to the user
     let contract_addr = env.contract.address.clone();
@@ -1486,43 +3179,29 @@ fn internal_stake(
         user_shares_increase,
     )?;
 
-    // calculate the fees to mint to the treasury for the liquid rewards on the validator
+    // calculate the fees to mint to the treasury for the liquid rewards on the validator and the deposit
     let treasury_shares_to_mint = calculate_treasury_fees(
         validator_total_rewards,
         fee,
         share_price_num,
         share_price_denom,
-    )?;
+    )? + deposit_fee_shares;
 
     if !treasury_shares_to_mint.is_zero() {
-        // As we are executing two cw_20 actions in one transaction, we must add one action event as a submessage
-        // to ensure separate wasm events are emitted for both actions so that they may be correctly indexed.
-        let fee_mint = execute_mint(
+        let fee_mint_msgs = mint_fee_shares(
             deps.branch(),
-            env,
-            MessageInfo {
-                sender: contract_addr.clone(),
-                funds: vec![],
-            },
-            staker_info.treasury.clone().into_string(),
+            &env,
+            &staker_info.fee_recipients,
             treasury_shares_to_mint,
         )?;
-        let fee_event_msg = to_json_binary(&ExecuteMsg::EmitEvent {
-            attributes: fee_mint.attributes,
-        })?;
-
-        let cw_20_msg = WasmMsg::Execute {
-            contract_addr: contract_addr.into_string(),
-            msg: fee_event_msg,
-            funds: vec![],
-        };
-        mint_res = mint_res.add_message(cw_20_msg);
+        mint_res = mint_res.add_messages(fee_mint_msgs);
     }
 
     // sweep contract rewards
     let new_stake_amount = stake_amount + contract_rewards;
 
     // delegate to the validator
+    record_delegation(deps.storage, &validator_addr, new_stake_amount)?;
     let delegate_msg = StakingMsg::Delegate {
         validator: validator_addr.to_string(),
         amount: Coin {
@@ -1534,8 +3213,8 @@ fn internal_stake(
     let new_shares_total_supply = shares_supply + user_shares_increase + treasury_shares_to_mint;
 
     let user_balance = query_balance(deps.as_ref(), user)?.balance;
-    let treasury_balance =
-        query_balance(deps.as_ref(), staker_info.treasury.into_string())?.balance;
+    let fee_recipient_balances =
+        fee_recipient_balances(deps.as_ref(), &staker_info.fee_recipients)?;
 
     Ok(mint_res.add_message(delegate_msg).add_event(
         Event::new("deposited")
@@ -1544,8 +3223,9 @@ fn internal_stake(
             .add_attribute("amount", stake_amount)
             .add_attribute("contract_rewards", contract_rewards)
             .add_attribute("user_shares_minted", user_shares_increase)
+            .add_attribute("deposit_fee_shares", deposit_fee_shares)
             .add_attribute("treasury_shares_minted", treasury_shares_to_mint)
-            .add_attribute("treasury_balance", treasury_balance)
+            .add_attribute("fee_recipient_balances", fee_recipient_balances)
             .add_attribute(
                 "total_staked",
                 Uint128::from(total_staked) + new_stake_amount,
@@ -1613,6 +3293,11 @@ fn internal_unstake(
     // check that the amount of shares to burn is greater than 0
     ensure!(shares_to_burn > 0, ContractError::SharesAmountTooLow);
 
+    // the withdrawal fee is deducted from the claim and remains staked, with the equivalent TruINJ minted to the treasury
+    let withdrawal_fee =
+        assets_to_unstake * u128::from(staker_info.withdrawal_fee) / u128::from(FEE_PRECISION);
+    let assets_to_claim = assets_to_unstake - withdrawal_fee;
+
     let (validator_total_staked, validator_total_rewards) = deps
         .querier
         .query_delegation(contract_addr.clone(), validator_addr.clone())?
@@ -1634,7 +3319,7 @@ fn internal_unstake(
     // and cover the difference using the validator’s staking rewards (validator_total_rewards), which are transferred directly to the staker,
     // and the staking rewards held in the contract (contract_rewards).
     // The reasoning behind this, is so that if there is a sole user, they should be able to withdraw their max_withdraw amount in one transaction.
-    let mut actual_amount_to_unstake = assets_to_unstake;
+    let mut actual_amount_to_unstake = assets_to_claim;
     let mut excess_unstaked_amount = 0;
     if actual_amount_to_unstake > validator_total_staked {
         excess_unstaked_amount = actual_amount_to_unstake - validator_total_staked;
@@ -1660,47 +3345,28 @@ fn internal_unstake(
 
     // add unbond request to the claims list
     let expiration = UNBONDING_PERIOD.after(&env.block);
-    CLAIMS.create_claim(
-        deps.storage,
-        &user_addr,
-        assets_to_unstake.into(),
-        expiration,
-    )?;
+    CLAIMS.create_claim(deps.storage, &user_addr, assets_to_claim.into(), expiration)?;
 
     // burn the user shares
     let mut res = execute_burn(deps.branch(), env.clone(), info, shares_to_burn.into())?;
 
-    // calculate the fees to mint to the treasury for the liquid rewards on the validator
-    let treasury_shares_to_mint = calculate_treasury_fees(
-        validator_total_rewards,
-        fee,
-        share_price_num,
-        share_price_denom,
-    )?;
+    // calculate the fees to mint to the treasury for the liquid rewards on the validator and the withdrawal
+    let treasury_shares_to_mint =
+        calculate_treasury_fees(
+            validator_total_rewards,
+            fee,
+            share_price_num,
+            share_price_denom,
+        )? + convert_to_shares(withdrawal_fee.into(), share_price_num, share_price_denom)?;
 
     if !treasury_shares_to_mint.is_zero() {
-        // // As we are executing two cw_20 actions in one transaction, we must add one action event as a submessage
-        // // to ensure separate wasm events are emitted for both actions so that they may be correctly indexed.
-        let fee_mint = execute_mint(
+        let fee_mint_msgs = mint_fee_shares(
             deps.branch(),
-            env,
-            MessageInfo {
-                sender: contract_addr.clone(),
-                funds: vec![],
-            },
-            staker_info.treasury.clone().into_string(),
+            &env,
+            &staker_info.fee_recipients,
             treasury_shares_to_mint,
         )?;
-        let fee_event_msg = to_json_binary(&ExecuteMsg::EmitEvent {
-            attributes: fee_mint.attributes,
-        })?;
-
-        let cw_20_msg = WasmMsg::Execute {
-            contract_addr: contract_addr.into_string(),
-            msg: fee_event_msg,
-            funds: vec![],
-        };
-        res = res.add_message(cw_20_msg);
+        res = res.add_messages(fee_mint_msgs);
     }
 
     let new_total_staked = total_staked - actual_amount_to_unstake;
@@ -1708,6 +3374,11 @@ fn internal_unstake(
 
     // check if any INJ needs to be unstaked
     if actual_amount_to_unstake > 0 {
+        record_undelegation(
+            deps.storage,
+            &validator_addr,
+            actual_amount_to_unstake.into(),
+        )?;
         res = res.add_message(StakingMsg::Undelegate {
             validator: validator_addr.to_string(),
             amount: Coin {
@@ -1718,13 +3389,14 @@ fn internal_unstake(
     }
 
     let user_shares_balance = query_balance(deps.as_ref(), user_addr.to_string())?.balance;
-    let treasury_balance =
-        query_balance(deps.as_ref(), staker_info.treasury.into_string())?.balance;
+    let fee_recipient_balances =
+        fee_recipient_balances(deps.as_ref(), &staker_info.fee_recipients)?;
 
     Ok(res.add_event(
         Event::new("unstaked")
             .add_attribute("user", user_addr)
             .add_attribute("amount", assets_to_unstake.to_string())
+            .add_attribute("withdrawal_fee_inj", withdrawal_fee.to_string())
             .add_attribute("validator_addr", validator_addr)
             .add_attribute("user_balance", user_shares_balance)
             .add_attribute("user_shares_burned", shares_to_burn.to_string())
@@ -1732,7 +3404,7 @@ fn internal_unstake(
                 "treasury_shares_minted",
                 treasury_shares_to_mint.to_string(),
             )
-            .add_attribute("treasury_balance", treasury_balance)
+            .add_attribute("fee_recipient_balances", fee_recipient_balances)
             .add_attribute("total_staked", new_total_staked.to_string())
             .add_attribute("total_supply", new_shares_supply.to_string())
             .add_attribute("expires_at", expiration.get_value().to_string()),
@@ -1794,6 +3466,9 @@ fn internal_redelegate(
         Ok(rewards)
     })?;
 
+    record_undelegation(deps.storage, &src_validator_addr, assets.into())?;
+    record_delegation(deps.storage, &dst_validator_addr, assets.into())?;
+
     let mut res = Response::new();
     res = res.add_message(StakingMsg::Redelegate {
         src_validator: src_validator_addr.clone(),
@@ -1813,7 +3488,7 @@ fn internal_redelegate(
 }
 
 /// Converts an amount of INJ tokens to the equivalent TruINJ amount.
-fn convert_to_shares(
+pub(crate) fn convert_to_shares(
     inj_amount: Uint128,
     share_price_num: Uint256,
     share_price_denom: Uint256,
@@ -1826,85 +3501,287 @@ fn convert_to_shares(
     Ok(shares)
 }
 
-/// Converts an amount of TruINJ shares to the equivalent INJ amount, with the desired rounding.
-fn convert_to_assets(
-    shares: u128,
-    share_price_num: Uint256,
-    share_price_denom: Uint256,
-    rounding_up: bool,
-) -> Result<u128, ContractError> {
-    let x = Uint512::from(shares);
-    let y = Uint512::from(share_price_num);
-    let denominator = Uint512::from(share_price_denom)
-        .checked_mul(Uint512::from(SHARE_PRICE_SCALING_FACTOR))
-        .unwrap();
-
-    let mut assets = x * y / denominator;
-    let remainder = (x * y) % denominator;
+/// Converts an amount of TruINJ shares to the equivalent INJ amount, with the desired rounding.
+pub(crate) fn convert_to_assets(
+    shares: u128,
+    share_price_num: Uint256,
+    share_price_denom: Uint256,
+    rounding_up: bool,
+) -> Result<u128, ContractError> {
+    let x = Uint512::from(shares);
+    let y = Uint512::from(share_price_num);
+    let denominator = Uint512::from(share_price_denom)
+        .checked_mul(Uint512::from(SHARE_PRICE_SCALING_FACTOR))
+        .unwrap();
+
+    let mut assets = x * y / denominator;
+    let remainder = (x * y) % denominator;
+
+    if rounding_up && !remainder.is_zero() {
+        assets += Uint512::one();
+    }
+
+    let result = Uint128::try_from(assets)?;
+    Ok(result.u128())
+}
+
+/// Calculates the share price using the provided parameters.
+fn internal_share_price(
+    total_staked: u128,
+    total_assets: u128,
+    total_rewards: u128,
+    shares_supply: u128,
+    fee: u16,
+) -> (Uint256, Uint256) {
+    if shares_supply == 0 {
+        return (Uint256::from(SHARE_PRICE_SCALING_FACTOR), Uint256::one());
+    };
+    let total_capital = Uint256::from(total_staked + total_assets) * Uint256::from(FEE_PRECISION)
+        + Uint256::from(total_rewards) * Uint256::from(FEE_PRECISION - fee);
+
+    let price_num = total_capital * Uint256::from(SHARE_PRICE_SCALING_FACTOR);
+    let price_denom = Uint256::from(shares_supply) * Uint256::from(FEE_PRECISION);
+
+    (price_num, price_denom)
+}
+
+/// Distributes rewards for the given allocation.
+fn internal_distribute(
+    mut deps: DepsMut,
+    env: Env,
+    allocation: Allocation,
+    in_inj: bool,
+    global_share_price: &GetSharePriceResponse,
+    attached_inj_amount: u128,
+    staker_info: &StakerInfo,
+) -> Result<Response, ContractError> {
+    let amounts = calculate_distribution_amounts(
+        &allocation,
+        global_share_price.numerator,
+        global_share_price.denominator,
+        staker_info.distribution_fee,
+    )?;
+
+    // check that the distributor has attached enough INJ, or holds enough TruINJ, and can pay the fees
+    check_distribution_funds(
+        deps.as_ref(),
+        &allocation.allocator,
+        in_inj,
+        attached_inj_amount,
+        amounts,
+    )?;
+
+    let mut response = apply_distribution(
+        deps.branch(),
+        &env,
+        allocation.clone(),
+        in_inj,
+        global_share_price,
+        global_share_price,
+        amounts,
+        staker_info,
+    )?;
+
+    let refund_amount = if in_inj {
+        attached_inj_amount - amounts.0
+    } else {
+        attached_inj_amount
+    };
+    if refund_amount > 0 {
+        response = response.add_message(BankMsg::Send {
+            to_address: allocation.allocator.to_string(),
+            amount: vec![Coin {
+                denom: INJ.to_string(),
+                amount: refund_amount.into(),
+            }],
+        });
+    }
+
+    Ok(response)
+}
+
+/// Checks whether an allocation has rewards to distribute, i.e. whether its share price is below the global share price.
+/// An allocation share price above the global one, e.g. due to slashing, has no rewards to distribute.
+fn has_rewards_to_distribute(
+    allocation: &Allocation,
+    global_share_price: &GetSharePriceResponse,
+) -> bool {
+    allocation.share_price_num / allocation.share_price_denom
+        < global_share_price.numerator / global_share_price.denominator
+}
+
+/// Returns the allocation with the INJ amount of a percentage allocation recomputed from the allocator's
+/// current TruINJ balance. Fixed allocations are returned as is.
+/// The TruINJ recorded by the allocation is valued at the share price of the allocation, while TruINJ the
+/// allocator has gained since is valued at the global share price and re-weights the share price of the
+/// allocation, so that it doesn't earn the rewards accrued before it was held.
+fn effective_allocation(
+    deps: Deps,
+    allocation: Allocation,
+    global_share_price: &GetSharePriceResponse,
+) -> Result<Allocation, ContractError> {
+    let Some(bps) = allocation.bps else {
+        return Ok(allocation);
+    };
+
+    let balance = query_balance(deps, allocation.allocator.to_string())?.balance;
+    let shares = balance.multiply_ratio(bps, FEE_PRECISION);
+    let recorded_shares = if allocation.inj_amount.is_zero() {
+        Uint128::zero()
+    } else {
+        convert_to_shares(
+            allocation.inj_amount,
+            allocation.share_price_num,
+            allocation.share_price_denom,
+        )?
+    };
+
+    let added_inj_amount = convert_to_assets(
+        shares.saturating_sub(recorded_shares).u128(),
+        global_share_price.numerator,
+        global_share_price.denominator,
+        false,
+    )?;
+    if added_inj_amount == 0 {
+        let inj_amount = convert_to_assets(
+            shares.u128(),
+            allocation.share_price_num,
+            allocation.share_price_denom,
+            false,
+        )?;
+        return Ok(Allocation {
+            inj_amount: inj_amount.into(),
+            ..allocation
+        });
+    }
+
+    calculate_updated_allocation(
+        &allocation,
+        added_inj_amount.into(),
+        global_share_price.numerator,
+        global_share_price.denominator,
+    )
+}
+
+/// Returns the claim approval with its period and claimed amount reset if its period has ended.
+fn current_claim_period(approval: ClaimApproval, now: u64) -> ClaimApproval {
+    if now < approval.period_start + approval.period {
+        return approval;
+    }
+    // start the new period at the last period boundary
+    let elapsed_periods = (now - approval.period_start) / approval.period;
+    ClaimApproval {
+        period_start: approval.period_start + elapsed_periods * approval.period,
+        claimed_in_period: Uint128::zero(),
+        ..approval
+    }
+}
 
-    if rounding_up && !remainder.is_zero() {
-        assets += Uint512::one();
+/// Converts legacy staker info into staker info with the treasury as the only fee recipient.
+/// Staker info already in the current format is left unchanged.
+fn migrate_staker_info(storage: &mut dyn Storage) -> StdResult<()> {
+    if STAKER_INFO.load(storage).is_ok() {
+        return Ok(());
     }
 
-    let result = Uint128::try_from(assets)?;
-    Ok(result.u128())
+    let legacy = LEGACY_STAKER_INFO.load(storage)?;
+    STAKER_INFO.save(
+        storage,
+        &StakerInfo {
+            fee_recipients: vec![FeeRecipient {
+                address: legacy.treasury,
+                bps: FEE_PRECISION,
+            }],
+            fee: legacy.fee,
+            min_deposit: legacy.min_deposit,
+            distribution_fee: legacy.distribution_fee,
+            deposit_fee: 0,
+            withdrawal_fee: 0,
+        },
+    )
 }
 
-/// Calculates the share price using the provided parameters.
-fn internal_share_price(
-    total_staked: u128,
-    total_assets: u128,
-    total_rewards: u128,
-    shares_supply: u128,
-    fee: u16,
-) -> (Uint256, Uint256) {
-    if shares_supply == 0 {
-        return (Uint256::from(SHARE_PRICE_SCALING_FACTOR), Uint256::one());
-    };
-    let total_capital = Uint256::from(total_staked + total_assets) * Uint256::from(FEE_PRECISION)
-        + Uint256::from(total_rewards) * Uint256::from(FEE_PRECISION - fee);
+/// Funds the rounding reserve of a contract that predates it with the INJ held beyond the contract rewards and pending claims.
+/// A reserve already tracked is left unchanged.
+fn migrate_reserve(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    contract_address: &Addr,
+) -> StdResult<()> {
+    if RESERVE.may_load(storage)?.is_some() {
+        return Ok(());
+    }
 
-    let price_num = total_capital * Uint256::from(SHARE_PRICE_SCALING_FACTOR);
-    let price_denom = Uint256::from(shares_supply) * Uint256::from(FEE_PRECISION);
+    let contract_balance = querier.query_balance(contract_address, INJ)?.amount;
+    let contract_rewards = CONTRACT_REWARDS.may_load(storage)?.unwrap_or_default();
+    let pending_claims = PENDING_CLAIMS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| Ok(item?.1.iter().map(|claim| claim.amount).sum::<Uint128>()))
+        .sum::<StdResult<Uint128>>()?;
+
+    let reserve = contract_balance
+        .saturating_sub(contract_rewards)
+        .saturating_sub(pending_claims);
+    RESERVE.save(storage, &reserve)
+}
 
-    (price_num, price_denom)
+/// Rebuilds the recipient index of the allocations.
+fn rebuild_allocation_recipient_index(storage: &mut dyn Storage) -> StdResult<()> {
+    let entries = allocations()
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (key, allocation) in entries {
+        // re-saving an entry indexes it
+        allocations().save(storage, key, &allocation)?;
+    }
+    Ok(())
 }
 
-/// Distributes rewards for the given allocation.
-fn internal_distribute(
-    mut deps: DepsMut,
-    env: Env,
-    allocation: Allocation,
+/// Checks that a distributor has attached enough INJ, or holds enough TruINJ, for the given
+/// (INJ, TruINJ, fees) distribution amounts, and holds enough TruINJ to pay the fees.
+fn check_distribution_funds(
+    deps: Deps,
+    distributor: &Addr,
     in_inj: bool,
-    global_share_price: &GetSharePriceResponse,
     attached_inj_amount: u128,
-    staker_info: &StakerInfo,
-) -> Result<Response, ContractError> {
-    let dist_fee = staker_info.distribution_fee;
-    let treasury = &staker_info.treasury;
-    let mut refund_amount = attached_inj_amount;
-
-    let (assets_to_distribute, shares_to_distribute, fees) = calculate_distribution_amounts(
-        &allocation,
-        global_share_price.numerator,
-        global_share_price.denominator,
-        dist_fee,
-    )?;
-
-    let mut response = Response::new();
+    (assets_to_distribute, shares_to_distribute, fees): (u128, u128, u128),
+) -> Result<(), ContractError> {
+    let distributor_balance = query_balance(deps, distributor.to_string())?.balance.u128();
     if in_inj {
         ensure!(
             attached_inj_amount >= assets_to_distribute,
             ContractError::InsufficientInjAttached
         );
         ensure!(
-            query_balance(deps.as_ref(), allocation.allocator.clone().into_string())?
-                .balance
-                .u128()
-                >= fees,
+            distributor_balance >= fees,
+            ContractError::InsufficientTruINJBalance
+        );
+    } else {
+        ensure!(
+            distributor_balance >= shares_to_distribute + fees,
             ContractError::InsufficientTruINJBalance
         );
+    }
+    Ok(())
+}
 
+/// Sends the rewards of an allocation to its recipient, pays the distribution fees and advances
+/// the share price of the allocation to the given one, which is the global share price unless the
+/// rewards are only partly paid. The distributor's funds must have been checked beforehand.
+#[allow(clippy::too_many_arguments)]
+fn apply_distribution(
+    mut deps: DepsMut,
+    env: &Env,
+    allocation: Allocation,
+    in_inj: bool,
+    global_share_price: &GetSharePriceResponse,
+    allocation_share_price: &GetSharePriceResponse,
+    (assets_to_distribute, shares_to_distribute, fees): (u128, u128, u128),
+    staker_info: &StakerInfo,
+) -> Result<Response, ContractError> {
+    let mut response = Response::new();
+    if in_inj {
         // return the message to send INJ to the recipient
         response = response.add_message(BankMsg::Send {
             to_address: allocation.recipient.to_string(),
@@ -1913,17 +3790,7 @@ fn internal_distribute(
                 amount: assets_to_distribute.into(),
             }],
         });
-        refund_amount -= assets_to_distribute;
     } else {
-        // check that the distributor has enough TruINJ to distribute and pay the fees
-        ensure!(
-            query_balance(deps.as_ref(), allocation.allocator.clone().into_string())?
-                .balance
-                .u128()
-                >= shares_to_distribute + fees,
-            ContractError::InsufficientTruINJBalance
-        );
-
         // transfer the rewards in TruINJ to the recipient
         let transfer_res = execute_transfer(
             deps.branch(),
@@ -1948,9 +3815,14 @@ fn internal_distribute(
         response = response.add_message(cw_20_msg);
     };
 
-    // transfer fees to the treasury
-    if fees > 0 {
-        // transfer the rewards in TruINJ to the recipient
+    // transfer fees to the fee recipients
+    let fee_transfers =
+        distribution_fee_transfers(deps.as_ref(), fees, &staker_info.fee_recipients)?;
+    let fees_paid: Uint128 = fee_transfers
+        .iter()
+        .map(|(_, fee_shares)| *fee_shares)
+        .sum();
+    for (fee_recipient, fee_shares) in fee_transfers {
         let transfer_fee_res = execute_transfer(
             deps.branch(),
             env.clone(),
@@ -1958,48 +3830,36 @@ fn internal_distribute(
                 sender: allocation.allocator.clone(),
                 funds: vec![],
             },
-            treasury.to_string(),
-            Uint128::from(fees),
+            fee_recipient.to_string(),
+            fee_shares,
         )?;
-
-        let transfer_fee_event_msg = to_json_binary(&ExecuteMsg::EmitEvent {
-            attributes: transfer_fee_res.attributes,
-        })?;
-
-        let cw_20_msg = WasmMsg::Execute {
-            contract_addr: env.contract.address.into_string(),
-            msg: transfer_fee_event_msg,
-            funds: vec![],
-        };
-        response = response.add_message(cw_20_msg);
+        response = response.add_message(emit_event_msg(env, transfer_fee_res.attributes)?);
     }
 
-    // update the share price of the allocation
+    // update the share price of the allocation, and record the INJ value of a percentage allocation
     allocations().update(
         deps.storage,
         (allocation.allocator.clone(), allocation.recipient.clone()),
         |existing| -> Result<_, ContractError> {
             let mut updated_alloc = existing.unwrap();
-            updated_alloc.share_price_num = global_share_price.numerator;
-            updated_alloc.share_price_denom = global_share_price.denominator;
+            updated_alloc.share_price_num = allocation_share_price.numerator;
+            updated_alloc.share_price_denom = allocation_share_price.denominator;
+            if updated_alloc.bps.is_some() {
+                updated_alloc.inj_amount = allocation.inj_amount;
+            }
             Ok(updated_alloc)
         },
     )?;
 
-    if refund_amount > 0 {
-        response = response.add_message(BankMsg::Send {
-            to_address: allocation.allocator.to_string(),
-            amount: vec![Coin {
-                denom: INJ.to_string(),
-                amount: refund_amount.into(),
-            }],
-        });
-    }
-
-    let total_allocated = get_total_allocated(deps.as_ref(), allocation.allocator.clone())?;
+    let total_allocated = get_total_allocated(
+        deps.as_ref(),
+        allocation.allocator.clone(),
+        global_share_price,
+    )?;
     let recipient_balance =
         query_balance(deps.as_ref(), allocation.recipient.clone().into_string())?.balance;
-    let treasury_balance = query_balance(deps.as_ref(), treasury.clone().into_string())?.balance;
+    let fee_recipient_balances =
+        fee_recipient_balances(deps.as_ref(), &staker_info.fee_recipients)?;
     let user_balance =
         query_balance(deps.as_ref(), allocation.allocator.clone().into_string())?.balance;
 
@@ -2008,13 +3868,13 @@ fn internal_distribute(
         .add_attribute("recipient", allocation.recipient)
         .add_attribute("user_balance", user_balance.to_string())
         .add_attribute("recipient_balance", recipient_balance.to_string())
-        .add_attribute("treasury_balance", treasury_balance.to_string())
-        .add_attribute("fees", fees.to_string())
+        .add_attribute("fee_recipient_balances", fee_recipient_balances)
+        .add_attribute("fees", fees_paid.to_string())
         .add_attribute("shares", shares_to_distribute.to_string())
         .add_attribute("inj_amount", assets_to_distribute.to_string())
         .add_attribute("in_inj", in_inj.to_string())
-        .add_attribute("share_price_num", global_share_price.numerator)
-        .add_attribute("share_price_denom", global_share_price.denominator)
+        .add_attribute("share_price_num", allocation_share_price.numerator)
+        .add_attribute("share_price_denom", allocation_share_price.denominator)
         .add_attribute(
             "total_allocated_amount",
             total_allocated.total_allocated_amount,
@@ -2031,7 +3891,7 @@ fn internal_distribute(
     Ok(response.add_event(distribution_event))
 }
 
-/// Mints fees to the treasury for the amount of staking rewards provided.
+/// Calculates the fees in TruINJ to mint to the fee recipients for the amount of staking rewards provided.
 fn calculate_treasury_fees(
     rewards: u128,
     fee: u16,
@@ -2048,6 +3908,123 @@ fn calculate_treasury_fees(
     Ok(treasury_shares_increase)
 }
 
+/// Validates the given (address, bps) fee recipients.
+/// The bps of each recipient must be non-zero and the bps of all recipients must sum to FEE_PRECISION.
+pub(crate) fn validate_fee_recipients(
+    deps: Deps,
+    fee_recipients: &[(String, u16)],
+) -> Result<Vec<FeeRecipient>, ContractError> {
+    let mut validated: Vec<FeeRecipient> = Vec::with_capacity(fee_recipients.len());
+    for (address, bps) in fee_recipients {
+        let address = deps.api.addr_validate(address)?;
+        ensure!(*bps > 0, ContractError::ZeroFeeRecipientShare);
+        ensure!(
+            !validated.iter().any(|r| r.address == address),
+            ContractError::DuplicateFeeRecipient
+        );
+        validated.push(FeeRecipient { address, bps: *bps });
+    }
+
+    let total_bps: u32 = validated.iter().map(|r| u32::from(r.bps)).sum();
+    ensure!(
+        total_bps == u32::from(FEE_PRECISION),
+        ContractError::InvalidFeeRecipientShares
+    );
+    Ok(validated)
+}
+
+/// Splits the given fee shares between the fee recipients according to their bps.
+/// Any rounding remainder is assigned to the first recipient.
+pub(crate) fn split_fee_shares(
+    fee_shares: Uint128,
+    fee_recipients: &[FeeRecipient],
+) -> Vec<(Addr, Uint128)> {
+    let mut splits: Vec<(Addr, Uint128)> = fee_recipients
+        .iter()
+        .map(|r| {
+            (
+                r.address.clone(),
+                fee_shares.multiply_ratio(r.bps, FEE_PRECISION),
+            )
+        })
+        .collect();
+
+    let total_split: Uint128 = splits.iter().map(|(_, shares)| *shares).sum();
+    if let Some((_, first_shares)) = splits.first_mut() {
+        *first_shares += fee_shares - total_split;
+    }
+    splits
+}
+
+/// Splits the given distribution fees between the fee recipients, leaving out recipients without a share
+/// and frozen recipients, whose share is not charged and stays with the distributor.
+fn distribution_fee_transfers(
+    deps: Deps,
+    fees: u128,
+    fee_recipients: &[FeeRecipient],
+) -> StdResult<Vec<(Addr, Uint128)>> {
+    let mut transfers = vec![];
+    for (fee_recipient, fee_shares) in split_fee_shares(fees.into(), fee_recipients) {
+        if !fee_shares.is_zero() && !whitelist::is_user_blacklisted(deps, &fee_recipient)? {
+            transfers.push((fee_recipient, fee_shares));
+        }
+    }
+    Ok(transfers)
+}
+
+/// Mints the given fee shares to the fee recipients.
+/// As the mints are executed alongside other cw20 actions in the same transaction, each mint event is
+/// returned as a message to ensure separate wasm events are emitted so that they may be correctly indexed.
+fn mint_fee_shares(
+    mut deps: DepsMut,
+    env: &Env,
+    fee_recipients: &[FeeRecipient],
+    fee_shares: Uint128,
+) -> Result<Vec<WasmMsg>, ContractError> {
+    let mut msgs = vec![];
+    for (fee_recipient, shares) in split_fee_shares(fee_shares, fee_recipients) {
+        if shares.is_zero() {
+            continue;
+        }
+        let fee_mint = execute_mint(
+            deps.branch(),
+            env.clone(),
+            MessageInfo {
+                sender: env.contract.address.clone(),
+                funds: vec![],
+            },
+            fee_recipient.into_string(),
+            shares,
+        )?;
+        msgs.push(emit_event_msg(env, fee_mint.attributes)?);
+    }
+    Ok(msgs)
+}
+
+/// Returns the message re-emitting the given cw20 event attributes through the contract.
+pub(crate) fn emit_event_msg(
+    env: &Env,
+    attributes: Vec<Attribute>,
+) -> Result<WasmMsg, ContractError> {
+    Ok(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::EmitEvent { attributes })?,
+        funds: vec![],
+    })
+}
+
+/// Returns the TruINJ balances of the fee recipients as a JSON encoded list of (address, balance) pairs.
+fn fee_recipient_balances(
+    deps: Deps,
+    fee_recipients: &[FeeRecipient],
+) -> Result<String, ContractError> {
+    let balances = fee_recipients
+        .iter()
+        .map(|r| query_balance(deps, r.address.to_string()).map(|b| (r.address.clone(), b.balance)))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(to_json_string(&balances)?)
+}
+
 /// Calculates the updated allocation values.
 fn calculate_updated_allocation(
     existing: &Allocation,
@@ -2076,9 +4053,46 @@ fn calculate_updated_allocation(
         inj_amount: existing.inj_amount + amount,
         share_price_num,
         share_price_denom,
+        bps: existing.bps,
     })
 }
 
+/// Returns the distribution amounts of an allocation's rewards capped at the given TruINJ, including fees,
+/// and the share price to advance the allocation to, so that the rewards left unpaid stay owed.
+/// The rewards owed must exceed the cap.
+fn capped_distribution_amounts(
+    allocation: &Allocation,
+    global_share_price: &GetSharePriceResponse,
+    max_shares: Uint128,
+    distribution_fee: u16,
+) -> Result<((u128, u128, u128), GetSharePriceResponse), ContractError> {
+    let fees = max_shares.u128() * distribution_fee as u128 / FEE_PRECISION as u128;
+    let shares_to_distribute = max_shares.u128() - fees;
+    let assets_to_distribute = convert_to_assets(
+        shares_to_distribute,
+        global_share_price.numerator,
+        global_share_price.denominator,
+        false,
+    )?;
+
+    // the TruINJ value of the allocation at its share price, less the TruINJ paid out,
+    // sets the new share price of the allocation
+    let allocation_shares = Uint512::from(allocation.inj_amount)
+        * Uint512::from(allocation.share_price_denom)
+        * Uint512::from(SHARE_PRICE_SCALING_FACTOR)
+        / Uint512::from(allocation.share_price_num);
+    let remaining_shares = Uint256::try_from(allocation_shares - Uint512::from(max_shares))?;
+
+    Ok((
+        (assets_to_distribute, shares_to_distribute, fees),
+        GetSharePriceResponse {
+            numerator: Uint256::from(allocation.inj_amount)
+                * Uint256::from(SHARE_PRICE_SCALING_FACTOR),
+            denominator: remaining_shares,
+        },
+    ))
+}
+
 /// For a given allocation returns a tuple containing the amount of INJ and TruINJ required for the distribution,
 /// as well as the fees in TruINJ that will be paid to the treasury.
 fn calculate_distribution_amounts(
@@ -2141,6 +4155,7 @@ pub fn test_allocate(
             inj_amount: amount,
             share_price_num: share_price_response.numerator,
             share_price_denom: share_price_response.denominator,
+            bps: None,
         },
     )?;
 
@@ -2187,10 +4202,13 @@ mod tests {
     use std::str::FromStr;
 
     use super::*;
-    use crate::state::{UserStatus, IS_PAUSED, WHITELIST_USERS};
-    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use crate::state::{whitelist_users, LegacyStakerInfo, UserStatus};
+    use cosmwasm_std::testing::{
+        message_info, mock_dependencies, mock_dependencies_with_balance, mock_env,
+    };
     use cosmwasm_std::{
-        coins, from_json, Addr, ConversionOverflowError, Decimal, DivideByZeroError, Uint128,
+        coin, coins, from_json, Addr, ConversionOverflowError, Decimal, DivideByZeroError,
+        FullDelegation, Uint128,
     };
     use cw20::BalanceResponse;
     use cw_multi_test::{App, ContractWrapper, Executor, IntoBech32};
@@ -2243,7 +4261,13 @@ mod tests {
         let res = query(deps.as_ref(), mock_env(), QueryMsg::GetStakerInfo {}).unwrap();
         let value: GetStakerInfoResponse = from_json(&res).unwrap();
         assert_eq!(value.default_validator, default_validator);
-        assert_eq!(value.treasury, treasury);
+        assert_eq!(
+            value.fee_recipients,
+            vec![FeeRecipient {
+                address: Addr::unchecked(treasury),
+                bps: FEE_PRECISION,
+            }]
+        );
         assert_eq!(value.owner, owner.into_string());
     }
 
@@ -2341,10 +4365,248 @@ mod tests {
 
         // mock a whitelisted user
         let user = "user".into_bech32();
-        let _ = WHITELIST_USERS.save(&mut deps.storage, &user, &UserStatus::Whitelisted);
+        let _ = whitelist_users().save(&mut deps.storage, user.clone(), &UserStatus::Whitelisted);
 
         // verify that Ok() is returned
-        assert!(whitelist::check_whitelisted(deps.as_ref(), &user).is_ok())
+        assert!(whitelist::check_whitelisted(deps.as_ref(), &mock_env(), &user).is_ok())
+    }
+
+    #[test]
+    fn test_rebuild_user_status_index() {
+        let mut deps = mock_dependencies();
+
+        // mock users stored before the whitelist was indexed by status
+        let legacy_users: cw_storage_plus::Map<&Addr, UserStatus> =
+            cw_storage_plus::Map::new("whitelist_users");
+        let alice = "alice".into_bech32();
+        let bob = "bob".into_bech32();
+        legacy_users
+            .save(&mut deps.storage, &alice, &UserStatus::Whitelisted)
+            .unwrap();
+        legacy_users
+            .save(&mut deps.storage, &bob, &UserStatus::Blacklisted)
+            .unwrap();
+
+        whitelist::rebuild_user_status_index(&mut deps.storage).unwrap();
+
+        // verify that the users are indexed and counted by status
+        assert_eq!(
+            whitelist::list_users_by_status(deps.as_ref(), UserStatus::Whitelisted, None, None),
+            Ok(vec![alice])
+        );
+        assert_eq!(
+            whitelist::count_users_by_status(deps.as_ref(), UserStatus::Blacklisted),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn test_migrate_staker_info() {
+        let mut deps = mock_dependencies();
+
+        // mock staker info stored before fees could be split between fee recipients
+        let treasury: Addr = "treasury".into_bech32();
+        LEGACY_STAKER_INFO
+            .save(
+                &mut deps.storage,
+                &LegacyStakerInfo {
+                    treasury: treasury.clone(),
+                    fee: 1000,
+                    min_deposit: ONE_INJ,
+                    distribution_fee: 500,
+                },
+            )
+            .unwrap();
+
+        migrate_staker_info(&mut deps.storage).unwrap();
+
+        // verify that the treasury receives all fees
+        let staker_info = STAKER_INFO.load(&deps.storage).unwrap();
+        assert_eq!(
+            staker_info,
+            StakerInfo {
+                fee_recipients: vec![FeeRecipient {
+                    address: treasury,
+                    bps: FEE_PRECISION,
+                }],
+                fee: 1000,
+                min_deposit: ONE_INJ,
+                distribution_fee: 500,
+                deposit_fee: 0,
+                withdrawal_fee: 0,
+            }
+        );
+
+        // verify that migrating again leaves the staker info unchanged
+        migrate_staker_info(&mut deps.storage).unwrap();
+        assert_eq!(STAKER_INFO.load(&deps.storage).unwrap(), staker_info);
+    }
+
+    #[test]
+    fn test_migrate_reserve() {
+        let env = mock_env();
+        let mut deps = mock_dependencies_with_balance(&coins(10_000, INJ));
+
+        // mock a contract that predates the reserve, holding contract rewards and a pending claim
+        CONTRACT_REWARDS
+            .save(&mut deps.storage, &Uint128::new(1_000))
+            .unwrap();
+        CLAIMS
+            .create_claim(
+                &mut deps.storage,
+                &"user".into_bech32(),
+                Uint128::new(8_500),
+                UNBONDING_PERIOD.after(&env.block),
+            )
+            .unwrap();
+
+        migrate_reserve(
+            &mut deps.storage,
+            QuerierWrapper::new(&deps.querier),
+            &env.contract.address,
+        )
+        .unwrap();
+
+        // verify that the reserve holds the INJ not owed to anyone
+        assert_eq!(RESERVE.load(&deps.storage).unwrap(), Uint128::new(500));
+
+        // verify that migrating again leaves the reserve unchanged
+        RESERVE.save(&mut deps.storage, &Uint128::new(700)).unwrap();
+        migrate_reserve(
+            &mut deps.storage,
+            QuerierWrapper::new(&deps.querier),
+            &env.contract.address,
+        )
+        .unwrap();
+        assert_eq!(RESERVE.load(&deps.storage).unwrap(), Uint128::new(700));
+    }
+
+    #[test]
+    fn test_pending_claims_storage_layout() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let user: Addr = "user".into_bech32();
+
+        CLAIMS
+            .create_claim(
+                &mut deps.storage,
+                &user,
+                Uint128::new(100),
+                UNBONDING_PERIOD.after(&env.block),
+            )
+            .unwrap();
+
+        // verify that the raw view reads the claims created through the claims API
+        let pending_claims = PENDING_CLAIMS.load(&deps.storage, &user).unwrap();
+        assert_eq!(
+            pending_claims,
+            CLAIMS.query_claims(deps.as_ref(), &user).unwrap().claims
+        );
+
+        // verify that removing the claims through the raw view removes them from the claims API
+        PENDING_CLAIMS.remove(&mut deps.storage, &user);
+        assert!(CLAIMS
+            .query_claims(deps.as_ref(), &user)
+            .unwrap()
+            .claims
+            .is_empty());
+    }
+
+    #[test]
+    fn test_sync_delegation_ledger() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        // mock a contract upgraded before the delegation ledger, with delegations on chain only
+        let validator: String = "validator".into_bech32().into_string();
+        let unused_validator: String = "unused-validator".into_bech32().into_string();
+        VALIDATORS
+            .save(&mut deps.storage, &validator, &ValidatorState::Enabled)
+            .unwrap();
+        VALIDATORS
+            .save(
+                &mut deps.storage,
+                &unused_validator,
+                &ValidatorState::Enabled,
+            )
+            .unwrap();
+        deps.querier.staking.update(
+            INJ,
+            &[],
+            &[FullDelegation::create(
+                env.contract.address.clone(),
+                validator.clone(),
+                coin(1000, INJ),
+                coin(1000, INJ),
+                vec![],
+            )],
+        );
+
+        let (total_delegated, drift_events) = sync_delegation_ledger(
+            &mut deps.storage,
+            QuerierWrapper::new(&deps.querier),
+            &env.contract.address,
+        )
+        .unwrap();
+
+        // verify that the ledger is filled from the delegations on chain
+        assert_eq!(total_delegated, Uint128::new(1000));
+        assert_eq!(drift_events.len(), 1);
+        assert_eq!(
+            DELEGATIONS.load(&deps.storage, &validator).unwrap(),
+            Uint128::new(1000)
+        );
+        assert!(!DELEGATIONS.has(&deps.storage, &unused_validator));
+        assert_eq!(
+            TOTAL_DELEGATED.load(&deps.storage).unwrap(),
+            Uint128::new(1000)
+        );
+    }
+
+    #[test]
+    fn test_staker_info_without_deposit_and_withdrawal_fees() {
+        // staker info stored before deposit and withdrawal fees were introduced
+        let treasury: Addr = "treasury".into_bech32();
+        let stored = format!(
+            r#"{{"fee_recipients":[{{"address":"{treasury}","bps":10000}}],"fee":1000,"min_deposit":1,"distribution_fee":500}}"#
+        );
+
+        let staker_info: StakerInfo = from_json(stored).unwrap();
+        assert_eq!(staker_info.deposit_fee, 0);
+        assert_eq!(staker_info.withdrawal_fee, 0);
+    }
+
+    #[test]
+    fn test_rebuild_allocation_recipient_index() {
+        let mut deps = mock_dependencies();
+
+        // mock an allocation stored before the allocations were indexed by recipient
+        let legacy_allocations: cw_storage_plus::Map<(Addr, Addr), Allocation> =
+            cw_storage_plus::Map::new("allocations");
+        let allocation = Allocation {
+            allocator: "alice".into_bech32(),
+            recipient: "bob".into_bech32(),
+            ..Allocation::default()
+        };
+        legacy_allocations
+            .save(
+                &mut deps.storage,
+                (allocation.allocator.clone(), allocation.recipient.clone()),
+                &allocation,
+            )
+            .unwrap();
+
+        rebuild_allocation_recipient_index(&mut deps.storage).unwrap();
+
+        // verify that the allocation is indexed by recipient
+        let response = query::get_allocations_to_recipient(
+            deps.as_ref(),
+            allocation.recipient.clone(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(response.allocations, vec![allocation]);
     }
 
     #[test]
@@ -2355,7 +4617,7 @@ mod tests {
         let user = "user".into_bech32();
 
         // verify that the expected error is returned
-        let error = whitelist::check_whitelisted(deps.as_ref(), &user);
+        let error = whitelist::check_whitelisted(deps.as_ref(), &mock_env(), &user);
         assert!(error.is_err());
         assert_eq!(error, Err(ContractError::UserNotWhitelisted));
     }
@@ -2364,7 +4626,9 @@ mod tests {
     fn test_check_not_paused_when_not_paused() {
         // mock contract is not paused
         let mut deps = mock_dependencies();
-        IS_PAUSED.save(&mut deps.storage, &false).unwrap();
+        PAUSE_STATUS
+            .save(&mut deps.storage, &PauseStatus::Unpaused)
+            .unwrap();
 
         // verify that check_not_paused returns Ok(())
         let result = check_not_paused(deps.as_ref());
@@ -2375,13 +4639,31 @@ mod tests {
     fn test_check_not_paused_when_paused() {
         // mock contract is paused
         let mut deps = mock_dependencies();
-        IS_PAUSED.save(&mut deps.storage, &true).unwrap();
+        PAUSE_STATUS
+            .save(&mut deps.storage, &PauseStatus::HardPaused)
+            .unwrap();
 
         // verify that check_not_paused returns the expected error
         let result = check_not_paused(deps.as_ref());
         assert_eq!(result.err().unwrap(), ContractError::ContractPaused);
     }
 
+    #[test]
+    fn test_check_not_hard_paused_when_soft_paused() {
+        // mock contract is soft paused
+        let mut deps = mock_dependencies();
+        PAUSE_STATUS
+            .save(&mut deps.storage, &PauseStatus::SoftPaused)
+            .unwrap();
+
+        // verify that only check_not_paused returns an error
+        assert!(check_not_hard_paused(deps.as_ref()).is_ok());
+        assert_eq!(
+            check_not_paused(deps.as_ref()).err().unwrap(),
+            ContractError::ContractPaused
+        );
+    }
+
     #[test]
     fn test_share_price_with_zero_shares_supply() {
         let total_staked: u128 = 0;
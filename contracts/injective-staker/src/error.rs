@@ -19,9 +19,30 @@ pub enum ContractError {
     #[error("Only the pending owner can call this method")]
     NotPendingOwner,
 
+    #[error("The pending owner has expired")]
+    PendingOwnerExpired,
+
+    #[error("Renouncing ownership must be confirmed with the contract address")]
+    InvalidRenounceConfirmation,
+
+    #[error("The contract has no owner")]
+    NoOwner,
+
     #[error("Fee cannot be larger than fee precision")]
     FeeTooLarge,
 
+    #[error("Keeper bounty cannot be larger than the treasury fee")]
+    KeeperBountyAboveFee,
+
+    #[error("Fee recipient shares must sum to fee precision")]
+    InvalidFeeRecipientShares,
+
+    #[error("Fee recipient share cannot be zero")]
+    ZeroFeeRecipientShare,
+
+    #[error("Duplicate fee recipient")]
+    DuplicateFeeRecipient,
+
     #[error("Minimum deposit amount is too small")]
     MinimumDepositTooSmall,
 
@@ -77,6 +98,16 @@ pub enum ContractError {
     #[error("Agent does not exist")]
     AgentDoesNotExist,
 
+    // Role Errors
+    #[error("Caller does not have the {role} role")]
+    MissingRole { role: String },
+
+    #[error("Role {role} already granted")]
+    RoleAlreadyGranted { role: String },
+
+    #[error("Role {role} not granted")]
+    RoleNotGranted { role: String },
+
     #[error("User already whitelisted")]
     UserAlreadyWhitelisted,
 
@@ -89,12 +120,81 @@ pub enum ContractError {
     #[error("User not whitelisted")]
     UserNotWhitelisted,
 
+    #[error("Status change reason must be at most {max} characters")]
+    StatusReasonTooLong { max: usize },
+
+    // KYC Tier Errors
+    #[error("KYC tier name must be between 1 and {max} characters")]
+    InvalidKycTierName { max: usize },
+
+    #[error("KYC tier not found")]
+    KycTierNotFound,
+
+    #[error("Deposit exceeds the KYC tier limit")]
+    DepositAboveTierLimit,
+
+    #[error("Holding exceeds the KYC tier limit")]
+    HoldingAboveTierLimit,
+
+    #[error("KYC tier does not allow allocations")]
+    AllocationNotAllowedForTier,
+
+    #[error("KYC tier does not allow distributions")]
+    DistributionNotAllowedForTier,
+
+    // Compliance Errors
+    #[error("Account is frozen")]
+    AccountFrozen,
+
+    #[error("Account is not frozen")]
+    AccountNotFrozen,
+
+    #[error("Nothing to recover")]
+    NothingToRecover,
+
+    // Attestation Errors
+    #[error("Agent key must be a 33 or 65 byte secp256k1 public key")]
+    InvalidAgentKey,
+
+    #[error("Agent has no registered key")]
+    AgentKeyNotFound,
+
+    #[error("Attestation is not for this user, chain or contract")]
+    InvalidAttestation,
+
+    #[error("Attestation has expired")]
+    AttestationExpired,
+
+    #[error("Invalid attestation signature")]
+    InvalidAttestationSignature,
+
+    #[error("Attestation nonce already used")]
+    AttestationNonceUsed,
+
+    #[error("User statuses are managed by an external KYC registry")]
+    ExternalWhitelistBackend,
+
+    #[error("Whitelist expiry must be in the future and can only be set when whitelisting")]
+    InvalidWhitelistExpiry,
+
+    #[error("Batch cannot contain more than {max} entries")]
+    BatchTooLarge { max: usize },
+
     #[error("Contract is paused")]
     ContractPaused,
 
     #[error("Contract is not paused")]
     NotPaused,
 
+    #[error("Contract is already soft paused")]
+    AlreadySoftPaused,
+
+    #[error("Operation {operation} is paused")]
+    OperationPaused { operation: String },
+
+    #[error("No operations provided")]
+    NoOperationsProvided,
+
     #[error("Insufficient TruINJ balance")]
     InsufficientTruINJBalance,
 
@@ -113,6 +213,9 @@ pub enum ContractError {
     #[error("Insufficient funds on staker")]
     InsufficientStakerFunds,
 
+    #[error("Insufficient reserve balance")]
+    InsufficientReserve,
+
     #[error("Cannot allocate to self")]
     InvalidRecipient,
 
@@ -131,8 +234,95 @@ pub enum ContractError {
     #[error("No allocations")]
     NoAllocations,
 
+    #[error("Allocation to recipient uses a different allocation mode")]
+    AllocationModeMismatch,
+
+    #[error("Percentage allocations cannot exceed {max} bps in total")]
+    ExcessiveAllocationPercentage { max: u16 },
+
+    #[error("Recipient is not approved to claim rewards")]
+    NoClaimApproval,
+
+    #[error("Claim approval must have a non-zero allowance and period")]
+    InvalidClaimApproval,
+
+    #[error("Claim exceeds the allowance for the period")]
+    ClaimAllowanceExceeded,
+
+    #[error("No allocation rewards to claim")]
+    NoAllocationRewards,
+
+    #[error("No escrowed allocation to recipient")]
+    NoEscrowedAllocation,
+
+    #[error("No escrowed rewards to claim")]
+    NoEscrowedRewards,
+
     #[error("Validator is not in validator set")]
     NotInValidatorSet,
+
+    // Timelock Errors
+    #[error("Parameter change must go through the timelock")]
+    ParameterTimelocked,
+
+    #[error("ETA does not respect the timelock minimum delay")]
+    TimelockDelayTooShort,
+
+    #[error("Timelock minimum delay cannot exceed {max} seconds")]
+    TimelockDelayTooLong { max: u64 },
+
+    #[error("Parameter change not found")]
+    ParameterChangeNotFound,
+
+    #[error("Parameter change ETA has not been reached")]
+    TimelockNotExpired,
+
+    #[error("Parameter change ETA has already been reached")]
+    TimelockExpired,
+
+    // Multisig Errors
+    #[error("Caller is not a multisig signer")]
+    NotMultisigSigner,
+
+    #[error("This action requires a multisig proposal")]
+    MultisigRequired,
+
+    #[error("Multisig threshold must be between 1 and the number of signers")]
+    InvalidMultisigThreshold,
+
+    #[error("Multisig proposal duration must be greater than zero")]
+    ZeroProposalDuration,
+
+    #[error("Duplicate multisig signer")]
+    DuplicateMultisigSigner,
+
+    #[error("This message cannot be proposed")]
+    InvalidProposalMessage,
+
+    #[error("Proposal not found")]
+    ProposalNotFound,
+
+    #[error("Proposal has expired")]
+    ProposalExpired,
+
+    #[error("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+
+    #[error("Proposal already approved by signer")]
+    ProposalAlreadyApproved,
+
+    #[error("Proposal approval threshold not reached")]
+    ProposalThresholdNotReached,
+
+    #[error("Ownership cannot be renounced while the multisig is enabled")]
+    MultisigEnabled,
+
+    // Compound Errors
+    #[error("Minimum interval between compounds has not elapsed")]
+    CompoundTooSoon,
+
+    #[error("A compound sweep is in progress and must be resumed from its cursor")]
+    CompoundSweepInProgress,
 }
 
 impl From<cw20_base::ContractError> for ContractError {
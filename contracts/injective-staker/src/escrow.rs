@@ -0,0 +1,376 @@
+use crate::contract::query::get_share_price;
+use crate::contract::{
+    check_not_paused, check_operation_not_paused, convert_to_assets, convert_to_shares,
+    emit_event_msg, split_fee_shares,
+};
+use crate::*;
+use cosmwasm_std::{
+    ensure, Addr, Deps, DepsMut, Env, Event, MessageInfo, Order, Response, StdError, StdResult,
+    Storage, Uint128,
+};
+use cw20_base::contract::execute_transfer;
+use cw_storage_plus::Bound;
+use error::ContractError;
+use msg::{
+    EscrowedAllocationRewards, GetEscrowedAllocationResponse,
+    GetEscrowedAllocationsToRecipientResponse, GetSharePriceResponse,
+};
+use state::{escrowed_allocations, EscrowedAllocation, PausableOperation, STAKER_INFO};
+
+/// Locks TruINJ from the allocator in the contract on behalf of the recipient.
+/// The principal is recorded at its current INJ value, and any appreciation above it accrues to the recipient.
+pub fn allocate_escrowed(
+    mut deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    recipient: &str,
+    shares: Uint128,
+) -> Result<Response, ContractError> {
+    check_not_paused(deps.as_ref())?;
+    check_operation_not_paused(deps.as_ref(), PausableOperation::Allocate)?;
+    whitelist::check_whitelisted(deps.as_ref(), &env, &sender)?;
+    tiers::check_can_allocate(deps.as_ref(), &sender)?;
+    let recipient_addr = deps.api.addr_validate(recipient)?;
+    ensure!(recipient_addr != sender, ContractError::InvalidRecipient);
+
+    let share_price = get_share_price(deps.as_ref(), &env.contract.address);
+    let inj_amount = Uint128::new(convert_to_assets(
+        shares.u128(),
+        share_price.numerator,
+        share_price.denominator,
+        false,
+    )?);
+
+    let mut allocation = escrowed_allocations()
+        .may_load(deps.storage, (sender.clone(), recipient_addr.clone()))?
+        .unwrap_or(EscrowedAllocation {
+            allocator: sender.clone(),
+            recipient: recipient_addr.clone(),
+            shares: Uint128::zero(),
+            inj_amount: Uint128::zero(),
+        });
+    allocation.shares += shares;
+    allocation.inj_amount += inj_amount;
+    ensure!(
+        allocation.inj_amount.u128() >= ONE_INJ,
+        ContractError::AllocationUnderOneInj
+    );
+    escrowed_allocations().save(
+        deps.storage,
+        (sender.clone(), recipient_addr.clone()),
+        &allocation,
+    )?;
+
+    // lock the TruINJ in the contract
+    let transfer_res = execute_transfer(
+        deps.branch(),
+        env.clone(),
+        MessageInfo {
+            sender: sender.clone(),
+            funds: vec![],
+        },
+        env.contract.address.to_string(),
+        shares,
+    )?;
+
+    Ok(Response::new()
+        .add_message(emit_event_msg(&env, transfer_res.attributes)?)
+        .add_event(
+            Event::new("allocated_escrowed")
+                .add_attribute("user", sender)
+                .add_attribute("recipient", recipient_addr)
+                .add_attribute("shares", shares)
+                .add_attribute("amount", inj_amount)
+                .add_attribute("total_shares", allocation.shares)
+                .add_attribute("total_amount", allocation.inj_amount),
+        ))
+}
+
+/// Unlocks an amount of INJ of principal from an escrowed allocation and returns it in TruINJ to the allocator.
+/// The rewards owed to the recipient stay in escrow until they are claimed.
+pub fn deallocate_escrowed(
+    mut deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    recipient: &str,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    check_not_paused(deps.as_ref())?;
+    check_operation_not_paused(deps.as_ref(), PausableOperation::Deallocate)?;
+    whitelist::check_whitelisted(deps.as_ref(), &env, &sender)?;
+    let recipient_addr = deps.api.addr_validate(recipient)?;
+
+    let mut allocation = escrowed_allocations()
+        .may_load(deps.storage, (sender.clone(), recipient_addr.clone()))?
+        .ok_or(ContractError::NoEscrowedAllocation)?;
+    ensure!(
+        amount <= allocation.inj_amount,
+        ContractError::ExcessiveDeallocation
+    );
+
+    let remaining_amount = allocation.inj_amount - amount;
+    ensure!(
+        remaining_amount.is_zero() || remaining_amount.u128() >= ONE_INJ,
+        ContractError::AllocationUnderOneInj
+    );
+
+    let share_price = get_share_price(deps.as_ref(), &env.contract.address);
+    let principal_shares = principal_shares(&allocation, &share_price)?;
+    let shares = if remaining_amount.is_zero() {
+        principal_shares
+    } else {
+        convert_to_shares(amount, share_price.numerator, share_price.denominator)?
+            .min(principal_shares)
+    };
+
+    allocation.shares -= shares;
+    allocation.inj_amount = remaining_amount;
+    save_or_remove(deps.storage, &allocation)?;
+
+    // return the principal to the allocator
+    let transfer_res = execute_transfer(
+        deps.branch(),
+        env.clone(),
+        MessageInfo {
+            sender: env.contract.address.clone(),
+            funds: vec![],
+        },
+        sender.to_string(),
+        shares,
+    )?;
+
+    Ok(Response::new()
+        .add_message(emit_event_msg(&env, transfer_res.attributes)?)
+        .add_event(
+            Event::new("deallocated_escrowed")
+                .add_attribute("user", sender)
+                .add_attribute("recipient", recipient_addr)
+                .add_attribute("amount", amount)
+                .add_attribute("shares", shares)
+                .add_attribute("total_shares", allocation.shares)
+                .add_attribute("total_amount", allocation.inj_amount),
+        ))
+}
+
+/// Transfers the rewards accrued by an escrowed allocation to its recipient, net of distribution fees.
+pub fn claim_escrowed_rewards(
+    mut deps: DepsMut,
+    env: Env,
+    recipient: Addr,
+    allocator: &str,
+) -> Result<Response, ContractError> {
+    check_not_paused(deps.as_ref())?;
+    check_operation_not_paused(deps.as_ref(), PausableOperation::DistributeRewards)?;
+    whitelist::check_not_frozen(deps.as_ref(), &recipient)?;
+    let allocator_addr = deps.api.addr_validate(allocator)?;
+
+    let mut allocation = escrowed_allocations()
+        .may_load(deps.storage, (allocator_addr.clone(), recipient.clone()))?
+        .ok_or(ContractError::NoEscrowedAllocation)?;
+
+    let share_price = get_share_price(deps.as_ref(), &env.contract.address);
+    let staker_info = STAKER_INFO.load(deps.storage)?;
+    let rewards = allocation.shares - principal_shares(&allocation, &share_price)?;
+    ensure!(!rewards.is_zero(), ContractError::NoEscrowedRewards);
+    let (shares, fees) = split_rewards(rewards, staker_info.distribution_fee);
+
+    allocation.shares -= rewards;
+    save_or_remove(deps.storage, &allocation)?;
+
+    let contract_info = MessageInfo {
+        sender: env.contract.address.clone(),
+        funds: vec![],
+    };
+    let mut response = Response::new();
+    for (to, amount) in std::iter::once((recipient.clone(), shares))
+        .chain(split_fee_shares(fees, &staker_info.fee_recipients))
+    {
+        if amount.is_zero() {
+            continue;
+        }
+        let transfer_res = execute_transfer(
+            deps.branch(),
+            env.clone(),
+            contract_info.clone(),
+            to.to_string(),
+            amount,
+        )?;
+        response = response.add_message(emit_event_msg(&env, transfer_res.attributes)?);
+    }
+
+    let inj_amount = convert_to_assets(
+        shares.u128(),
+        share_price.numerator,
+        share_price.denominator,
+        false,
+    )?;
+
+    Ok(response.add_event(
+        Event::new("claimed_escrowed_rewards")
+            .add_attribute("user", recipient)
+            .add_attribute("allocator", allocator_addr)
+            .add_attribute("shares", shares)
+            .add_attribute("inj_amount", inj_amount.to_string())
+            .add_attribute("fees", fees),
+    ))
+}
+
+/// Releases the principal of all the escrowed allocations of an allocator, e.g. when it is recovered from a frozen account.
+/// The rewards owed to the recipients stay in escrow until they are claimed.
+/// Returns the released TruINJ, which is still held by the contract, and the number of allocations released.
+pub fn release_principal(
+    deps: DepsMut,
+    env: &Env,
+    allocator: &Addr,
+) -> Result<(Uint128, usize), ContractError> {
+    let allocations = escrowed_allocations()
+        .prefix(allocator.clone())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, allocation)| allocation))
+        .collect::<StdResult<Vec<_>>>()?;
+    if allocations.is_empty() {
+        return Ok((Uint128::zero(), 0));
+    }
+
+    let share_price = get_share_price(deps.as_ref(), &env.contract.address);
+    let mut released_shares = Uint128::zero();
+    for mut allocation in allocations.iter().cloned() {
+        let shares = principal_shares(&allocation, &share_price)?;
+        allocation.shares -= shares;
+        allocation.inj_amount = Uint128::zero();
+        save_or_remove(deps.storage, &allocation)?;
+        released_shares += shares;
+    }
+
+    Ok((released_shares, allocations.len()))
+}
+
+/// Returns an escrowed allocation, if any, and the rewards its recipient can currently claim.
+pub fn get_escrowed_allocation(
+    deps: Deps,
+    env: &Env,
+    allocator: &Addr,
+    recipient: &Addr,
+) -> StdResult<GetEscrowedAllocationResponse> {
+    let allocation =
+        escrowed_allocations().may_load(deps.storage, (allocator.clone(), recipient.clone()))?;
+
+    let (claimable_shares, claimable_inj) = match &allocation {
+        Some(allocation) => {
+            let share_price = get_share_price(deps, &env.contract.address);
+            let fee = STAKER_INFO.load(deps.storage)?.distribution_fee;
+            claimable_rewards(allocation, &share_price, fee)
+                .map_err(|err| StdError::generic_err(err.to_string()))?
+        }
+        None => (Uint128::zero(), Uint128::zero()),
+    };
+
+    Ok(GetEscrowedAllocationResponse {
+        allocation,
+        claimable_shares,
+        claimable_inj,
+    })
+}
+
+/// Returns the escrowed allocations made to a recipient, ordered by allocator, and the rewards it can currently claim from each.
+pub fn get_escrowed_allocations_to_recipient(
+    deps: Deps,
+    env: &Env,
+    recipient: Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<GetEscrowedAllocationsToRecipientResponse> {
+    let limit = limit
+        .unwrap_or(DEFAULT_ALLOCATION_QUERY_LIMIT)
+        .min(MAX_ALLOCATION_QUERY_LIMIT) as usize;
+    let start = start_after
+        .map(|allocator| deps.api.addr_validate(&allocator))
+        .transpose()?
+        .map(|allocator| Bound::exclusive((allocator, recipient.clone())));
+
+    let share_price = get_share_price(deps, &env.contract.address);
+    let fee = STAKER_INFO.load(deps.storage)?.distribution_fee;
+
+    let escrowed_allocations = escrowed_allocations()
+        .idx
+        .recipient
+        .prefix(recipient)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, allocation) = item?;
+            let (claimable_shares, claimable_inj) =
+                claimable_rewards(&allocation, &share_price, fee)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?;
+            Ok(EscrowedAllocationRewards {
+                allocation,
+                claimable_shares,
+                claimable_inj,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(GetEscrowedAllocationsToRecipientResponse {
+        escrowed_allocations,
+    })
+}
+
+/// Returns the TruINJ the recipient of an escrowed allocation can claim, net of fees, and its INJ value.
+fn claimable_rewards(
+    allocation: &EscrowedAllocation,
+    share_price: &GetSharePriceResponse,
+    distribution_fee: u16,
+) -> Result<(Uint128, Uint128), ContractError> {
+    let rewards = allocation.shares - principal_shares(allocation, share_price)?;
+    let (shares, _) = split_rewards(rewards, distribution_fee);
+    let inj = convert_to_assets(
+        shares.u128(),
+        share_price.numerator,
+        share_price.denominator,
+        false,
+    )?;
+    Ok((shares, Uint128::new(inj)))
+}
+
+/// Returns the TruINJ backing the principal of an escrowed allocation at the given share price, rounded up.
+/// If the share price has dropped below that of the principal, e.g. due to slashing, all the escrowed TruINJ is principal.
+fn principal_shares(
+    allocation: &EscrowedAllocation,
+    share_price: &GetSharePriceResponse,
+) -> Result<Uint128, ContractError> {
+    let mut shares = convert_to_shares(
+        allocation.inj_amount,
+        share_price.numerator,
+        share_price.denominator,
+    )?;
+    if convert_to_assets(
+        shares.u128(),
+        share_price.numerator,
+        share_price.denominator,
+        false,
+    )? < allocation.inj_amount.u128()
+    {
+        shares += Uint128::one();
+    }
+    Ok(shares.min(allocation.shares))
+}
+
+/// Splits escrowed rewards into the TruINJ for the recipient and the distribution fees.
+fn split_rewards(rewards: Uint128, distribution_fee: u16) -> (Uint128, Uint128) {
+    let fees = rewards.multiply_ratio(distribution_fee, FEE_PRECISION);
+    (rewards - fees, fees)
+}
+
+/// Saves an escrowed allocation, or removes it once it holds no TruINJ and no principal.
+fn save_or_remove(
+    storage: &mut dyn Storage,
+    allocation: &EscrowedAllocation,
+) -> Result<(), ContractError> {
+    let key = (allocation.allocator.clone(), allocation.recipient.clone());
+    if allocation.shares.is_zero() && allocation.inj_amount.is_zero() {
+        escrowed_allocations().remove(storage, key)?;
+    } else {
+        escrowed_allocations().save(storage, key, allocation)?;
+    }
+    Ok(())
+}
@@ -1,8 +1,15 @@
+pub mod attestation;
+pub mod compliance;
 pub mod constants;
 pub mod contract;
 mod error;
+pub mod escrow;
 pub mod msg;
+pub mod multisig;
+pub mod roles;
 pub mod state;
+pub mod tiers;
+pub mod timelock;
 pub mod whitelist;
 
 pub use crate::constants::*;
@@ -1,6 +1,10 @@
-use crate::state::{Allocation, UserStatus, ValidatorInfo};
+use crate::state::{
+    Allocation, Attestation, ClaimApproval, EscrowedAllocation, FeeRecipient, KycTier,
+    ParameterChange, PausableOperation, PauseStatus, PendingParameterChange, Proposal, Role,
+    TimelockedParameter, UserStatus, UserStatusChange, ValidatorInfo, WhitelistBackend,
+};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Attribute, Binary, Uint128, Uint256};
+use cosmwasm_std::{Addr, Attribute, Binary, Uint128, Uint256};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -20,11 +24,18 @@ pub enum ExecuteMsg {
     SetDistributionFee {
         new_distribution_fee: u16,
     },
+    SetDepositFee {
+        new_deposit_fee: u16,
+    },
+    SetWithdrawalFee {
+        new_withdrawal_fee: u16,
+    },
     SetMinimumDeposit {
         new_min_deposit: Uint128,
     },
-    SetTreasury {
-        new_treasury_addr: String,
+    /// Sets the (address, bps) recipients of the treasury fees. The bps must sum to 10,000.
+    SetFeeRecipients {
+        new_fee_recipients: Vec<(String, u16)>,
     },
     SetDefaultValidator {
         new_default_validator_addr: String,
@@ -74,22 +85,140 @@ pub enum ExecuteMsg {
     RemoveAgent {
         agent: String,
     },
+    // Role messages
+    GrantRole {
+        role: Role,
+        account: String,
+    },
+    RevokeRole {
+        role: Role,
+        account: String,
+    },
     SetPendingOwner {
         new_owner: String,
     },
+    CancelPendingOwner {},
     ClaimOwnership {},
+    /// Permanently removes the owner. `confirm` must be the address of the contract.
+    /// Fails while the multisig is enabled.
+    RenounceOwnership {
+        confirm: String,
+    },
+    /// Whitelists a user, optionally until a given time in seconds.
+    /// Can also be used to renew the KYC approval of a whitelisted user.
+    /// The optional reason code of a status change is stored in the user's status history.
     AddUserToWhitelist {
         user: String,
+        valid_until: Option<u64>,
+        reason: Option<String>,
     },
     AddUserToBlacklist {
         user: String,
+        reason: Option<String>,
     },
     ClearUserStatus {
         user: String,
+        reason: Option<String>,
+    },
+    /// Registers or rotates the secp256k1 public key the calling agent signs attestations with.
+    RegisterAgentKey {
+        public_key: Binary,
+    },
+    RevokeAgentKey {},
+    /// Sets the caller's status from an attestation signed by an agent.
+    SelfWhitelist {
+        attestation: Attestation,
+        signature: Binary,
+    },
+    /// Creates or updates a KYC tier.
+    /// The holding and deposit limits of a tier are only enforced when staking.
+    SetKycTier {
+        name: String,
+        tier: KycTier,
+    },
+    /// Assigns a user to a KYC tier, or removes them from their tier if `tier` is not set.
+    SetUserTier {
+        user: String,
+        tier: Option<String>,
+    },
+    /// Moves TruINJ from a frozen account to `to`, or burns it if `to` is not set.
+    ComplianceRecover {
+        from: String,
+        to: Option<String>,
+        amount: Uint128,
+        /// Whether to also move the pending claims of the account to `to`, or cancel them if `to` is not set.
+        recover_claims: bool,
+        /// Whether to also recover the principal the account has locked in escrowed allocations.
+        recover_escrow: bool,
+    },
+    /// Sets whether user statuses are managed by this contract or read from an external KYC registry.
+    SetWhitelistBackend {
+        backend: WhitelistBackend,
+    },
+    /// Sets the status of several users. Invalid entries fail the whole batch,
+    /// unless skip_invalid is set, in which case they are returned in the response data.
+    BatchUpdateUserStatus {
+        entries: Vec<UserStatusEntry>,
+        skip_invalid: bool,
     },
     Pause,
+    SoftPause,
     Unpause,
-    CompoundRewards,
+    PauseOperations {
+        operations: Vec<PausableOperation>,
+    },
+    UnpauseOperations {
+        operations: Vec<PausableOperation>,
+    },
+    // Multisig messages
+    SetMultisigConfig {
+        signers: Vec<String>,
+        threshold: u32,
+        proposal_duration: u64,
+    },
+    /// Proposes a message to be executed with the authority of the owner once approved by the multisig.
+    Propose {
+        msg: Box<ExecuteMsg>,
+    },
+    ApproveProposal {
+        id: u64,
+    },
+    ExecuteProposal {
+        id: u64,
+    },
+    // Timelock messages
+    ScheduleParameterChange {
+        change: ParameterChange,
+        eta: u64,
+    },
+    ExecuteParameterChange {
+        id: u64,
+    },
+    CancelParameterChange {
+        id: u64,
+    },
+    /// Restakes the rewards of up to `limit` validators after `start_after`, skipping validators
+    /// whose pending rewards are below `min_reward`. Resumes the sweep in progress when `start_after` is not set.
+    CompoundRewards {
+        start_after: Option<String>,
+        limit: Option<u32>,
+        min_reward: Option<Uint128>,
+    },
+    // Reserve messages
+    TopUpReserve {},
+    WithdrawReserve {
+        amount: Uint128,
+    },
+    SetReserveLowWaterMark {
+        low_water_mark: Uint128,
+    },
+    /// Re-syncs the delegation ledger with the actual delegations, e.g. after a slashing event.
+    ReconcileDelegations {},
+    SetCompoundConfig {
+        keeper_bounty: u16,
+        min_compound_interval: u64,
+        keepers_only: bool,
+    },
     Allocate {
         recipient: String,
         amount: Uint128,
@@ -98,10 +227,51 @@ pub enum ExecuteMsg {
         recipient: String,
         amount: Uint128,
     },
+    /// Allocates a share, in basis points, of the rewards on the caller's TruINJ balance to the recipient.
+    /// The allocated amount follows the caller's balance. Setting the share to zero removes the allocation.
+    AllocatePercentage {
+        recipient: String,
+        bps: u16,
+    },
     DistributeRewards {
         recipient: String,
         in_inj: bool,
     },
+    /// Distributes the rewards of a page of the caller's allocations, ordered by recipient. Unused INJ is refunded.
+    DistributeAll {
+        in_inj: bool,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Approves a recipient to claim the rewards of the caller's allocation to them, up to an amount of
+    /// TruINJ, including distribution fees, per period in seconds.
+    ApproveRecipientClaims {
+        recipient: String,
+        max_per_period: Uint128,
+        period: u64,
+    },
+    /// Revokes the approval of a recipient to claim the rewards of the caller's allocation to them.
+    RevokeRecipientClaims {
+        recipient: String,
+    },
+    /// Claims the rewards of the distributor's allocation to the caller, in TruINJ out of the distributor's balance.
+    ClaimAllocationRewards {
+        distributor: String,
+    },
+    /// Locks TruINJ from the caller in escrow. The rewards it earns above its INJ value accrue to the recipient.
+    AllocateEscrowed {
+        recipient: String,
+        shares: Uint128,
+    },
+    /// Unlocks an amount of INJ of principal from an escrowed allocation. The recipient's rewards stay in escrow.
+    DeallocateEscrowed {
+        recipient: String,
+        amount: Uint128,
+    },
+    /// Claims the rewards accrued by an escrowed allocation from the given allocator to the caller.
+    ClaimEscrowedRewards {
+        allocator: String,
+    },
     // Internal messages
     Restake {
         amount: Uint128,
@@ -140,6 +310,8 @@ pub enum QueryMsg {
     MarketingInfo {},
     #[returns(GetIsOwnerResponse)]
     IsOwner { addr: String },
+    #[returns(GetPendingOwnerResponse)]
+    GetPendingOwner {},
     #[returns(GetValidatorResponse)]
     GetValidators {},
     #[returns(GetTotalStakedResponse)]
@@ -154,12 +326,57 @@ pub enum QueryMsg {
     // Whitelist queries
     #[returns(GetIsAgentResponse)]
     IsAgent { agent: String },
+    #[returns(GetAgentKeyResponse)]
+    GetAgentKey { agent: String },
+    #[returns(GetIsNonceUsedResponse)]
+    IsNonceUsed { agent: String, nonce: u64 },
+    #[returns(WhitelistBackend)]
+    GetWhitelistBackend {},
+    #[returns(ListAgentsResponse)]
+    ListAgents {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     #[returns(GetIsWhitelistedResponse)]
     IsWhitelisted { user: String },
+
+    // Role queries
+    #[returns(GetHasRoleResponse)]
+    HasRole { role: Role, account: String },
+    #[returns(GetRoleMembersResponse)]
+    GetRoleMembers { role: Role },
+
     #[returns(GetIsBlacklistedResponse)]
     IsBlacklisted { user: String },
     #[returns(GetCurrentUserStatusResponse)]
     GetCurrentUserStatus { user: String },
+    /// Only available while user statuses are managed locally.
+    #[returns(ListUsersByStatusResponse)]
+    ListUsersByStatus {
+        status: UserStatus,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Only available while user statuses are managed locally.
+    #[returns(CountUsersByStatusResponse)]
+    CountUsersByStatus { status: UserStatus },
+    #[returns(ListKycTiersResponse)]
+    ListKycTiers {},
+    #[returns(GetUserTierResponse)]
+    GetUserTier { user: String },
+    #[returns(GetUserStatusHistoryResponse)]
+    GetUserStatusHistory {
+        user: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Only available while user statuses are managed locally.
+    #[returns(GetExpiringWhitelistResponse)]
+    GetExpiringWhitelist {
+        before: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     #[returns(GetSharePriceResponse)]
     GetSharePrice {},
     #[returns(GetTotalAssetsResponse)]
@@ -170,6 +387,39 @@ pub enum QueryMsg {
     GetMaxWithdraw { user: String },
     #[returns(GetAllocationsResponse)]
     GetAllocations { user: String },
+    /// Returns the allocations made to a recipient, ordered by allocator.
+    #[returns(GetAllocationsResponse)]
+    GetAllocationsToRecipient {
+        recipient: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the rewards each distributor currently owes a recipient, ordered by distributor, and their totals.
+    #[returns(GetPendingRewardsForRecipientResponse)]
+    GetPendingRewardsForRecipient {
+        recipient: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the approval of a recipient to claim a distributor's allocation rewards, and the allowance left in the current period.
+    #[returns(GetClaimApprovalResponse)]
+    GetClaimApproval {
+        distributor: String,
+        recipient: String,
+    },
+    /// Returns an escrowed allocation and the rewards the recipient can claim from it.
+    #[returns(GetEscrowedAllocationResponse)]
+    GetEscrowedAllocation {
+        allocator: String,
+        recipient: String,
+    },
+    /// Returns the escrowed allocations made to a recipient, ordered by allocator, and the rewards it can claim from each.
+    #[returns(GetEscrowedAllocationsToRecipientResponse)]
+    GetEscrowedAllocationsToRecipient {
+        recipient: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     #[returns(GetTotalAllocatedResponse)]
     GetTotalAllocated { user: String },
     #[returns(GetDistributionAmountsResponse)]
@@ -177,17 +427,48 @@ pub enum QueryMsg {
         distributor: String,
         recipient: Option<String>,
     },
+
+    // Multisig queries
+    #[returns(GetMultisigConfigResponse)]
+    GetMultisigConfig {},
+    #[returns(GetProposalResponse)]
+    GetProposal { id: u64 },
+    #[returns(ListProposalsResponse)]
+    ListProposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    // Timelock queries
+    #[returns(GetPendingChangesResponse)]
+    GetPendingChanges {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(GetTimelockConfigResponse)]
+    GetTimelockConfig {},
+
+    #[returns(GetCompoundConfigResponse)]
+    GetCompoundConfig {},
+
+    #[returns(GetReserveStatusResponse)]
+    GetReserveStatus {},
+
+    #[returns(GetPauseMatrixResponse)]
+    GetPauseMatrix {},
 }
 
 #[cw_serde]
 pub struct GetStakerInfoResponse {
     pub owner: String,
     pub default_validator: String,
-    pub treasury: String,
+    pub fee_recipients: Vec<FeeRecipient>,
     pub fee: u16,
     pub distribution_fee: u16,
+    pub deposit_fee: u16,
+    pub withdrawal_fee: u16,
     pub min_deposit: Uint128,
-    pub is_paused: bool,
+    pub pause_status: PauseStatus,
 }
 
 #[cw_serde]
@@ -200,11 +481,27 @@ pub struct GetIsAgentResponse {
     pub is_agent: bool,
 }
 
+#[cw_serde]
+pub struct GetHasRoleResponse {
+    pub has_role: bool,
+}
+
+#[cw_serde]
+pub struct GetRoleMembersResponse {
+    pub members: Vec<Addr>,
+}
+
 #[cw_serde]
 pub struct GetIsOwnerResponse {
     pub is_owner: bool,
 }
 
+#[cw_serde]
+pub struct GetPendingOwnerResponse {
+    pub pending_owner: Option<Addr>,
+    pub expires_at: Option<u64>,
+}
+
 #[cw_serde]
 pub struct GetIsWhitelistedResponse {
     pub is_whitelisted: bool,
@@ -218,6 +515,59 @@ pub struct GetIsBlacklistedResponse {
 #[cw_serde]
 pub struct GetCurrentUserStatusResponse {
     pub user_status: UserStatus,
+    pub valid_until: Option<u64>,
+}
+
+#[cw_serde]
+pub struct GetAgentKeyResponse {
+    pub public_key: Option<Binary>,
+}
+
+#[cw_serde]
+pub struct GetIsNonceUsedResponse {
+    pub is_used: bool,
+}
+
+#[cw_serde]
+pub struct ListAgentsResponse {
+    pub agents: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct ListUsersByStatusResponse {
+    pub users: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct CountUsersByStatusResponse {
+    pub count: u64,
+}
+
+/// The configured KYC tiers. `max_holding` only limits the holding a user can reach by staking.
+#[cw_serde]
+pub struct ListKycTiersResponse {
+    pub tiers: Vec<(String, KycTier)>,
+}
+
+#[cw_serde]
+pub struct GetUserTierResponse {
+    pub tier: Option<String>,
+}
+
+#[cw_serde]
+pub struct GetUserStatusHistoryResponse {
+    pub history: Vec<UserStatusChange>,
+}
+
+#[cw_serde]
+pub struct GetExpiringWhitelistResponse {
+    pub entries: Vec<WhitelistExpiryEntry>,
+}
+
+#[cw_serde]
+pub struct WhitelistExpiryEntry {
+    pub user: Addr,
+    pub valid_until: u64,
 }
 
 #[cw_serde]
@@ -261,6 +611,53 @@ pub struct GetAllocationsResponse {
     pub allocations: Vec<Allocation>,
 }
 
+#[cw_serde]
+pub struct GetPendingRewardsForRecipientResponse {
+    /// The total rewards owed, in INJ if distributed in INJ.
+    pub inj_amount: Uint128,
+    /// The total rewards owed, in TruINJ if distributed in TruINJ.
+    pub truinj_amount: Uint128,
+    pub pending_rewards: Vec<PendingRewards>,
+    /// The last distributor scanned, to pass as `start_after` for the next page.
+    pub last_distributor: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct PendingRewards {
+    pub distributor: Addr,
+    pub inj_amount: Uint128,
+    pub truinj_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct GetClaimApprovalResponse {
+    pub approval: Option<ClaimApproval>,
+    pub remaining_allowance: Uint128,
+}
+
+#[cw_serde]
+pub struct GetEscrowedAllocationResponse {
+    pub allocation: Option<EscrowedAllocation>,
+    /// The TruINJ the recipient can claim, net of distribution fees.
+    pub claimable_shares: Uint128,
+    /// The INJ value of the claimable TruINJ.
+    pub claimable_inj: Uint128,
+}
+
+#[cw_serde]
+pub struct GetEscrowedAllocationsToRecipientResponse {
+    pub escrowed_allocations: Vec<EscrowedAllocationRewards>,
+}
+
+#[cw_serde]
+pub struct EscrowedAllocationRewards {
+    pub allocation: EscrowedAllocation,
+    /// The TruINJ the recipient can claim, net of distribution fees.
+    pub claimable_shares: Uint128,
+    /// The INJ value of the claimable TruINJ.
+    pub claimable_inj: Uint128,
+}
+
 #[cw_serde]
 pub struct GetTotalAllocatedResponse {
     pub total_allocated_amount: Uint128,
@@ -274,3 +671,98 @@ pub struct GetDistributionAmountsResponse {
     pub truinj_amount: Uint128,
     pub distribution_fee: Uint128,
 }
+
+#[cw_serde]
+pub struct GetPendingChangesResponse {
+    pub changes: Vec<PendingParameterChange>,
+}
+
+#[cw_serde]
+pub struct GetMultisigConfigResponse {
+    pub signers: Vec<Addr>,
+    pub threshold: u32,
+    pub proposal_duration: u64,
+}
+
+#[cw_serde]
+pub struct GetProposalResponse {
+    pub proposal: Proposal,
+}
+
+#[cw_serde]
+pub struct ListProposalsResponse {
+    pub proposals: Vec<Proposal>,
+}
+
+#[cw_serde]
+pub struct GetTimelockConfigResponse {
+    pub min_delay: u64,
+    pub timelocked_parameters: Vec<TimelockedParameter>,
+}
+
+#[cw_serde]
+pub struct OperationPauseState {
+    pub operation: PausableOperation,
+    pub is_paused: bool,
+}
+
+#[cw_serde]
+pub struct GetPauseMatrixResponse {
+    pub pause_status: PauseStatus,
+    pub operations: Vec<OperationPauseState>,
+}
+
+#[cw_serde]
+pub struct GetReserveStatusResponse {
+    pub balance: Uint128,
+    pub total_subsidies: Uint128,
+    pub low_water_mark: Uint128,
+    pub is_below_low_water_mark: bool,
+    /// The INJ of cancelled claims still undelegating, which is credited to the reserve once it arrives.
+    pub pending_cancelled_claims: Uint128,
+}
+
+#[cw_serde]
+pub struct GetCompoundConfigResponse {
+    pub keeper_bounty: u16,
+    pub min_compound_interval: u64,
+    pub keepers_only: bool,
+    pub last_compound_time: u64,
+    pub compound_cursor: Option<String>,
+}
+
+/// An entry of BatchUpdateUserStatus. The expiry can only be set when whitelisting,
+/// and the optional reason code is stored in the user's status history.
+#[cw_serde]
+pub struct UserStatusEntry {
+    pub user: String,
+    pub status: UserStatus,
+    pub valid_until: Option<u64>,
+    pub reason: Option<String>,
+}
+
+/// The response data of BatchUpdateUserStatus.
+#[cw_serde]
+pub struct BatchUpdateUserStatusResponse {
+    pub skipped: Vec<SkippedUserStatusEntry>,
+}
+
+#[cw_serde]
+pub struct SkippedUserStatusEntry {
+    pub user: String,
+    pub status: UserStatus,
+    pub reason: String,
+}
+
+/// The query interface an external KYC registry must implement to be used as the whitelist backend.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum KycRegistryQueryMsg {
+    #[returns(KycRegistryUserStatusResponse)]
+    UserStatus { user: String },
+}
+
+#[cw_serde]
+pub struct KycRegistryUserStatusResponse {
+    pub status: UserStatus,
+}
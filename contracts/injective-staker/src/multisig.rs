@@ -0,0 +1,275 @@
+use crate::contract::{check_owner, dispatch, load_pause_status};
+use crate::msg::ExecuteMsg;
+use crate::*;
+use cosmwasm_std::{
+    ensure, to_json_string, Addr, Deps, DepsMut, Env, Event, MessageInfo, Order, Response,
+    StdResult,
+};
+use cw_storage_plus::Bound;
+use error::ContractError;
+use state::{
+    MultisigConfig, PauseStatus, Proposal, ProposalStatus, MULTISIG_CONFIG, OWNER, PROPOSALS,
+    PROPOSAL_COUNT,
+};
+
+/// Sets the multisig signers, the number of approvals required and how long proposals stay open.
+/// Once signers are set, this can only be changed through a multisig proposal.
+pub fn set_multisig_config(
+    deps: DepsMut,
+    caller: Addr,
+    signers: Vec<String>,
+    threshold: u32,
+    proposal_duration: u64,
+) -> Result<Response, ContractError> {
+    // check that the caller is the owner
+    check_owner(deps.as_ref(), &caller)?;
+
+    let mut signer_addrs: Vec<Addr> = Vec::with_capacity(signers.len());
+    for signer in &signers {
+        let signer_addr = deps.api.addr_validate(signer)?;
+        ensure!(
+            !signer_addrs.contains(&signer_addr),
+            ContractError::DuplicateMultisigSigner
+        );
+        signer_addrs.push(signer_addr);
+    }
+
+    // an empty signer set disables the multisig
+    ensure!(
+        (signer_addrs.is_empty() && threshold == 0)
+            || (threshold > 0 && threshold as usize <= signer_addrs.len()),
+        ContractError::InvalidMultisigThreshold
+    );
+    ensure!(proposal_duration > 0, ContractError::ZeroProposalDuration);
+
+    MULTISIG_CONFIG.save(
+        deps.storage,
+        &MultisigConfig {
+            signers: signer_addrs.clone(),
+            threshold,
+            proposal_duration,
+        },
+    )?;
+
+    Ok(Response::new().add_event(
+        Event::new("set_multisig_config")
+            .add_attribute("signers", to_json_string(&signer_addrs)?)
+            .add_attribute("threshold", threshold.to_string())
+            .add_attribute("proposal_duration", proposal_duration.to_string()),
+    ))
+}
+
+/// Creates a proposal to execute a message with the authority of the owner.
+/// The proposer's approval is counted automatically.
+pub fn propose(
+    deps: DepsMut,
+    env: Env,
+    caller: Addr,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    let config = get_multisig_config(deps.as_ref())?;
+    check_signer(&config, &caller)?;
+    ensure!(is_proposable(&msg), ContractError::InvalidProposalMessage);
+
+    let id = PROPOSAL_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    PROPOSAL_COUNT.save(deps.storage, &id)?;
+
+    let expires_at = env.block.time.seconds() + config.proposal_duration;
+    PROPOSALS.save(
+        deps.storage,
+        id,
+        &Proposal {
+            id,
+            proposer: caller.clone(),
+            msg: msg.clone(),
+            approvals: vec![caller.clone()],
+            expires_at,
+            status: ProposalStatus::Open,
+        },
+    )?;
+
+    Ok(Response::new().add_event(
+        Event::new("proposal_created")
+            .add_attribute("id", id.to_string())
+            .add_attribute("proposer", caller)
+            .add_attribute("msg", to_json_string(&msg)?)
+            .add_attribute("expires_at", expires_at.to_string()),
+    ))
+}
+
+/// Approves an open proposal.
+pub fn approve_proposal(
+    deps: DepsMut,
+    env: Env,
+    caller: Addr,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let config = get_multisig_config(deps.as_ref())?;
+    check_signer(&config, &caller)?;
+
+    let mut proposal = load_open_proposal(deps.as_ref(), &env, id)?;
+    ensure!(
+        !proposal.approvals.contains(&caller),
+        ContractError::ProposalAlreadyApproved
+    );
+    proposal.approvals.push(caller.clone());
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    Ok(Response::new().add_event(
+        Event::new("proposal_approved")
+            .add_attribute("id", id.to_string())
+            .add_attribute("signer", caller)
+            .add_attribute("approvals", count_approvals(&config, &proposal).to_string()),
+    ))
+}
+
+/// Executes a proposal that has reached the approval threshold. Can be called by anyone.
+pub fn execute_proposal(mut deps: DepsMut, env: Env, id: u64) -> Result<Response, ContractError> {
+    let config = get_multisig_config(deps.as_ref())?;
+
+    let mut proposal = load_open_proposal(deps.as_ref(), &env, id)?;
+
+    // only approvals from the current signers count towards the threshold
+    ensure!(
+        config.threshold > 0 && count_approvals(&config, &proposal) >= config.threshold,
+        ContractError::ProposalThresholdNotReached
+    );
+
+    proposal.status = ProposalStatus::Executed;
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    // execute the message with the authority of the owner
+    let owner = OWNER
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoOwner)?;
+    let info = MessageInfo {
+        sender: owner,
+        funds: vec![],
+    };
+    let response = dispatch(deps.branch(), env, info, proposal.msg)?;
+
+    Ok(response.add_event(Event::new("proposal_executed").add_attribute("id", id.to_string())))
+}
+
+/// Checks that a message can be executed directly, i.e. that it does not require the multisig.
+/// Returns MultisigRequired error if the multisig is enabled and the message is guarded.
+pub(crate) fn check_not_multisig_guarded(
+    deps: Deps,
+    msg: &ExecuteMsg,
+) -> Result<(), ContractError> {
+    if !is_guarded(deps, msg)? {
+        return Ok(());
+    }
+    let config = get_multisig_config(deps)?;
+    ensure!(config.signers.is_empty(), ContractError::MultisigRequired);
+    Ok(())
+}
+
+/// Gets the multisig configuration.
+pub fn get_multisig_config(deps: Deps) -> StdResult<MultisigConfig> {
+    Ok(MULTISIG_CONFIG.may_load(deps.storage)?.unwrap_or_default())
+}
+
+/// Gets a page of proposals, in ascending id order.
+pub fn list_proposals(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Proposal>> {
+    let limit = limit
+        .unwrap_or(DEFAULT_PROPOSAL_LIMIT)
+        .min(MAX_PROPOSAL_LIMIT) as usize;
+
+    PROPOSALS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(_, proposal)| proposal))
+        .collect()
+}
+
+/// Checks whether an address is a multisig signer.
+/// Returns NotMultisigSigner error if not.
+fn check_signer(config: &MultisigConfig, caller: &Addr) -> Result<(), ContractError> {
+    ensure!(
+        config.signers.contains(caller),
+        ContractError::NotMultisigSigner
+    );
+    Ok(())
+}
+
+/// Loads a proposal that can still be approved or executed.
+fn load_open_proposal(deps: Deps, env: &Env, id: u64) -> Result<Proposal, ContractError> {
+    let proposal = PROPOSALS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::ProposalNotFound)?;
+    ensure!(
+        proposal.status == ProposalStatus::Open,
+        ContractError::ProposalAlreadyExecuted
+    );
+    ensure!(
+        env.block.time.seconds() < proposal.expires_at,
+        ContractError::ProposalExpired
+    );
+    Ok(proposal)
+}
+
+/// Counts the approvals of a proposal given by the current signers.
+fn count_approvals(config: &MultisigConfig, proposal: &Proposal) -> u32 {
+    proposal
+        .approvals
+        .iter()
+        .filter(|signer| config.signers.contains(signer))
+        .count() as u32
+}
+
+/// Privileged actions that require a multisig proposal once the multisig is enabled.
+fn is_guarded(deps: Deps, msg: &ExecuteMsg) -> StdResult<bool> {
+    // relaxing a hard pause to a soft pause is guarded like Unpause
+    if let ExecuteMsg::SoftPause = msg {
+        return Ok(load_pause_status(deps)? == PauseStatus::HardPaused);
+    }
+
+    Ok(matches!(
+        msg,
+        ExecuteMsg::SetFee { .. }
+            | ExecuteMsg::SetDistributionFee { .. }
+            | ExecuteMsg::SetDepositFee { .. }
+            | ExecuteMsg::SetWithdrawalFee { .. }
+            | ExecuteMsg::SetFeeRecipients { .. }
+            | ExecuteMsg::SetDefaultValidator { .. }
+            | ExecuteMsg::AddValidator { .. }
+            | ExecuteMsg::EnableValidator { .. }
+            | ExecuteMsg::DisableValidator { .. }
+            | ExecuteMsg::Redelegate { .. }
+            | ExecuteMsg::Unpause
+            | ExecuteMsg::UnpauseOperations { .. }
+            | ExecuteMsg::SetPendingOwner { .. }
+            | ExecuteMsg::RenounceOwnership { .. }
+            | ExecuteMsg::GrantRole { .. }
+            | ExecuteMsg::RevokeRole { .. }
+            | ExecuteMsg::WithdrawReserve { .. }
+            | ExecuteMsg::ScheduleParameterChange { .. }
+            | ExecuteMsg::CancelParameterChange { .. }
+            | ExecuteMsg::SetMultisigConfig { .. }
+            | ExecuteMsg::SetWhitelistBackend { .. }
+            | ExecuteMsg::ComplianceRecover { .. }
+            | ExecuteMsg::SetKycTier { .. }
+    ))
+}
+
+/// Multisig and internal messages cannot be proposed.
+fn is_proposable(msg: &ExecuteMsg) -> bool {
+    !matches!(
+        msg,
+        ExecuteMsg::Propose { .. }
+            | ExecuteMsg::ApproveProposal { .. }
+            | ExecuteMsg::ExecuteProposal { .. }
+            | ExecuteMsg::Restake { .. }
+            | ExecuteMsg::EmitEvent { .. }
+    )
+}
@@ -0,0 +1,110 @@
+use crate::*;
+use cosmwasm_std::{ensure, Addr, Deps, DepsMut, Event, Order, Response, StdResult};
+use error::ContractError;
+use state::{Role, ROLE_MEMBERS, WHITELIST_AGENTS};
+
+/// Grants a role to an account. Only the owner can grant roles.
+pub fn grant_role(
+    deps: DepsMut,
+    caller: Addr,
+    role: Role,
+    account: &String,
+) -> Result<Response, ContractError> {
+    contract::check_owner(deps.as_ref(), &caller)?;
+
+    let account_addr = deps.api.addr_validate(account)?;
+
+    // the owner implicitly holds every role
+    ensure!(
+        !contract::is_owner(deps.as_ref(), &account_addr)?,
+        ContractError::OwnerCannotBeAdded
+    );
+
+    ensure!(
+        !is_role_member(deps.as_ref(), role, &account_addr)?,
+        ContractError::RoleAlreadyGranted {
+            role: role.to_string()
+        }
+    );
+
+    // KYC agents are kept in the agent whitelist
+    match role {
+        Role::KycAgent => WHITELIST_AGENTS.save(deps.storage, &account_addr, &())?,
+        _ => ROLE_MEMBERS.save(deps.storage, (&role.to_string(), &account_addr), &())?,
+    }
+
+    Ok(Response::new().add_event(
+        Event::new("role_granted")
+            .add_attribute("role", role.to_string())
+            .add_attribute("account", account),
+    ))
+}
+
+/// Revokes a role from an account. Only the owner can revoke roles.
+pub fn revoke_role(
+    deps: DepsMut,
+    caller: Addr,
+    role: Role,
+    account: &String,
+) -> Result<Response, ContractError> {
+    contract::check_owner(deps.as_ref(), &caller)?;
+
+    let account_addr = deps.api.addr_validate(account)?;
+
+    ensure!(
+        is_role_member(deps.as_ref(), role, &account_addr)?,
+        ContractError::RoleNotGranted {
+            role: role.to_string()
+        }
+    );
+
+    match role {
+        Role::KycAgent => WHITELIST_AGENTS.remove(deps.storage, &account_addr),
+        _ => ROLE_MEMBERS.remove(deps.storage, (&role.to_string(), &account_addr)),
+    }
+
+    Ok(Response::new().add_event(
+        Event::new("role_revoked")
+            .add_attribute("role", role.to_string())
+            .add_attribute("account", account),
+    ))
+}
+
+/// Checks whether an address holds a role or is the owner.
+/// Returns MissingRole error if it is neither.
+pub(crate) fn check_role(deps: Deps, role: Role, account: &Addr) -> Result<(), ContractError> {
+    ensure!(
+        has_role(deps, role, account)?,
+        ContractError::MissingRole {
+            role: role.to_string()
+        }
+    );
+    Ok(())
+}
+
+/// Checks whether an address holds a role or is the owner.
+/// Returns true if it is either, false otherwise.
+pub fn has_role(deps: Deps, role: Role, account: &Addr) -> StdResult<bool> {
+    Ok(contract::is_owner(deps, account)? || is_role_member(deps, role, account)?)
+}
+
+/// Returns the accounts that have been granted a role, excluding the owner.
+pub fn get_role_members(deps: Deps, role: Role) -> StdResult<Vec<Addr>> {
+    match role {
+        Role::KycAgent => WHITELIST_AGENTS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect(),
+        _ => ROLE_MEMBERS
+            .prefix(&role.to_string())
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect(),
+    }
+}
+
+/// Checks whether a role has been explicitly granted to an address.
+fn is_role_member(deps: Deps, role: Role, account: &Addr) -> StdResult<bool> {
+    Ok(match role {
+        Role::KycAgent => WHITELIST_AGENTS.has(deps.storage, account),
+        _ => ROLE_MEMBERS.has(deps.storage, (&role.to_string(), account)),
+    })
+}
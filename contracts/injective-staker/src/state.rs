@@ -1,18 +1,41 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128, Uint256};
+use cosmwasm_std::{Addr, Binary, Uint128, Uint256};
 use cw20::Expiration;
-use cw_controllers::Claims;
+use cw_controllers::{Claim, Claims};
 use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use std::fmt;
 
+use crate::msg::ExecuteMsg;
+
 #[cw_serde]
 pub struct StakerInfo {
+    pub fee_recipients: Vec<FeeRecipient>,
+    pub fee: u16,
+    pub min_deposit: u128,
+    pub distribution_fee: u16,
+    /// Defaults to zero for contracts instantiated before deposit fees were introduced.
+    #[serde(default)]
+    pub deposit_fee: u16,
+    /// Defaults to zero for contracts instantiated before withdrawal fees were introduced.
+    #[serde(default)]
+    pub withdrawal_fee: u16,
+}
+
+/// The staker info stored before fees could be split between multiple fee recipients.
+#[cw_serde]
+pub struct LegacyStakerInfo {
     pub treasury: Addr,
     pub fee: u16,
     pub min_deposit: u128,
     pub distribution_fee: u16,
 }
 
+#[cw_serde]
+pub struct FeeRecipient {
+    pub address: Addr,
+    pub bps: u16,
+}
+
 #[cw_serde]
 pub struct ValidatorInfo {
     pub total_staked: Uint128,
@@ -34,6 +57,10 @@ pub struct Allocation {
     pub inj_amount: Uint128,
     pub share_price_num: Uint256,
     pub share_price_denom: Uint256,
+    /// The share of the allocator's TruINJ balance allocated, in basis points, for percentage allocations.
+    /// The INJ amount of a percentage allocation records the value of its TruINJ when it was last distributed,
+    /// and is recomputed from the allocator's balance when it is used.
+    pub bps: Option<u16>,
 }
 
 impl Default for Allocation {
@@ -44,17 +71,20 @@ impl Default for Allocation {
             inj_amount: Uint128::zero(),
             share_price_num: Uint256::zero(),
             share_price_denom: Uint256::zero(),
+            bps: None,
         }
     }
 }
 
 pub struct AllocationIndexes<'a> {
     pub allocator: MultiIndex<'a, Addr, Allocation, (Addr, Addr)>,
+    pub recipient: MultiIndex<'a, Addr, Allocation, (Addr, Addr)>,
 }
 
 impl<'a> IndexList<Allocation> for AllocationIndexes<'a> {
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Allocation>> + '_> {
-        Box::new(std::iter::once(&self.allocator as &dyn Index<Allocation>))
+        let v: Vec<&dyn Index<Allocation>> = vec![&self.allocator, &self.recipient];
+        Box::new(v.into_iter())
     }
 }
 
@@ -65,20 +95,208 @@ pub fn allocations<'a>() -> IndexedMap<(Addr, Addr), Allocation, AllocationIndex
             "allocations",
             "allocations__allocator",
         ),
+        recipient: MultiIndex::new(
+            |_pk: &[u8], d: &Allocation| d.recipient.clone(),
+            "allocations",
+            "allocations__recipient",
+        ),
     };
     IndexedMap::new("allocations", indexes)
 }
 
+pub struct EscrowedAllocationIndexes<'a> {
+    pub recipient: MultiIndex<'a, Addr, EscrowedAllocation, (Addr, Addr)>,
+}
+
+impl<'a> IndexList<EscrowedAllocation> for EscrowedAllocationIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<EscrowedAllocation>> + '_> {
+        Box::new(std::iter::once(
+            &self.recipient as &dyn Index<EscrowedAllocation>,
+        ))
+    }
+}
+
+/// The escrowed allocations, keyed by (allocator, recipient) and indexed by recipient.
+pub fn escrowed_allocations<'a>(
+) -> IndexedMap<(Addr, Addr), EscrowedAllocation, EscrowedAllocationIndexes<'a>> {
+    let indexes = EscrowedAllocationIndexes {
+        recipient: MultiIndex::new(
+            |_pk: &[u8], d: &EscrowedAllocation| d.recipient.clone(),
+            "escrowed_allocations",
+            "escrowed_allocations__recipient",
+        ),
+    };
+    IndexedMap::new("escrowed_allocations", indexes)
+}
+
+pub struct WhitelistUserIndexes<'a> {
+    pub status: MultiIndex<'a, String, UserStatus, Addr>,
+}
+
+impl<'a> IndexList<UserStatus> for WhitelistUserIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<UserStatus>> + '_> {
+        Box::new(std::iter::once(&self.status as &dyn Index<UserStatus>))
+    }
+}
+
+/// The whitelist status of each user, indexed by status. Users without a status are not stored.
+pub fn whitelist_users<'a>() -> IndexedMap<Addr, UserStatus, WhitelistUserIndexes<'a>> {
+    let indexes = WhitelistUserIndexes {
+        status: MultiIndex::new(
+            |_pk: &[u8], status: &UserStatus| status.to_string(),
+            "whitelist_users",
+            "whitelist_users__status",
+        ),
+    };
+    IndexedMap::new("whitelist_users", indexes)
+}
+
+pub struct WhitelistExpiryIndexes<'a> {
+    pub valid_until: MultiIndex<'a, u64, u64, Addr>,
+}
+
+impl<'a> IndexList<u64> for WhitelistExpiryIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<u64>> + '_> {
+        Box::new(std::iter::once(&self.valid_until as &dyn Index<u64>))
+    }
+}
+
+/// The time, in seconds, until which a whitelisted user's KYC approval is valid.
+/// Whitelisted users without an entry do not expire.
+pub fn whitelist_expiries<'a>() -> IndexedMap<Addr, u64, WhitelistExpiryIndexes<'a>> {
+    let indexes = WhitelistExpiryIndexes {
+        valid_until: MultiIndex::new(
+            |_pk: &[u8], valid_until: &u64| *valid_until,
+            "whitelist_expiries",
+            "whitelist_expiries__valid_until",
+        ),
+    };
+    IndexedMap::new("whitelist_expiries", indexes)
+}
+
 pub const STAKER_INFO: Item<StakerInfo> = Item::new("staker_info");
+/// Legacy staker info with a single treasury, superseded by STAKER_INFO and only read on migration.
+pub const LEGACY_STAKER_INFO: Item<LegacyStakerInfo> = Item::new("staker_info");
 pub const VALIDATORS: Map<&String, ValidatorState> = Map::new("validators");
 pub const DEFAULT_VALIDATOR: Item<String> = Item::new("default_validator");
 pub const WHITELIST_AGENTS: Map<&Addr, ()> = Map::new("whitelist_agents");
+/// The members of each role, keyed by role name. KYC agents are kept in WHITELIST_AGENTS.
+pub const ROLE_MEMBERS: Map<(&str, &Addr), ()> = Map::new("role_members");
 pub const OWNER: Item<Addr> = Item::new("owner");
 pub const PENDING_OWNER: Item<Addr> = Item::new("pending_owner");
-pub const WHITELIST_USERS: Map<&Addr, UserStatus> = Map::new("whitelist_users");
+/// The time, in seconds, after which the pending owner can no longer claim ownership.
+pub const PENDING_OWNER_EXPIRES_AT: Item<u64> = Item::new("pending_owner_expires_at");
+pub const WHITELIST_BACKEND: Item<WhitelistBackend> = Item::new("whitelist_backend");
+/// The secp256k1 public key each agent signs attestations with.
+pub const AGENT_KEYS: Map<&Addr, Binary> = Map::new("agent_keys");
+/// The attestation nonces already used, keyed by agent and nonce.
+pub const USED_NONCES: Map<(&Addr, u64), ()> = Map::new("used_nonces");
+/// The status changes of each user, keyed by user and a per-user sequence number.
+pub const USER_STATUS_HISTORY: Map<(&Addr, u64), UserStatusChange> =
+    Map::new("user_status_history");
+/// The KYC tiers configured by the owner, keyed by tier name.
+pub const KYC_TIERS: Map<&str, KycTier> = Map::new("kyc_tiers");
+/// The KYC tier each user is assigned to. Users without a tier are not subject to tier limits.
+pub const USER_TIERS: Map<&Addr, String> = Map::new("user_tiers");
+/// The approvals given by distributors to recipients to claim their allocation rewards, keyed by (distributor, recipient).
+pub const CLAIM_APPROVALS: Map<(&Addr, &Addr), ClaimApproval> = Map::new("claim_approvals");
+/// The number of users with each status, keyed by status name.
+pub const USER_STATUS_COUNTS: Map<&str, u64> = Map::new("user_status_counts");
+/// Legacy pause flag, superseded by PAUSE_STATUS and only read on migration.
 pub const IS_PAUSED: Item<bool> = Item::new("is_paused");
+pub const PAUSE_STATUS: Item<PauseStatus> = Item::new("pause_status");
+/// The operations that have been individually paused, keyed by operation name.
+pub const PAUSED_OPERATIONS: Map<&str, ()> = Map::new("paused_operations");
 pub const CONTRACT_REWARDS: Item<Uint128> = Item::new("contract_rewards");
 pub const CLAIMS: Claims = Claims::new("claims");
+/// A raw view of the CLAIMS storage, as `Claims` can neither list nor remove the pending claims of an account.
+/// It relies on `Claims` storing the claims of each address as a `Vec<Claim>` under its namespace,
+/// which `test_pending_claims_storage_layout` pins.
+pub const PENDING_CLAIMS: Map<&Addr, Vec<Claim>> = Map::new("claims");
+/// The amount of INJ delegated to each validator, as tracked by the contract.
+pub const DELEGATIONS: Map<&String, Uint128> = Map::new("delegations");
+pub const TOTAL_DELEGATED: Item<Uint128> = Item::new("total_delegated");
+/// The INJ set aside to cover rounding errors, and the rounding subsidies paid from it so far.
+pub const RESERVE: Item<Uint128> = Item::new("reserve");
+pub const RESERVE_SUBSIDIES: Item<Uint128> = Item::new("reserve_subsidies");
+pub const RESERVE_LOW_WATER_MARK: Item<Uint128> = Item::new("reserve_low_water_mark");
+pub const TIMELOCK_CONFIG: Item<TimelockConfig> = Item::new("timelock_config");
+pub const PARAMETER_CHANGES: Map<u64, PendingParameterChange> = Map::new("parameter_changes");
+pub const PARAMETER_CHANGE_COUNT: Item<u64> = Item::new("parameter_change_count");
+pub const COMPOUND_CONFIG: Item<CompoundConfig> = Item::new("compound_config");
+pub const LAST_COMPOUND_TIME: Item<u64> = Item::new("last_compound_time");
+/// The last validator processed by a compound sweep that is still in progress.
+pub const COMPOUND_CURSOR: Item<String> = Item::new("compound_cursor");
+/// The global share price, as (numerator, denominator), when the compound sweep in progress started.
+pub const COMPOUND_SHARE_PRICE: Item<(Uint256, Uint256)> = Item::new("compound_share_price");
+pub const MULTISIG_CONFIG: Item<MultisigConfig> = Item::new("multisig_config");
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+
+/// An agent's signed approval of a user's status, which the user can submit themselves.
+#[cw_serde]
+pub struct Attestation {
+    pub agent: String,
+    pub user: String,
+    pub status: UserStatus,
+    /// The time, in seconds, until which the user's KYC approval is valid, if it expires.
+    pub valid_until: Option<u64>,
+    /// The time, in seconds, after which the attestation can no longer be submitted.
+    pub expires_at: u64,
+    /// A number used once per agent, to prevent replaying the attestation.
+    pub nonce: u64,
+    pub chain_id: String,
+    pub contract_address: String,
+}
+
+/// Where user statuses are read from.
+#[cw_serde]
+#[derive(Default)]
+pub enum WhitelistBackend {
+    /// User statuses are managed by the agents of this contract.
+    #[default]
+    Local,
+    /// User statuses are read from a KYC registry contract implementing KycRegistryQueryMsg.
+    External { registry: Addr },
+}
+
+/// An approval for a recipient to claim the rewards of an allocation out of the distributor's TruINJ balance.
+#[cw_serde]
+pub struct ClaimApproval {
+    /// The maximum TruINJ, including distribution fees, the recipient can claim in a period.
+    pub max_per_period: Uint128,
+    /// The length of a period, in seconds.
+    pub period: u64,
+    /// The time the current period started, in seconds.
+    pub period_start: u64,
+    /// The TruINJ claimed in the current period, including distribution fees.
+    pub claimed_in_period: Uint128,
+}
+
+/// An allocation backed by TruINJ locked in the contract by the allocator.
+/// The value of the locked TruINJ above `inj_amount` accrues to the recipient.
+#[cw_serde]
+pub struct EscrowedAllocation {
+    pub allocator: Addr,
+    pub recipient: Addr,
+    /// The TruINJ held in escrow, including the rewards not yet claimed by the recipient.
+    pub shares: Uint128,
+    /// The INJ value of the principal, which the allocator can unlock.
+    pub inj_amount: Uint128,
+}
+
+/// The limits and permissions of the users in a KYC tier.
+#[cw_serde]
+pub struct KycTier {
+    /// The maximum value, in INJ, of the TruINJ a user can hold after staking, if any.
+    /// This is a deposit-only check: TruINJ received through transfers, distributions, escrow claims
+    /// or compliance recovery is not limited by it, and can take a holding above the cap.
+    pub max_holding: Option<Uint128>,
+    /// The maximum amount of INJ a user can stake at once, if any.
+    pub max_deposit: Option<Uint128>,
+    pub can_allocate: bool,
+    pub can_distribute: bool,
+}
 
 #[cw_serde]
 pub enum UserStatus {
@@ -99,6 +317,216 @@ impl fmt::Display for UserStatus {
     }
 }
 
+/// The roles that can be granted by the owner to delegate routine administration.
+/// The owner implicitly holds every role.
+#[cw_serde]
+#[derive(Copy)]
+pub enum Role {
+    Pauser,
+    ValidatorManager,
+    FeeManager,
+    KycAgent,
+    Keeper,
+}
+
+/// Implement Display for Role
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let role_str = match self {
+            Self::Pauser => "pauser",
+            Self::ValidatorManager => "validator_manager",
+            Self::FeeManager => "fee_manager",
+            Self::KycAgent => "kyc_agent",
+            Self::Keeper => "keeper",
+        };
+        write!(f, "{}", role_str)
+    }
+}
+
+/// A soft pause blocks deposits, allocations and transfers but still lets users unstake and claim.
+/// A hard pause blocks all user operations.
+#[cw_serde]
+#[derive(Copy, Default)]
+pub enum PauseStatus {
+    #[default]
+    Unpaused,
+    SoftPaused,
+    HardPaused,
+}
+
+/// Implement Display for PauseStatus
+impl fmt::Display for PauseStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status_str = match self {
+            Self::Unpaused => "unpaused",
+            Self::SoftPaused => "soft_paused",
+            Self::HardPaused => "hard_paused",
+        };
+        write!(f, "{}", status_str)
+    }
+}
+
+/// The user and keeper operations that can be paused individually.
+#[cw_serde]
+#[derive(Copy)]
+pub enum PausableOperation {
+    Stake,
+    StakeToSpecificValidator,
+    Unstake,
+    UnstakeFromSpecificValidator,
+    Redelegate,
+    Claim,
+    Allocate,
+    Deallocate,
+    DistributeRewards,
+    Transfer,
+    CompoundRewards,
+}
+
+impl PausableOperation {
+    pub const ALL: [PausableOperation; 11] = [
+        Self::Stake,
+        Self::StakeToSpecificValidator,
+        Self::Unstake,
+        Self::UnstakeFromSpecificValidator,
+        Self::Redelegate,
+        Self::Claim,
+        Self::Allocate,
+        Self::Deallocate,
+        Self::DistributeRewards,
+        Self::Transfer,
+        Self::CompoundRewards,
+    ];
+}
+
+/// Implement Display for PausableOperation
+impl fmt::Display for PausableOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operation_str = match self {
+            Self::Stake => "stake",
+            Self::StakeToSpecificValidator => "stake_to_specific_validator",
+            Self::Unstake => "unstake",
+            Self::UnstakeFromSpecificValidator => "unstake_from_specific_validator",
+            Self::Redelegate => "redelegate",
+            Self::Claim => "claim",
+            Self::Allocate => "allocate",
+            Self::Deallocate => "deallocate",
+            Self::DistributeRewards => "distribute_rewards",
+            Self::Transfer => "transfer",
+            Self::CompoundRewards => "compound_rewards",
+        };
+        write!(f, "{}", operation_str)
+    }
+}
+
+/// The admin parameters that can be placed behind the timelock.
+#[cw_serde]
+pub enum TimelockedParameter {
+    Fee,
+    DistributionFee,
+    DepositFee,
+    WithdrawalFee,
+    FeeRecipients,
+    DefaultValidator,
+}
+
+/// A change to an admin parameter that can be scheduled through the timelock.
+#[cw_serde]
+pub enum ParameterChange {
+    Fee {
+        new_fee: u16,
+    },
+    DistributionFee {
+        new_distribution_fee: u16,
+    },
+    DepositFee {
+        new_deposit_fee: u16,
+    },
+    WithdrawalFee {
+        new_withdrawal_fee: u16,
+    },
+    FeeRecipients {
+        new_fee_recipients: Vec<(String, u16)>,
+    },
+    DefaultValidator {
+        new_default_validator_addr: String,
+    },
+    TimelockConfig {
+        min_delay: u64,
+        timelocked_parameters: Vec<TimelockedParameter>,
+    },
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct TimelockConfig {
+    /// The minimum delay, in seconds, between scheduling a parameter change and its ETA.
+    pub min_delay: u64,
+    pub timelocked_parameters: Vec<TimelockedParameter>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct CompoundConfig {
+    /// The bounty paid to the caller of CompoundRewards, in bps of the rewards compounded.
+    pub keeper_bounty: u16,
+    /// The minimum interval, in seconds, between two compounds.
+    pub min_compound_interval: u64,
+    /// Whether compounding is restricted to keepers.
+    #[serde(alias = "agents_only")]
+    pub keepers_only: bool,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct MultisigConfig {
+    pub signers: Vec<Addr>,
+    /// The number of signer approvals required to execute a proposal.
+    pub threshold: u32,
+    /// The time, in seconds, a proposal stays open for approval and execution.
+    pub proposal_duration: u64,
+}
+
+#[cw_serde]
+pub enum ProposalStatus {
+    Open,
+    Executed,
+}
+
+/// A change of a user's status, recorded for auditing.
+#[cw_serde]
+pub struct UserStatusChange {
+    pub id: u64,
+    pub old_status: UserStatus,
+    pub new_status: UserStatus,
+    pub valid_until: Option<u64>,
+    /// The agent who made the change, or who signed the attestation of a self-whitelisted user.
+    pub agent: Addr,
+    /// The block time, in seconds, of the change.
+    pub timestamp: u64,
+    pub reason: Option<String>,
+}
+
+/// A message proposed for execution with the authority of the owner, once approved by the multisig.
+#[cw_serde]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Addr,
+    pub msg: ExecuteMsg,
+    pub approvals: Vec<Addr>,
+    /// The time, in seconds, after which the proposal can no longer be approved or executed.
+    pub expires_at: u64,
+    pub status: ProposalStatus,
+}
+
+#[cw_serde]
+pub struct PendingParameterChange {
+    pub id: u64,
+    pub change: ParameterChange,
+    /// The time, in seconds, after which the change can be executed.
+    pub eta: u64,
+}
+
 pub trait GetValueTrait {
     fn get_value(&self) -> u64;
 }
@@ -0,0 +1,127 @@
+use crate::contract::check_owner;
+use crate::*;
+use cosmwasm_std::{ensure, Addr, Deps, DepsMut, Event, Order, Response, StdResult, Uint128};
+use error::ContractError;
+use state::{KycTier, KYC_TIERS, USER_TIERS};
+
+/// Creates or updates a KYC tier. Only the owner can configure tiers.
+pub fn set_kyc_tier(
+    deps: DepsMut,
+    caller: Addr,
+    name: String,
+    tier: KycTier,
+) -> Result<Response, ContractError> {
+    // check that the caller is the owner
+    check_owner(deps.as_ref(), &caller)?;
+
+    ensure!(
+        !name.is_empty() && name.len() <= MAX_KYC_TIER_NAME_LENGTH,
+        ContractError::InvalidKycTierName {
+            max: MAX_KYC_TIER_NAME_LENGTH
+        }
+    );
+
+    KYC_TIERS.save(deps.storage, &name, &tier)?;
+
+    Ok(Response::new().add_event(
+        Event::new("set_kyc_tier")
+            .add_attribute("name", name)
+            .add_attribute("max_holding", optional_limit(tier.max_holding))
+            .add_attribute("max_deposit", optional_limit(tier.max_deposit))
+            .add_attribute("can_allocate", tier.can_allocate.to_string())
+            .add_attribute("can_distribute", tier.can_distribute.to_string()),
+    ))
+}
+
+/// Assigns a user to a KYC tier, or removes them from their tier. Only agents can assign tiers.
+pub fn set_user_tier(
+    deps: DepsMut,
+    caller: Addr,
+    user: &str,
+    tier: Option<String>,
+) -> Result<Response, ContractError> {
+    // check if the caller is an agent
+    whitelist::check_agent(deps.as_ref(), &caller)?;
+
+    let user_addr = deps.api.addr_validate(user)?;
+    let old_tier = USER_TIERS.may_load(deps.storage, &user_addr)?;
+
+    match &tier {
+        Some(tier) => {
+            ensure!(
+                KYC_TIERS.has(deps.storage, tier),
+                ContractError::KycTierNotFound
+            );
+            USER_TIERS.save(deps.storage, &user_addr, tier)?;
+        }
+        None => USER_TIERS.remove(deps.storage, &user_addr),
+    }
+
+    Ok(Response::new().add_event(
+        Event::new("user_tier_changed")
+            .add_attribute("user", user_addr)
+            .add_attribute("old_tier", old_tier.unwrap_or_default())
+            .add_attribute("new_tier", tier.unwrap_or_default()),
+    ))
+}
+
+/// Gets the name and configuration of a user's KYC tier, if they are assigned to one.
+pub fn get_user_tier(deps: Deps, user: &Addr) -> StdResult<Option<(String, KycTier)>> {
+    USER_TIERS
+        .may_load(deps.storage, user)?
+        .map(|name| Ok((name.clone(), KYC_TIERS.load(deps.storage, &name)?)))
+        .transpose()
+}
+
+/// Returns all KYC tiers, ordered by name.
+pub fn list_kyc_tiers(deps: Deps) -> StdResult<Vec<(String, KycTier)>> {
+    KYC_TIERS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect()
+}
+
+/// Checks a deposit, and the value of the user's holding once it is made, against the limits of a tier.
+pub(crate) fn check_deposit_limits(
+    tier: &KycTier,
+    deposit: Uint128,
+    holding: Uint128,
+) -> Result<(), ContractError> {
+    ensure!(
+        tier.max_deposit.is_none_or(|max| deposit <= max),
+        ContractError::DepositAboveTierLimit
+    );
+    ensure!(
+        tier.max_holding.is_none_or(|max| holding <= max),
+        ContractError::HoldingAboveTierLimit
+    );
+    Ok(())
+}
+
+/// Checks that the user's KYC tier, if any, allows allocations.
+/// Returns AllocationNotAllowedForTier error if not.
+pub(crate) fn check_can_allocate(deps: Deps, user: &Addr) -> Result<(), ContractError> {
+    if let Some((_, tier)) = get_user_tier(deps, user)? {
+        ensure!(
+            tier.can_allocate,
+            ContractError::AllocationNotAllowedForTier
+        );
+    }
+    Ok(())
+}
+
+/// Checks that the user's KYC tier, if any, allows distributions.
+/// Returns DistributionNotAllowedForTier error if not.
+pub(crate) fn check_can_distribute(deps: Deps, user: &Addr) -> Result<(), ContractError> {
+    if let Some((_, tier)) = get_user_tier(deps, user)? {
+        ensure!(
+            tier.can_distribute,
+            ContractError::DistributionNotAllowedForTier
+        );
+    }
+    Ok(())
+}
+
+/// Formats an optional limit as an event attribute, with an empty value for no limit.
+fn optional_limit(limit: Option<Uint128>) -> String {
+    limit.map(|limit| limit.to_string()).unwrap_or_default()
+}
@@ -0,0 +1,243 @@
+use crate::contract::{check_owner, execute, validate_fee_recipients};
+use crate::*;
+use cosmwasm_std::{
+    ensure, to_json_string, Addr, Deps, DepsMut, Env, Event, Order, Response, StdResult,
+};
+use cw_storage_plus::Bound;
+use error::ContractError;
+use state::{
+    ParameterChange, PendingParameterChange, TimelockConfig, TimelockedParameter,
+    PARAMETER_CHANGES, PARAMETER_CHANGE_COUNT, TIMELOCK_CONFIG,
+};
+
+/// Schedules a parameter change that can be executed once the ETA has been reached.
+pub fn schedule_parameter_change(
+    deps: DepsMut,
+    env: Env,
+    caller: Addr,
+    change: ParameterChange,
+    eta: u64,
+) -> Result<Response, ContractError> {
+    // check that the caller is the owner
+    check_owner(deps.as_ref(), &caller)?;
+
+    // check that the change is valid at the time of scheduling
+    validate_parameter_change(deps.as_ref(), &change)?;
+
+    // check that the ETA respects the minimum delay
+    let min_delay = get_timelock_config(deps.as_ref())?.min_delay;
+    ensure!(
+        env.block
+            .time
+            .seconds()
+            .checked_add(min_delay)
+            .is_some_and(|earliest_eta| eta >= earliest_eta),
+        ContractError::TimelockDelayTooShort
+    );
+
+    // store the pending change
+    let id = PARAMETER_CHANGE_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+    PARAMETER_CHANGE_COUNT.save(deps.storage, &id)?;
+    PARAMETER_CHANGES.save(
+        deps.storage,
+        id,
+        &PendingParameterChange {
+            id,
+            change: change.clone(),
+            eta,
+        },
+    )?;
+
+    // emit the event
+    Ok(Response::new().add_event(
+        Event::new("parameter_change_scheduled")
+            .add_attribute("id", id.to_string())
+            .add_attribute("change", to_json_string(&change)?)
+            .add_attribute("eta", eta.to_string()),
+    ))
+}
+
+/// Executes a scheduled parameter change whose ETA has been reached. Can be called by anyone.
+pub fn execute_parameter_change(
+    mut deps: DepsMut,
+    env: Env,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let pending_change = PARAMETER_CHANGES
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::ParameterChangeNotFound)?;
+
+    // check that the ETA has been reached
+    ensure!(
+        env.block.time.seconds() >= pending_change.eta,
+        ContractError::TimelockNotExpired
+    );
+
+    // remove the pending change
+    PARAMETER_CHANGES.remove(deps.storage, id);
+
+    // apply the change
+    let response = match pending_change.change {
+        ParameterChange::Fee { new_fee } => execute::update_fee(deps.branch(), new_fee)?,
+        ParameterChange::DistributionFee {
+            new_distribution_fee,
+        } => execute::update_distribution_fee(deps.branch(), new_distribution_fee)?,
+        ParameterChange::DepositFee { new_deposit_fee } => {
+            execute::update_deposit_fee(deps.branch(), new_deposit_fee)?
+        }
+        ParameterChange::WithdrawalFee { new_withdrawal_fee } => {
+            execute::update_withdrawal_fee(deps.branch(), new_withdrawal_fee)?
+        }
+        ParameterChange::FeeRecipients { new_fee_recipients } => {
+            execute::update_fee_recipients(deps.branch(), new_fee_recipients)?
+        }
+        ParameterChange::DefaultValidator {
+            new_default_validator_addr,
+        } => execute::update_default_validator(deps.branch(), &new_default_validator_addr)?,
+        ParameterChange::TimelockConfig {
+            min_delay,
+            timelocked_parameters,
+        } => update_timelock_config(deps.branch(), min_delay, timelocked_parameters)?,
+    };
+
+    // emit the event
+    Ok(response
+        .add_event(Event::new("parameter_change_executed").add_attribute("id", id.to_string())))
+}
+
+/// Cancels a scheduled parameter change before its ETA is reached.
+pub fn cancel_parameter_change(
+    deps: DepsMut,
+    env: Env,
+    caller: Addr,
+    id: u64,
+) -> Result<Response, ContractError> {
+    // check that the caller is the owner
+    check_owner(deps.as_ref(), &caller)?;
+
+    let pending_change = PARAMETER_CHANGES
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::ParameterChangeNotFound)?;
+
+    // check that the ETA has not been reached
+    ensure!(
+        env.block.time.seconds() < pending_change.eta,
+        ContractError::TimelockExpired
+    );
+
+    // remove the pending change
+    PARAMETER_CHANGES.remove(deps.storage, id);
+
+    // emit the event
+    Ok(Response::new()
+        .add_event(Event::new("parameter_change_cancelled").add_attribute("id", id.to_string())))
+}
+
+/// Updates the timelock delay and the set of parameters that require the timelock.
+fn update_timelock_config(
+    deps: DepsMut,
+    min_delay: u64,
+    timelocked_parameters: Vec<TimelockedParameter>,
+) -> Result<Response, ContractError> {
+    validate_min_delay(min_delay)?;
+
+    let old_config = get_timelock_config(deps.as_ref())?;
+    let new_config = TimelockConfig {
+        min_delay,
+        timelocked_parameters,
+    };
+    TIMELOCK_CONFIG.save(deps.storage, &new_config)?;
+
+    Ok(Response::new().add_event(
+        Event::new("set_timelock_config")
+            .add_attribute("old_min_delay", old_config.min_delay.to_string())
+            .add_attribute("new_min_delay", new_config.min_delay.to_string())
+            .add_attribute(
+                "timelocked_parameters",
+                to_json_string(&new_config.timelocked_parameters)?,
+            ),
+    ))
+}
+
+/// Checks that a parameter change is valid.
+fn validate_parameter_change(deps: Deps, change: &ParameterChange) -> Result<(), ContractError> {
+    match change {
+        ParameterChange::Fee { new_fee: fee }
+        | ParameterChange::DistributionFee {
+            new_distribution_fee: fee,
+        }
+        | ParameterChange::DepositFee {
+            new_deposit_fee: fee,
+        }
+        | ParameterChange::WithdrawalFee {
+            new_withdrawal_fee: fee,
+        } => ensure!(*fee < FEE_PRECISION, ContractError::FeeTooLarge),
+        ParameterChange::FeeRecipients { new_fee_recipients } => {
+            validate_fee_recipients(deps, new_fee_recipients)?;
+        }
+        ParameterChange::DefaultValidator {
+            new_default_validator_addr,
+        } => ensure!(
+            state::VALIDATORS.has(deps.storage, new_default_validator_addr),
+            ContractError::ValidatorDoesNotExist
+        ),
+        ParameterChange::TimelockConfig { min_delay, .. } => validate_min_delay(*min_delay)?,
+    }
+    Ok(())
+}
+
+/// Checks that a timelock minimum delay does not exceed MAX_TIMELOCK_DELAY.
+fn validate_min_delay(min_delay: u64) -> Result<(), ContractError> {
+    ensure!(
+        min_delay <= MAX_TIMELOCK_DELAY,
+        ContractError::TimelockDelayTooLong {
+            max: MAX_TIMELOCK_DELAY
+        }
+    );
+    Ok(())
+}
+
+/// Checks that a parameter can be changed without going through the timelock.
+/// Returns ParameterTimelocked error if it can't.
+pub(crate) fn check_not_timelocked(
+    deps: Deps,
+    parameter: TimelockedParameter,
+) -> Result<(), ContractError> {
+    ensure!(
+        !get_timelock_config(deps)?
+            .timelocked_parameters
+            .contains(&parameter),
+        ContractError::ParameterTimelocked
+    );
+    Ok(())
+}
+
+/// Gets the timelock configuration.
+pub fn get_timelock_config(deps: Deps) -> StdResult<TimelockConfig> {
+    Ok(TIMELOCK_CONFIG.may_load(deps.storage)?.unwrap_or_default())
+}
+
+/// Returns the parameter changes scheduled through the timelock, ordered by id.
+pub fn list_pending_changes(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<PendingParameterChange>> {
+    let limit = limit
+        .unwrap_or(DEFAULT_PENDING_CHANGE_LIMIT)
+        .min(MAX_PENDING_CHANGE_LIMIT) as usize;
+
+    PARAMETER_CHANGES
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(_, change)| change))
+        .collect()
+}
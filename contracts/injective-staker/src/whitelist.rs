@@ -1,7 +1,18 @@
 use crate::*;
-use cosmwasm_std::{ensure, Addr, Deps, DepsMut, Event, Response, StdResult};
+use cosmwasm_std::{
+    ensure, to_json_binary, Addr, Deps, DepsMut, Env, Event, Order, Response, StdError, StdResult,
+    Storage,
+};
+use cw_storage_plus::Bound;
 use error::ContractError;
-use state::{UserStatus, OWNER, WHITELIST_AGENTS, WHITELIST_USERS};
+use msg::{
+    BatchUpdateUserStatusResponse, KycRegistryQueryMsg, KycRegistryUserStatusResponse,
+    SkippedUserStatusEntry, UserStatusEntry, WhitelistExpiryEntry,
+};
+use state::{
+    whitelist_expiries, whitelist_users, UserStatus, UserStatusChange, WhitelistBackend,
+    USER_STATUS_COUNTS, USER_STATUS_HISTORY, WHITELIST_AGENTS, WHITELIST_BACKEND,
+};
 
 /// Adds an agent to the whitelist.
 pub fn add_agent(
@@ -9,15 +20,17 @@ pub fn add_agent(
     caller: Addr,
     new_agent: &String,
 ) -> Result<Response, ContractError> {
-    // check that the caller is an agent
-    check_agent(deps.as_ref(), &caller)?;
+    // only the owner can grant the KYC agent role
+    contract::check_owner(deps.as_ref(), &caller)?;
 
     // validate agent address
     let new_agent_addr = deps.api.addr_validate(new_agent)?;
 
     // check that the new agent is not the owner
-    let owner = OWNER.load(deps.storage)?;
-    ensure!(new_agent_addr != owner, ContractError::OwnerCannotBeAdded);
+    ensure!(
+        !contract::is_owner(deps.as_ref(), &new_agent_addr)?,
+        ContractError::OwnerCannotBeAdded
+    );
 
     // check that the new agent is already an agent
     ensure!(
@@ -38,15 +51,14 @@ pub fn remove_agent(
     caller: Addr,
     agent_to_remove: &String,
 ) -> Result<Response, ContractError> {
-    // check that the caller is an agent
-    check_agent(deps.as_ref(), &caller)?;
+    // only the owner can revoke the KYC agent role
+    contract::check_owner(deps.as_ref(), &caller)?;
 
     let agent_to_remove_addr = deps.api.addr_validate(agent_to_remove)?;
 
     // check that the agent to remove is not the owner
-    let owner = OWNER.load(deps.storage)?;
     ensure!(
-        agent_to_remove_addr != owner,
+        !contract::is_owner(deps.as_ref(), &agent_to_remove_addr)?,
         ContractError::OwnerCannotBeRemoved
     );
 
@@ -66,134 +78,503 @@ pub fn remove_agent(
 
 /// Checks whether an address is an agent or the owner.
 /// Returns CallerIsNotAgent error if it is neither.
-fn check_agent(deps: Deps, agent: &Addr) -> Result<(), ContractError> {
+pub(crate) fn check_agent(deps: Deps, agent: &Addr) -> Result<(), ContractError> {
     ensure!(is_agent(deps, agent)?, ContractError::CallerIsNotAgent);
     Ok(())
 }
 
-/// Checks whether a user is whitelisted.
+/// Checks whether a user is whitelisted and their KYC approval has not expired.
 /// Returns UserNotWhitelisted error if not.
-pub(crate) fn check_whitelisted(deps: Deps, user: &Addr) -> Result<(), ContractError> {
+pub(crate) fn check_whitelisted(deps: Deps, env: &Env, user: &Addr) -> Result<(), ContractError> {
     ensure!(
-        is_user_whitelisted(deps, user),
+        is_user_whitelisted(deps, env, user)?,
         ContractError::UserNotWhitelisted
     );
     Ok(())
 }
 
+/// Checks that an account is not frozen, i.e. not blacklisted.
+/// Frozen accounts cannot send or receive TruINJ. Returns AccountFrozen error if it is.
+pub(crate) fn check_not_frozen(deps: Deps, user: &Addr) -> Result<(), ContractError> {
+    ensure!(
+        !is_user_blacklisted(deps, user)?,
+        ContractError::AccountFrozen
+    );
+    Ok(())
+}
+
 /// Checks whether an address is an agent or the owner.
 /// Returns true if it is either, false otherwise.
 pub fn is_agent(deps: Deps, agent: &Addr) -> StdResult<bool> {
-    let owner = OWNER.load(deps.storage)?;
-
-    Ok(owner == agent || WHITELIST_AGENTS.has(deps.storage, agent))
+    Ok(contract::is_owner(deps, agent)? || WHITELIST_AGENTS.has(deps.storage, agent))
 }
 
-/// Adds a user to the whitelist.
+/// Adds a user to the whitelist, optionally until the given time.
+/// Whitelisting an already whitelisted user with a different expiry renews their approval.
 pub fn add_user_to_whitelist(
     deps: DepsMut,
+    env: Env,
     caller: Addr,
     user: &String,
+    valid_until: Option<u64>,
+    reason: Option<String>,
 ) -> Result<Response, ContractError> {
     // check if the caller is an agent
     check_agent(deps.as_ref(), &caller)?;
-
-    // validate user address
-    let user_addr = deps.api.addr_validate(user.as_str())?;
-
-    // check if the user is already whitelisted
-    let current_user_status = get_current_user_status(deps.as_ref(), &user_addr)?;
-    ensure!(
-        current_user_status != UserStatus::Whitelisted,
-        ContractError::UserAlreadyWhitelisted
-    );
-
-    // add the user to the whitelist
-    WHITELIST_USERS.save(deps.storage, &user_addr, &UserStatus::Whitelisted)?;
-
-    // emit the event
-    Ok(Response::new().add_event(
-        Event::new("whitelisting_status_changed")
-            .add_attribute("user", user)
-            .add_attribute("old_status", current_user_status.to_string())
-            .add_attribute("new_status", UserStatus::Whitelisted.to_string()),
-    ))
+    check_local_backend(deps.as_ref())?;
+
+    check_whitelist_expiry(&env, &UserStatus::Whitelisted, valid_until)?;
+
+    let event = update_user_status(
+        deps,
+        &env,
+        &caller,
+        user,
+        UserStatus::Whitelisted,
+        valid_until,
+        reason,
+    )?;
+    Ok(Response::new().add_event(event))
 }
 
 /// Adds a user to the blacklist.
 pub fn add_user_to_blacklist(
     deps: DepsMut,
+    env: Env,
     caller: Addr,
     user: &String,
+    reason: Option<String>,
 ) -> Result<Response, ContractError> {
     // check if the caller is an agent
     check_agent(deps.as_ref(), &caller)?;
-
-    // validate user address
-    let user_addr = deps.api.addr_validate(user.as_str())?;
-
-    // check if the user is already blacklisted
-    let current_user_status = get_current_user_status(deps.as_ref(), &user_addr).unwrap();
-    ensure!(
-        current_user_status != UserStatus::Blacklisted,
-        ContractError::UserAlreadyBlacklisted
-    );
-
-    // add the user to the blacklist
-    WHITELIST_USERS.save(deps.storage, &user_addr, &UserStatus::Blacklisted)?;
-
-    // emit the event
-    Ok(Response::new().add_event(
-        Event::new("whitelisting_status_changed")
-            .add_attribute("user", user)
-            .add_attribute("old_status", current_user_status.to_string())
-            .add_attribute("new_status", UserStatus::Blacklisted.to_string()),
-    ))
+    check_local_backend(deps.as_ref())?;
+
+    let event = update_user_status(
+        deps,
+        &env,
+        &caller,
+        user,
+        UserStatus::Blacklisted,
+        None,
+        reason,
+    )?;
+    Ok(Response::new().add_event(event))
 }
 
 /// Removes a user's status.
 pub fn clear_user_status(
     deps: DepsMut,
+    env: Env,
     caller: Addr,
     user: &String,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    // check if the caller is an agent
+    check_agent(deps.as_ref(), &caller)?;
+    check_local_backend(deps.as_ref())?;
+
+    let event = update_user_status(
+        deps,
+        &env,
+        &caller,
+        user,
+        UserStatus::NoStatus,
+        None,
+        reason,
+    )?;
+    Ok(Response::new().add_event(event))
+}
+
+/// Updates the status of several users at once.
+/// If skip_invalid is false, the whole batch fails on the first invalid entry.
+/// Otherwise, invalid entries are skipped and returned in the response data.
+pub fn batch_update_user_status(
+    mut deps: DepsMut,
+    env: Env,
+    caller: Addr,
+    entries: Vec<UserStatusEntry>,
+    skip_invalid: bool,
 ) -> Result<Response, ContractError> {
     // check if the caller is an agent
     check_agent(deps.as_ref(), &caller)?;
+    check_local_backend(deps.as_ref())?;
+
+    ensure!(
+        entries.len() <= MAX_USER_STATUS_BATCH_SIZE,
+        ContractError::BatchTooLarge {
+            max: MAX_USER_STATUS_BATCH_SIZE
+        }
+    );
+
+    let mut events = Vec::with_capacity(entries.len());
+    let mut skipped = vec![];
+    for entry in entries {
+        let result =
+            check_whitelist_expiry(&env, &entry.status, entry.valid_until).and_then(|_| {
+                update_user_status(
+                    deps.branch(),
+                    &env,
+                    &caller,
+                    &entry.user,
+                    entry.status.clone(),
+                    entry.valid_until,
+                    entry.reason,
+                )
+            });
+        match result {
+            Ok(event) => events.push(event),
+            Err(err) if skip_invalid => skipped.push(SkippedUserStatusEntry {
+                user: entry.user,
+                status: entry.status,
+                reason: err.to_string(),
+            }),
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(Response::new()
+        .add_events(events)
+        .set_data(to_json_binary(&BatchUpdateUserStatusResponse { skipped })?))
+}
+
+/// Checks that an expiry is only given when whitelisting a user, and that it is in the future.
+/// Returns InvalidWhitelistExpiry error otherwise.
+fn check_whitelist_expiry(
+    env: &Env,
+    status: &UserStatus,
+    valid_until: Option<u64>,
+) -> Result<(), ContractError> {
+    if let Some(valid_until) = valid_until {
+        ensure!(
+            *status == UserStatus::Whitelisted && valid_until > env.block.time.seconds(),
+            ContractError::InvalidWhitelistExpiry
+        );
+    }
+    Ok(())
+}
 
+/// Validates and stores the new status of a user, along with the expiry of a whitelisted user,
+/// and records the change in the user's status history.
+/// Returns the whitelisting_status_changed event to emit.
+pub(crate) fn update_user_status(
+    deps: DepsMut,
+    env: &Env,
+    agent: &Addr,
+    user: &String,
+    new_status: UserStatus,
+    valid_until: Option<u64>,
+    reason: Option<String>,
+) -> Result<Event, ContractError> {
+    // validate user address
     let user_addr = deps.api.addr_validate(user.as_str())?;
 
-    // check if the user has a status
+    if let Some(reason) = &reason {
+        ensure!(
+            reason.len() <= MAX_STATUS_REASON_LENGTH,
+            ContractError::StatusReasonTooLong {
+                max: MAX_STATUS_REASON_LENGTH
+            }
+        );
+    }
+
+    // check that the status or, for whitelisted users, the expiry changes
     let current_user_status = get_current_user_status(deps.as_ref(), &user_addr)?;
+    let current_valid_until = get_whitelist_expiry(deps.as_ref(), &user_addr)?;
     ensure!(
-        current_user_status != UserStatus::NoStatus,
-        ContractError::UserStatusAlreadyCleared
+        current_user_status != new_status || current_valid_until != valid_until,
+        match new_status {
+            UserStatus::Whitelisted => ContractError::UserAlreadyWhitelisted,
+            UserStatus::Blacklisted => ContractError::UserAlreadyBlacklisted,
+            UserStatus::NoStatus => ContractError::UserStatusAlreadyCleared,
+        }
     );
 
-    // clear the user status
-    WHITELIST_USERS.remove(deps.storage, &user_addr);
+    match new_status {
+        UserStatus::NoStatus => whitelist_users().remove(deps.storage, user_addr.clone())?,
+        _ => whitelist_users().save(deps.storage, user_addr.clone(), &new_status)?,
+    }
+    if current_user_status != new_status {
+        update_user_status_count(deps.storage, &current_user_status, |count| {
+            count.saturating_sub(1)
+        })?;
+        update_user_status_count(deps.storage, &new_status, |count| count + 1)?;
+    }
+    match valid_until {
+        Some(valid_until) => {
+            whitelist_expiries().save(deps.storage, user_addr.clone(), &valid_until)?
+        }
+        None => whitelist_expiries().remove(deps.storage, user_addr.clone())?,
+    }
+
+    // record the change in the user's status history
+    let id = USER_STATUS_HISTORY
+        .prefix(&user_addr)
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(1, |last_id| last_id + 1);
+    USER_STATUS_HISTORY.save(
+        deps.storage,
+        (&user_addr, id),
+        &UserStatusChange {
+            id,
+            old_status: current_user_status.clone(),
+            new_status: new_status.clone(),
+            valid_until,
+            agent: agent.clone(),
+            timestamp: env.block.time.seconds(),
+            reason: reason.clone(),
+        },
+    )?;
+
+    let mut event = Event::new("whitelisting_status_changed")
+        .add_attribute("user", user)
+        .add_attribute("old_status", current_user_status.to_string())
+        .add_attribute("new_status", new_status.to_string());
+    if let Some(valid_until) = valid_until {
+        event = event.add_attribute("valid_until", valid_until.to_string());
+    }
+    if let Some(reason) = reason {
+        event = event.add_attribute("reason", reason);
+    }
+    Ok(event)
+}
+
+/// Sets where user statuses are read from. Only the owner can change the backend.
+pub fn set_whitelist_backend(
+    deps: DepsMut,
+    caller: Addr,
+    backend: WhitelistBackend,
+) -> Result<Response, ContractError> {
+    contract::check_owner(deps.as_ref(), &caller)?;
+
+    let mut event = Event::new("set_whitelist_backend");
+    let backend = match backend {
+        WhitelistBackend::Local => {
+            event = event.add_attribute("backend", "local");
+            WhitelistBackend::Local
+        }
+        WhitelistBackend::External { registry } => {
+            let registry = deps.api.addr_validate(registry.as_str())?;
+            event = event
+                .add_attribute("backend", "external")
+                .add_attribute("registry", &registry);
+            WhitelistBackend::External { registry }
+        }
+    };
+    WHITELIST_BACKEND.save(deps.storage, &backend)?;
+
+    Ok(Response::new().add_event(event))
+}
+
+/// Gets where user statuses are read from.
+pub fn get_whitelist_backend(deps: Deps) -> StdResult<WhitelistBackend> {
+    Ok(WHITELIST_BACKEND
+        .may_load(deps.storage)?
+        .unwrap_or_default())
+}
 
-    // emit the event
-    Ok(Response::new().add_event(
-        Event::new("whitelisting_status_changed")
-            .add_attribute("user", user)
-            .add_attribute("old_status", current_user_status.to_string())
-            .add_attribute("new_status", UserStatus::NoStatus.to_string()),
-    ))
+/// Checks that user statuses are managed by this contract.
+/// Returns ExternalWhitelistBackend error if they are read from an external registry.
+pub(crate) fn check_local_backend(deps: Deps) -> Result<(), ContractError> {
+    ensure!(
+        get_whitelist_backend(deps)? == WhitelistBackend::Local,
+        ContractError::ExternalWhitelistBackend
+    );
+    Ok(())
+}
+
+/// Checks that the local whitelist can be enumerated, as it goes stale while an external registry is set.
+/// Returns ExternalWhitelistBackend error as a query error if it can't.
+fn check_local_backend_query(deps: Deps) -> StdResult<()> {
+    check_local_backend(deps).map_err(|err| StdError::generic_err(err.to_string()))
 }
 
-/// Gets the current whitelist status of a user.
+/// Gets the current whitelist status of a user, from the external registry if one is set.
 pub fn get_current_user_status(deps: Deps, user: &Addr) -> StdResult<UserStatus> {
-    WHITELIST_USERS
-        .may_load(deps.storage, user)?
-        .map_or_else(|| Ok(UserStatus::NoStatus), Ok)
+    match get_whitelist_backend(deps)? {
+        WhitelistBackend::Local => Ok(whitelist_users()
+            .may_load(deps.storage, user.clone())?
+            .unwrap_or(UserStatus::NoStatus)),
+        WhitelistBackend::External { registry } => {
+            let response: KycRegistryUserStatusResponse = deps.querier.query_wasm_smart(
+                registry,
+                &KycRegistryQueryMsg::UserStatus {
+                    user: user.to_string(),
+                },
+            )?;
+            Ok(response.status)
+        }
+    }
+}
+
+/// Gets the time until which a whitelisted user's KYC approval is valid, if it expires.
+/// Expiries are left to the registry when an external registry is set.
+pub fn get_whitelist_expiry(deps: Deps, user: &Addr) -> StdResult<Option<u64>> {
+    match get_whitelist_backend(deps)? {
+        WhitelistBackend::Local => whitelist_expiries().may_load(deps.storage, user.clone()),
+        WhitelistBackend::External { .. } => Ok(None),
+    }
+}
+
+/// Checks if a user is whitelisted and their KYC approval has not expired.
+pub fn is_user_whitelisted(deps: Deps, env: &Env, user: &Addr) -> StdResult<bool> {
+    Ok(
+        get_current_user_status(deps, user)? == UserStatus::Whitelisted
+            && get_whitelist_expiry(deps, user)?
+                .is_none_or(|valid_until| env.block.time.seconds() < valid_until),
+    )
+}
+
+/// Gets a page of the users with the given status, in ascending address order.
+pub fn list_users_by_status(
+    deps: Deps,
+    status: UserStatus,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    check_local_backend_query(deps)?;
+    let limit = limit
+        .unwrap_or(DEFAULT_WHITELIST_QUERY_LIMIT)
+        .min(MAX_WHITELIST_QUERY_LIMIT) as usize;
+
+    whitelist_users()
+        .idx
+        .status
+        .prefix(status.to_string())
+        .keys(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect()
+}
+
+/// Gets the number of users with the given status.
+pub fn count_users_by_status(deps: Deps, status: UserStatus) -> StdResult<u64> {
+    check_local_backend_query(deps)?;
+    Ok(USER_STATUS_COUNTS
+        .may_load(deps.storage, &status.to_string())?
+        .unwrap_or_default())
+}
+
+/// Gets a page of the agents, in ascending address order. The owner is not included.
+pub fn list_agents(
+    deps: Deps,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    let limit = limit
+        .unwrap_or(DEFAULT_WHITELIST_QUERY_LIMIT)
+        .min(MAX_WHITELIST_QUERY_LIMIT) as usize;
+
+    WHITELIST_AGENTS
+        .keys(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect()
+}
+
+/// Rebuilds the status index and the status counts of the whitelisted and blacklisted users.
+pub(crate) fn rebuild_user_status_index(storage: &mut dyn Storage) -> StdResult<()> {
+    let users = whitelist_users()
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut counts = [
+        (UserStatus::Whitelisted, 0u64),
+        (UserStatus::Blacklisted, 0u64),
+    ];
+    for (user, status) in users {
+        // re-saving an entry indexes it
+        whitelist_users().save(storage, user, &status)?;
+        if let Some((_, count)) = counts.iter_mut().find(|(s, _)| *s == status) {
+            *count += 1;
+        }
+    }
+    for (status, count) in counts {
+        USER_STATUS_COUNTS.save(storage, &status.to_string(), &count)?;
+    }
+    Ok(())
+}
+
+/// Updates the number of users with the given status. Users without a status are not counted.
+fn update_user_status_count(
+    storage: &mut dyn Storage,
+    status: &UserStatus,
+    update: impl FnOnce(u64) -> u64,
+) -> StdResult<()> {
+    if *status == UserStatus::NoStatus {
+        return Ok(());
+    }
+    let key = status.to_string();
+    let count = USER_STATUS_COUNTS
+        .may_load(storage, &key)?
+        .unwrap_or_default();
+    USER_STATUS_COUNTS.save(storage, &key, &update(count))
+}
+
+/// Gets a page of the whitelisted users whose KYC approval expires before the given time,
+/// in ascending expiry order.
+pub fn get_expiring_whitelist(
+    deps: Deps,
+    before: u64,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<Vec<WhitelistExpiryEntry>> {
+    check_local_backend_query(deps)?;
+    let limit = limit
+        .unwrap_or(DEFAULT_WHITELIST_QUERY_LIMIT)
+        .min(MAX_WHITELIST_QUERY_LIMIT) as usize;
+
+    // continue after the expiry of the last user returned
+    let min = match start_after {
+        Some(user) => get_whitelist_expiry(deps, &user)?
+            .map(|valid_until| Bound::exclusive((valid_until, user))),
+        None => None,
+    };
+    let max = Some(Bound::exclusive((before, Addr::unchecked(""))));
+
+    whitelist_expiries()
+        .idx
+        .valid_until
+        .range(deps.storage, min, max, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(user, valid_until)| WhitelistExpiryEntry { user, valid_until }))
+        .collect()
 }
 
-/// Checks if a user is whitelisted.
-pub fn is_user_whitelisted(deps: Deps, user: &Addr) -> bool {
-    get_current_user_status(deps, user).unwrap() == UserStatus::Whitelisted
+/// Returns the status changes of a user, oldest first.
+pub fn get_user_status_history(
+    deps: Deps,
+    user: &Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<UserStatusChange>> {
+    let limit = limit
+        .unwrap_or(DEFAULT_WHITELIST_QUERY_LIMIT)
+        .min(MAX_WHITELIST_QUERY_LIMIT) as usize;
+
+    USER_STATUS_HISTORY
+        .prefix(user)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(_, change)| change))
+        .collect()
 }
 
 /// Checks if a user is blacklisted.
-pub fn is_user_blacklisted(deps: Deps, user: &Addr) -> bool {
-    get_current_user_status(deps, user).unwrap() == UserStatus::Blacklisted
+pub fn is_user_blacklisted(deps: Deps, user: &Addr) -> StdResult<bool> {
+    Ok(get_current_user_status(deps, user)? == UserStatus::Blacklisted)
 }
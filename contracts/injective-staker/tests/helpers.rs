@@ -1,6 +1,6 @@
 use cosmwasm_std::{
-    coin, testing::mock_env, to_json_binary, Addr, CosmosMsg, Decimal, Empty, StdResult, Validator,
-    WasmMsg,
+    coin, testing::mock_env, to_json_binary, to_json_string, Addr, CosmosMsg, Decimal, Empty,
+    StdResult, Validator, WasmMsg,
 };
 use cosmwasm_std::{
     Attribute, Coin, DelegationResponse, DistributionMsg, Event, FullDelegation, StakingQuery,
@@ -15,13 +15,18 @@ use cw_multi_test::{
 use injective_staker::constants::{INJ, ONE_INJ};
 use injective_staker::contract::{execute, instantiate, query};
 use injective_staker::msg::{
-    ExecuteMsg, GetAllocationsResponse, GetClaimableAmountResponse, GetCurrentUserStatusResponse,
-    GetDistributionAmountsResponse, GetIsAgentResponse, GetIsBlacklistedResponse,
-    GetIsWhitelistedResponse, GetMaxWithdrawResponse, GetSharePriceResponse, GetStakerInfoResponse,
+    ExecuteMsg, GetAllocationsResponse, GetClaimableAmountResponse, GetCompoundConfigResponse,
+    GetCurrentUserStatusResponse, GetDistributionAmountsResponse, GetIsAgentResponse,
+    GetIsBlacklistedResponse, GetIsWhitelistedResponse, GetMaxWithdrawResponse,
+    GetPauseMatrixResponse, GetPendingChangesResponse, GetProposalResponse,
+    GetReserveStatusResponse, GetSharePriceResponse, GetStakerInfoResponse,
     GetTotalAllocatedResponse, GetTotalRewardsResponse, GetTotalStakedResponse,
-    GetTotalSupplyResponse, InstantiateMsg, QueryMsg,
+    GetTotalSupplyResponse, InstantiateMsg, ListProposalsResponse, QueryMsg,
+};
+use injective_staker::state::{
+    Allocation, ParameterChange, PausableOperation, PendingParameterChange, Proposal, Role,
+    UserStatus,
 };
-use injective_staker::state::{Allocation, UserStatus};
 use injective_staker::SHARE_PRICE_SCALING_FACTOR;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -206,6 +211,15 @@ pub fn mint_truinj(
     assert!(response.is_ok());
 }
 
+/// Returns the expected value of the fee_recipient_balances event attribute.
+pub fn fee_recipient_balances(balances: Vec<(&Addr, u128)>) -> String {
+    let balances: Vec<(Addr, Uint128)> = balances
+        .into_iter()
+        .map(|(addr, balance)| (addr.clone(), Uint128::from(balance)))
+        .collect();
+    to_json_string(&balances).unwrap()
+}
+
 pub fn query_truinj_balance(app: &App, addr: &Addr, contract_address: &Addr) -> u128 {
     let sender_balance: BalanceResponse = app
         .wrap()
@@ -593,6 +607,140 @@ pub fn add_agent(app: &mut App, staker_contract: &Addr, owner: &Addr, new_agent:
     assert!(response.is_ok());
 }
 
+pub fn compound_rewards(
+    app: &mut App,
+    staker_contract: &Addr,
+    caller: &Addr,
+) -> Result<AppResponse, AnyError> {
+    app.execute(
+        caller.clone(),
+        wasm_execute_msg(
+            staker_contract,
+            &ExecuteMsg::CompoundRewards {
+                start_after: None,
+                limit: None,
+                min_reward: None,
+            },
+        )
+        .into(),
+    )
+}
+
+pub fn compound_rewards_page(
+    app: &mut App,
+    staker_contract: &Addr,
+    caller: &Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    min_reward: Option<Uint128>,
+) -> Result<AppResponse, AnyError> {
+    app.execute(
+        caller.clone(),
+        wasm_execute_msg(
+            staker_contract,
+            &ExecuteMsg::CompoundRewards {
+                start_after,
+                limit,
+                min_reward,
+            },
+        )
+        .into(),
+    )
+}
+
+pub fn get_compound_config(app: &App, staker_contract: &Addr) -> GetCompoundConfigResponse {
+    app.wrap()
+        .query_wasm_smart(staker_contract, &QueryMsg::GetCompoundConfig {})
+        .unwrap()
+}
+
+pub fn reconcile_delegations(
+    app: &mut App,
+    staker_contract: &Addr,
+    caller: &Addr,
+) -> Result<AppResponse, AnyError> {
+    app.execute(
+        caller.clone(),
+        wasm_execute_msg(staker_contract, &ExecuteMsg::ReconcileDelegations {}).into(),
+    )
+}
+
+pub fn top_up_reserve(
+    app: &mut App,
+    staker_contract: &Addr,
+    sender: &Addr,
+    amount: u128,
+) -> Result<AppResponse, AnyError> {
+    app.execute_contract(
+        sender.clone(),
+        staker_contract.clone(),
+        &ExecuteMsg::TopUpReserve {},
+        &[coin(amount, INJ)],
+    )
+}
+
+pub fn withdraw_reserve(
+    app: &mut App,
+    staker_contract: &Addr,
+    sender: &Addr,
+    amount: u128,
+) -> Result<AppResponse, AnyError> {
+    app.execute(
+        sender.clone(),
+        wasm_execute_msg(
+            staker_contract,
+            &ExecuteMsg::WithdrawReserve {
+                amount: amount.into(),
+            },
+        )
+        .into(),
+    )
+}
+
+pub fn get_reserve_status(app: &App, staker_contract: &Addr) -> GetReserveStatusResponse {
+    app.wrap()
+        .query_wasm_smart(staker_contract, &QueryMsg::GetReserveStatus {})
+        .unwrap()
+}
+
+pub fn set_compound_config(
+    app: &mut App,
+    staker_contract: &Addr,
+    owner: &Addr,
+    keeper_bounty: u16,
+    min_compound_interval: u64,
+    keepers_only: bool,
+) {
+    let response = app.execute(
+        owner.clone(),
+        wasm_execute_msg(
+            staker_contract,
+            &ExecuteMsg::SetCompoundConfig {
+                keeper_bounty,
+                min_compound_interval,
+                keepers_only,
+            },
+        )
+        .into(),
+    );
+    assert!(response.is_ok());
+}
+
+pub fn grant_role(app: &mut App, staker_contract: &Addr, owner: &Addr, role: Role, account: &Addr) {
+    let response = app.execute(
+        owner.clone(),
+        wasm_execute_msg(
+            staker_contract,
+            &ExecuteMsg::GrantRole {
+                role,
+                account: account.to_string(),
+            },
+        )
+        .into(),
+    );
+    assert!(response.is_ok());
+}
+
 pub fn whitelist_user(app: &mut App, contract: &Addr, agent: &Addr, user: &Addr) {
     let response = app.execute(
         agent.clone(),
@@ -600,6 +748,8 @@ pub fn whitelist_user(app: &mut App, contract: &Addr, agent: &Addr, user: &Addr)
             contract,
             &ExecuteMsg::AddUserToWhitelist {
                 user: user.to_string(),
+                valid_until: None,
+                reason: None,
             },
         )
         .into(),
@@ -614,6 +764,7 @@ pub fn blacklist_user(app: &mut App, contract: &Addr, agent: &Addr, user: &Addr)
             contract,
             &ExecuteMsg::AddUserToBlacklist {
                 user: user.to_string(),
+                reason: None,
             },
         )
         .into(),
@@ -628,6 +779,7 @@ pub fn clear_whitelist_status(app: &mut App, staker_addr: &Addr, agent: &Addr, u
             staker_addr,
             &ExecuteMsg::ClearUserStatus {
                 user: user.to_string(),
+                reason: None,
             },
         )
         .into(),
@@ -643,6 +795,44 @@ pub fn pause(app: &mut App, contract: &Addr, owner: &Addr) {
     assert!(response.is_ok());
 }
 
+pub fn soft_pause(app: &mut App, contract: &Addr, owner: &Addr) {
+    let response = app.execute(
+        owner.clone(),
+        wasm_execute_msg(contract, &ExecuteMsg::SoftPause).into(),
+    );
+    assert!(response.is_ok());
+}
+
+pub fn pause_operations(
+    app: &mut App,
+    contract: &Addr,
+    sender: &Addr,
+    operations: Vec<PausableOperation>,
+) -> Result<AppResponse, AnyError> {
+    app.execute(
+        sender.clone(),
+        wasm_execute_msg(contract, &ExecuteMsg::PauseOperations { operations }).into(),
+    )
+}
+
+pub fn unpause_operations(
+    app: &mut App,
+    contract: &Addr,
+    sender: &Addr,
+    operations: Vec<PausableOperation>,
+) -> Result<AppResponse, AnyError> {
+    app.execute(
+        sender.clone(),
+        wasm_execute_msg(contract, &ExecuteMsg::UnpauseOperations { operations }).into(),
+    )
+}
+
+pub fn get_pause_matrix(app: &App, contract: &Addr) -> GetPauseMatrixResponse {
+    app.wrap()
+        .query_wasm_smart(contract, &QueryMsg::GetPauseMatrix {})
+        .unwrap()
+}
+
 pub fn unpause(app: &mut App, contract: &Addr, owner: &Addr) {
     let response = app.execute(
         owner.clone(),
@@ -816,6 +1006,27 @@ pub fn get_allocations(app: &App, contract_addr: &Addr, user: &Addr) -> Vec<Allo
     allocations.allocations
 }
 
+pub fn get_allocations_to_recipient(
+    app: &App,
+    contract_addr: &Addr,
+    recipient: &Addr,
+    start_after: Option<&Addr>,
+    limit: Option<u32>,
+) -> Vec<Allocation> {
+    let allocations: GetAllocationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::GetAllocationsToRecipient {
+                recipient: recipient.to_string(),
+                start_after: start_after.map(Addr::to_string),
+                limit,
+            },
+        )
+        .unwrap();
+    allocations.allocations
+}
+
 pub fn get_total_allocated(
     app: &App,
     contract_addr: &Addr,
@@ -973,6 +1184,28 @@ pub fn set_fee(app: &mut App, contract_addr: &Addr, owner: &Addr, new_fee: u16)
     assert!(response.is_ok());
 }
 
+pub fn set_fee_recipients(
+    app: &mut App,
+    contract_addr: &Addr,
+    owner: &Addr,
+    new_fee_recipients: Vec<(&Addr, u16)>,
+) {
+    let msg = WasmMsg::Execute {
+        contract_addr: contract_addr.to_string(),
+        msg: to_json_binary(&ExecuteMsg::SetFeeRecipients {
+            new_fee_recipients: new_fee_recipients
+                .into_iter()
+                .map(|(addr, bps)| (addr.to_string(), bps))
+                .collect(),
+        })
+        .unwrap(),
+        funds: vec![],
+    };
+
+    let response = app.execute(owner.clone(), msg.into());
+    assert!(response.is_ok());
+}
+
 pub fn set_dist_fee(app: &mut App, contract_addr: &Addr, owner: &Addr, new_distribution_fee: u16) {
     let msg = WasmMsg::Execute {
         contract_addr: contract_addr.to_string(),
@@ -987,6 +1220,158 @@ pub fn set_dist_fee(app: &mut App, contract_addr: &Addr, owner: &Addr, new_distr
     assert!(response.is_ok());
 }
 
+pub fn set_deposit_fee(app: &mut App, contract_addr: &Addr, owner: &Addr, new_deposit_fee: u16) {
+    let msg = WasmMsg::Execute {
+        contract_addr: contract_addr.to_string(),
+        msg: to_json_binary(&ExecuteMsg::SetDepositFee { new_deposit_fee }).unwrap(),
+        funds: vec![],
+    };
+
+    let response = app.execute(owner.clone(), msg.into());
+    assert!(response.is_ok());
+}
+
+pub fn set_withdrawal_fee(
+    app: &mut App,
+    contract_addr: &Addr,
+    owner: &Addr,
+    new_withdrawal_fee: u16,
+) {
+    let msg = WasmMsg::Execute {
+        contract_addr: contract_addr.to_string(),
+        msg: to_json_binary(&ExecuteMsg::SetWithdrawalFee { new_withdrawal_fee }).unwrap(),
+        funds: vec![],
+    };
+
+    let response = app.execute(owner.clone(), msg.into());
+    assert!(response.is_ok());
+}
+
+pub fn schedule_parameter_change(
+    app: &mut App,
+    contract_addr: &Addr,
+    sender: &Addr,
+    change: ParameterChange,
+    eta: u64,
+) -> Result<AppResponse, AnyError> {
+    app.execute(
+        sender.clone(),
+        wasm_execute_msg(
+            contract_addr,
+            &ExecuteMsg::ScheduleParameterChange { change, eta },
+        )
+        .into(),
+    )
+}
+
+pub fn execute_parameter_change(
+    app: &mut App,
+    contract_addr: &Addr,
+    sender: &Addr,
+    id: u64,
+) -> Result<AppResponse, AnyError> {
+    app.execute(
+        sender.clone(),
+        wasm_execute_msg(contract_addr, &ExecuteMsg::ExecuteParameterChange { id }).into(),
+    )
+}
+
+pub fn set_multisig_config(
+    app: &mut App,
+    contract_addr: &Addr,
+    sender: &Addr,
+    signers: &[&Addr],
+    threshold: u32,
+    proposal_duration: u64,
+) -> Result<AppResponse, AnyError> {
+    app.execute(
+        sender.clone(),
+        wasm_execute_msg(
+            contract_addr,
+            &ExecuteMsg::SetMultisigConfig {
+                signers: signers.iter().map(|s| s.to_string()).collect(),
+                threshold,
+                proposal_duration,
+            },
+        )
+        .into(),
+    )
+}
+
+pub fn propose(
+    app: &mut App,
+    contract_addr: &Addr,
+    sender: &Addr,
+    msg: ExecuteMsg,
+) -> Result<AppResponse, AnyError> {
+    app.execute(
+        sender.clone(),
+        wasm_execute_msg(contract_addr, &ExecuteMsg::Propose { msg: Box::new(msg) }).into(),
+    )
+}
+
+pub fn approve_proposal(
+    app: &mut App,
+    contract_addr: &Addr,
+    sender: &Addr,
+    id: u64,
+) -> Result<AppResponse, AnyError> {
+    app.execute(
+        sender.clone(),
+        wasm_execute_msg(contract_addr, &ExecuteMsg::ApproveProposal { id }).into(),
+    )
+}
+
+pub fn execute_proposal(
+    app: &mut App,
+    contract_addr: &Addr,
+    sender: &Addr,
+    id: u64,
+) -> Result<AppResponse, AnyError> {
+    app.execute(
+        sender.clone(),
+        wasm_execute_msg(contract_addr, &ExecuteMsg::ExecuteProposal { id }).into(),
+    )
+}
+
+pub fn get_proposal(app: &App, contract_addr: &Addr, id: u64) -> Proposal {
+    let response: GetProposalResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr, &QueryMsg::GetProposal { id })
+        .unwrap();
+    response.proposal
+}
+
+pub fn list_proposals(
+    app: &App,
+    contract_addr: &Addr,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Vec<Proposal> {
+    let response: ListProposalsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr,
+            &QueryMsg::ListProposals { start_after, limit },
+        )
+        .unwrap();
+    response.proposals
+}
+
+pub fn get_pending_changes(app: &App, contract_addr: &Addr) -> Vec<PendingParameterChange> {
+    let response: GetPendingChangesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr,
+            &QueryMsg::GetPendingChanges {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    response.changes
+}
+
 pub fn convert_to_assets(
     shares: u128,
     share_price_num: Uint256,
@@ -1020,6 +1405,56 @@ pub fn transfer_truinj(
     assert!(res.is_ok());
 }
 
+/// Raises the share price by burning the TruINJ of a frozen holder of 10 INJ, which is shared among the
+/// remaining holders. Multi-test overflows when computing the rewards of INJ-sized stakes, so this stands in
+/// for accruing rewards in tests that need such stakes.
+pub fn raise_share_price(app: &mut App, contract_addr: &Addr, owner: &Addr) {
+    let holder: Addr = "holder".into_bech32();
+    whitelist_user(app, contract_addr, owner, &holder);
+    mint_inj(app, &holder, 10 * ONE_INJ);
+    stake(app, &holder, contract_addr, 10 * ONE_INJ).unwrap();
+
+    blacklist_user(app, contract_addr, owner, &holder);
+    compliance_recover(
+        app,
+        contract_addr,
+        owner,
+        &holder,
+        None,
+        10 * ONE_INJ,
+        false,
+        false,
+    )
+    .unwrap();
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn compliance_recover(
+    app: &mut App,
+    contract_addr: &Addr,
+    owner: &Addr,
+    from: &Addr,
+    to: Option<&Addr>,
+    amount: u128,
+    recover_claims: bool,
+    recover_escrow: bool,
+) -> Result<AppResponse, AnyError> {
+    app.execute(
+        owner.clone(),
+        wasm_execute_msg(
+            contract_addr,
+            &ExecuteMsg::ComplianceRecover {
+                from: from.to_string(),
+                to: to.map(Addr::to_string),
+                amount: amount.into(),
+                recover_claims,
+                recover_escrow,
+            },
+        )
+        .into(),
+    )
+}
+
 // a mock contract that can receive cw20 tokens
 mod mock_cw20_receiver {
 
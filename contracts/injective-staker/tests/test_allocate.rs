@@ -11,7 +11,7 @@ mod allocate {
         allocate, assert_error, assert_event_with_attributes, get_allocations, get_share_price,
         get_share_price_num_denom, get_total_allocated, instantiate_staker,
         instantiate_staker_with_min_deposit_and_initial_stake, move_days_forward, pause,
-        set_up_allocation,
+        reconcile_delegations, set_up_allocation,
     };
 
     #[test]
@@ -250,6 +250,7 @@ mod allocate {
             percentage: Decimal::percent(50),
         }))
         .unwrap();
+        reconcile_delegations(&mut app, &contract_addr, &owner).unwrap();
 
         allocate(&mut app, &anyone, &contract_addr, ONE_INJ * 2, &recipient).unwrap();
 
@@ -0,0 +1,201 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod allocate_percentage {
+
+    use cosmwasm_std::{Addr, Uint128};
+    use cw_multi_test::error::AnyError;
+    use cw_multi_test::{App, AppResponse, Executor, IntoBech32};
+    use injective_staker::{msg::ExecuteMsg, ONE_INJ};
+
+    use crate::helpers::{
+        allocate, assert_error, deallocate, distribute_rewards, get_allocations,
+        get_distribution_amounts, get_total_allocated, instantiate_staker_with_min_deposit,
+        mint_inj, query_truinj_balance, raise_share_price, set_up_test_allocation, stake,
+        wasm_execute_msg, whitelist_user,
+    };
+
+    fn allocate_percentage(
+        app: &mut App,
+        staker_addr: &Addr,
+        allocator: &Addr,
+        recipient: &Addr,
+        bps: u16,
+    ) -> Result<AppResponse, AnyError> {
+        app.execute(
+            allocator.clone(),
+            wasm_execute_msg(
+                staker_addr,
+                &ExecuteMsg::AllocatePercentage {
+                    recipient: recipient.to_string(),
+                    bps,
+                },
+            )
+            .into(),
+        )
+    }
+
+    /// Instantiates the staker with an allocator holding 100 TruINJ who allocates 25% to a recipient.
+    fn setup() -> (App, Addr, Addr, Addr, Addr) {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) =
+            instantiate_staker_with_min_deposit(owner.clone(), "treasury".into_bech32(), 0);
+
+        let allocator: Addr = "allocator".into_bech32();
+        let recipient: Addr = "recipient".into_bech32();
+        whitelist_user(&mut app, &staker_addr, &owner, &allocator);
+        mint_inj(&mut app, &allocator, 200 * ONE_INJ);
+        stake(&mut app, &allocator, &staker_addr, 100 * ONE_INJ).unwrap();
+
+        allocate_percentage(&mut app, &staker_addr, &allocator, &recipient, 2500).unwrap();
+
+        (app, staker_addr, owner, allocator, recipient)
+    }
+
+    #[test]
+    fn test_percentage_allocation_tracks_balance() {
+        let (mut app, staker_addr, _, allocator, _) = setup();
+
+        let (total_allocated, _, _) = get_total_allocated(&app, &staker_addr, &allocator);
+        assert_eq!(total_allocated, Uint128::new(25 * ONE_INJ));
+
+        // staking more increases the allocated amount
+        stake(&mut app, &allocator, &staker_addr, 100 * ONE_INJ).unwrap();
+        let (total_allocated, _, _) = get_total_allocated(&app, &staker_addr, &allocator);
+        assert_eq!(total_allocated, Uint128::new(50 * ONE_INJ));
+
+        let allocations = get_allocations(&app, &staker_addr, &allocator);
+        assert_eq!(allocations[0].bps, Some(2500));
+    }
+
+    #[test]
+    fn test_percentage_allocation_distribution_amounts() {
+        let (mut app, staker_addr, owner, allocator, recipient) = setup();
+
+        // a fixed allocation of the same INJ amount made at the same share price
+        let other_allocator: Addr = "other".into_bech32();
+        set_up_test_allocation(
+            &mut app,
+            &owner,
+            &staker_addr,
+            &other_allocator,
+            &recipient,
+            25 * ONE_INJ,
+        );
+
+        raise_share_price(&mut app, &staker_addr, &owner);
+
+        let percentage_amounts =
+            get_distribution_amounts(&app, &staker_addr, &allocator, Some(&recipient));
+        assert!(percentage_amounts.1 > 0);
+        assert_eq!(
+            percentage_amounts,
+            get_distribution_amounts(&app, &staker_addr, &other_allocator, Some(&recipient))
+        );
+        assert_eq!(
+            get_distribution_amounts(&app, &staker_addr, &allocator, None),
+            percentage_amounts
+        );
+
+        distribute_rewards(&mut app, &staker_addr, &allocator, &recipient, false, None);
+        assert_eq!(
+            query_truinj_balance(&app, &recipient, &staker_addr),
+            percentage_amounts.1
+        );
+
+        // nothing is left to distribute
+        let (_, truinj_amount, _) =
+            get_distribution_amounts(&app, &staker_addr, &allocator, Some(&recipient));
+        assert_eq!(truinj_amount, 0);
+    }
+
+    #[test]
+    fn test_percentage_allocation_deposits_do_not_earn_past_rewards() {
+        let (mut app, staker_addr, owner, allocator, recipient) = setup();
+        raise_share_price(&mut app, &staker_addr, &owner);
+
+        let (_, truinj_amount, fees) =
+            get_distribution_amounts(&app, &staker_addr, &allocator, Some(&recipient));
+        assert!(truinj_amount > 0);
+
+        // TruINJ staked after the rewards accrued only adds to the allocated amount
+        let (total_allocated, _, _) = get_total_allocated(&app, &staker_addr, &allocator);
+        stake(&mut app, &allocator, &staker_addr, 100 * ONE_INJ).unwrap();
+        let (new_total_allocated, _, _) = get_total_allocated(&app, &staker_addr, &allocator);
+        assert!(new_total_allocated > total_allocated);
+
+        let (_, new_truinj_amount, new_fees) =
+            get_distribution_amounts(&app, &staker_addr, &allocator, Some(&recipient));
+        assert!(new_truinj_amount.abs_diff(truinj_amount) <= 1);
+        assert!(new_fees.abs_diff(fees) <= 1);
+    }
+
+    #[test]
+    fn test_percentage_allocation_update_keeps_owed_rewards_at_new_share() {
+        let (mut app, staker_addr, owner, allocator, recipient) = setup();
+        raise_share_price(&mut app, &staker_addr, &owner);
+
+        let (_, truinj_amount, _) =
+            get_distribution_amounts(&app, &staker_addr, &allocator, Some(&recipient));
+
+        // doubling the share doubles the rewards owed
+        allocate_percentage(&mut app, &staker_addr, &allocator, &recipient, 5000).unwrap();
+        let (_, new_truinj_amount, _) =
+            get_distribution_amounts(&app, &staker_addr, &allocator, Some(&recipient));
+        assert!(new_truinj_amount.abs_diff(2 * truinj_amount) <= 2);
+    }
+
+    #[test]
+    fn test_percentage_allocations_above_total_limit_fail() {
+        let (mut app, staker_addr, _, allocator, recipient) = setup();
+
+        let other: Addr = "other".into_bech32();
+        allocate_percentage(&mut app, &staker_addr, &allocator, &other, 7500).unwrap();
+
+        let response = allocate_percentage(&mut app, &staker_addr, &allocator, &other, 7501);
+        assert_error(
+            response,
+            "Percentage allocations cannot exceed 10000 bps in total",
+        );
+
+        // updating an allocation replaces its share
+        allocate_percentage(&mut app, &staker_addr, &allocator, &recipient, 2000).unwrap();
+        allocate_percentage(&mut app, &staker_addr, &allocator, &other, 8000).unwrap();
+    }
+
+    #[test]
+    fn test_percentage_allocation_removed_with_zero_bps() {
+        let (mut app, staker_addr, _, allocator, recipient) = setup();
+
+        allocate_percentage(&mut app, &staker_addr, &allocator, &recipient, 0).unwrap();
+        assert!(get_allocations(&app, &staker_addr, &allocator).is_empty());
+
+        let response = allocate_percentage(&mut app, &staker_addr, &allocator, &recipient, 0);
+        assert_error(response, "No Allocation to recipient");
+    }
+
+    #[test]
+    fn test_mixing_allocation_modes_fails() {
+        let (mut app, staker_addr, _, allocator, recipient) = setup();
+
+        let response = allocate(&mut app, &allocator, &staker_addr, ONE_INJ, &recipient);
+        assert_error(
+            response,
+            "Allocation to recipient uses a different allocation mode",
+        );
+
+        let response = deallocate(&mut app, &allocator, &staker_addr, ONE_INJ, &recipient);
+        assert_error(
+            response,
+            "Allocation to recipient uses a different allocation mode",
+        );
+
+        let other: Addr = "other".into_bech32();
+        allocate(&mut app, &allocator, &staker_addr, ONE_INJ, &other).unwrap();
+        let response = allocate_percentage(&mut app, &staker_addr, &allocator, &other, 100);
+        assert_error(
+            response,
+            "Allocation to recipient uses a different allocation mode",
+        );
+    }
+}
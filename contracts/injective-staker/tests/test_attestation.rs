@@ -0,0 +1,258 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod attestation {
+    use cosmwasm_std::{Addr, Binary};
+    use cw_multi_test::error::AnyError;
+    use cw_multi_test::{App, AppResponse, Executor, IntoBech32};
+    use injective_staker::{
+        attestation::attestation_hash,
+        msg::{ExecuteMsg, GetAgentKeyResponse, QueryMsg},
+        state::{Attestation, UserStatus},
+    };
+    use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+
+    use crate::helpers::{
+        add_agent, assert_error, blacklist_user, instantiate_staker, is_user_whitelisted,
+        move_days_forward, query_user_status, wasm_execute_msg,
+    };
+
+    const ONE_DAY: u64 = 24 * 60 * 60;
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32].into()).unwrap()
+    }
+
+    fn register_agent_key(
+        app: &mut App,
+        staker_addr: &Addr,
+        agent: &Addr,
+        key: &SigningKey,
+    ) -> Result<AppResponse, AnyError> {
+        let public_key = key.verifying_key().to_encoded_point(true);
+        app.execute(
+            agent.clone(),
+            wasm_execute_msg(
+                staker_addr,
+                &ExecuteMsg::RegisterAgentKey {
+                    public_key: Binary::from(public_key.as_bytes()),
+                },
+            )
+            .into(),
+        )
+    }
+
+    fn attestation(
+        app: &App,
+        staker_addr: &Addr,
+        agent: &Addr,
+        user: &Addr,
+        nonce: u64,
+    ) -> Attestation {
+        Attestation {
+            agent: agent.to_string(),
+            user: user.to_string(),
+            status: UserStatus::Whitelisted,
+            valid_until: None,
+            expires_at: app.block_info().time.seconds() + ONE_DAY,
+            nonce,
+            chain_id: app.block_info().chain_id,
+            contract_address: staker_addr.to_string(),
+        }
+    }
+
+    fn sign(key: &SigningKey, attestation: &Attestation) -> Binary {
+        let signature: Signature = key
+            .sign_prehash(&attestation_hash(attestation).unwrap())
+            .unwrap();
+        Binary::from(signature.to_bytes().as_slice())
+    }
+
+    fn self_whitelist(
+        app: &mut App,
+        staker_addr: &Addr,
+        user: &Addr,
+        attestation: Attestation,
+        signature: Binary,
+    ) -> Result<AppResponse, AnyError> {
+        app.execute(
+            user.clone(),
+            wasm_execute_msg(
+                staker_addr,
+                &ExecuteMsg::SelfWhitelist {
+                    attestation,
+                    signature,
+                },
+            )
+            .into(),
+        )
+    }
+
+    /// Instantiates the staker with an agent that has registered a signing key.
+    fn setup() -> (App, Addr, Addr, SigningKey) {
+        let owner: Addr = "owner".into_bech32();
+        let agent: Addr = "agent".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        add_agent(&mut app, &staker_addr, &owner, &agent);
+
+        let key = signing_key(1);
+        register_agent_key(&mut app, &staker_addr, &agent, &key).unwrap();
+
+        (app, staker_addr, agent, key)
+    }
+
+    #[test]
+    fn test_register_agent_key() {
+        let (app, staker_addr, agent, key) = setup();
+
+        let response: GetAgentKeyResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staker_addr,
+                &QueryMsg::GetAgentKey {
+                    agent: agent.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            response.public_key,
+            Some(Binary::from(
+                key.verifying_key().to_encoded_point(true).as_bytes()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_register_agent_key_by_non_agent_fails() {
+        let (mut app, staker_addr, _, key) = setup();
+        let user: Addr = "user".into_bech32();
+
+        let response = register_agent_key(&mut app, &staker_addr, &user, &key);
+        assert_error(response, "Caller is not an agent");
+    }
+
+    #[test]
+    fn test_self_whitelist() {
+        let (mut app, staker_addr, agent, key) = setup();
+        let user: Addr = "user".into_bech32();
+
+        let attestation = attestation(&app, &staker_addr, &agent, &user, 1);
+        let signature = sign(&key, &attestation);
+        let response =
+            self_whitelist(&mut app, &staker_addr, &user, attestation, signature).unwrap();
+
+        let event = response.events.last().unwrap();
+        assert_eq!(event.ty, "wasm-self_whitelisted");
+        assert_eq!(event.attributes[2].value, agent.to_string());
+        assert!(is_user_whitelisted(&app, &user, &staker_addr));
+    }
+
+    #[test]
+    fn test_self_whitelist_with_replayed_nonce_fails() {
+        let (mut app, staker_addr, agent, key) = setup();
+        let user: Addr = "user".into_bech32();
+
+        let attestation = attestation(&app, &staker_addr, &agent, &user, 1);
+        let signature = sign(&key, &attestation);
+        self_whitelist(
+            &mut app,
+            &staker_addr,
+            &user,
+            attestation.clone(),
+            signature.clone(),
+        )
+        .unwrap();
+
+        let response = self_whitelist(&mut app, &staker_addr, &user, attestation, signature);
+        assert_error(response, "Attestation nonce already used");
+    }
+
+    #[test]
+    fn test_self_whitelist_for_another_user_fails() {
+        let (mut app, staker_addr, agent, key) = setup();
+        let user: Addr = "user".into_bech32();
+        let other: Addr = "other".into_bech32();
+
+        let attestation = attestation(&app, &staker_addr, &agent, &user, 1);
+        let signature = sign(&key, &attestation);
+        let response = self_whitelist(&mut app, &staker_addr, &other, attestation, signature);
+        assert_error(
+            response,
+            "Attestation is not for this user, chain or contract",
+        );
+    }
+
+    #[test]
+    fn test_self_whitelist_with_tampered_attestation_fails() {
+        let (mut app, staker_addr, agent, key) = setup();
+        let user: Addr = "user".into_bech32();
+
+        let mut attestation = attestation(&app, &staker_addr, &agent, &user, 1);
+        let signature = sign(&key, &attestation);
+        attestation.valid_until = Some(u64::MAX);
+
+        let response = self_whitelist(&mut app, &staker_addr, &user, attestation, signature);
+        assert_error(response, "Invalid attestation signature");
+        assert_eq!(
+            query_user_status(&app, &user, &staker_addr),
+            UserStatus::NoStatus
+        );
+    }
+
+    #[test]
+    fn test_self_whitelist_with_expired_attestation_fails() {
+        let (mut app, staker_addr, agent, key) = setup();
+        let user: Addr = "user".into_bech32();
+
+        let attestation = attestation(&app, &staker_addr, &agent, &user, 1);
+        let signature = sign(&key, &attestation);
+        move_days_forward(&mut app, 1);
+
+        let response = self_whitelist(&mut app, &staker_addr, &user, attestation, signature);
+        assert_error(response, "Attestation has expired");
+    }
+
+    #[test]
+    fn test_self_whitelist_after_key_rotation() {
+        let (mut app, staker_addr, agent, old_key) = setup();
+        let user: Addr = "user".into_bech32();
+
+        // rotate the agent key
+        let new_key = signing_key(2);
+        register_agent_key(&mut app, &staker_addr, &agent, &new_key).unwrap();
+
+        // attestations signed with the old key are no longer accepted
+        let attestation = attestation(&app, &staker_addr, &agent, &user, 1);
+        let signature = sign(&old_key, &attestation);
+        let response = self_whitelist(
+            &mut app,
+            &staker_addr,
+            &user,
+            attestation.clone(),
+            signature,
+        );
+        assert_error(response, "Invalid attestation signature");
+
+        let signature = sign(&new_key, &attestation);
+        self_whitelist(&mut app, &staker_addr, &user, attestation, signature).unwrap();
+        assert!(is_user_whitelisted(&app, &user, &staker_addr));
+    }
+
+    #[test]
+    fn test_self_whitelist_when_blacklisted_fails() {
+        let (mut app, staker_addr, agent, key) = setup();
+        let user: Addr = "user".into_bech32();
+
+        // the user is blacklisted after being issued an attestation
+        let attestation = attestation(&app, &staker_addr, &agent, &user, 1);
+        let signature = sign(&key, &attestation);
+        blacklist_user(&mut app, &staker_addr, &agent, &user);
+
+        let response = self_whitelist(&mut app, &staker_addr, &user, attestation, signature);
+        assert_error(response, "Account is frozen");
+        assert_eq!(
+            query_user_status(&app, &user, &staker_addr),
+            UserStatus::Blacklisted
+        );
+    }
+}
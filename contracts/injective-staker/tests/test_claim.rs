@@ -5,7 +5,7 @@ mod claim {
 
     use cosmwasm_std::{Addr, Decimal};
     use cw_multi_test::{IntoBech32, StakingSudo};
-    use helpers::{mint_inj, stake};
+    use helpers::{mint_inj, reconcile_delegations, stake};
 
     use crate::helpers::{
         self, assert_error, assert_event_with_attributes, claim, get_claimable_assets,
@@ -245,6 +245,7 @@ mod claim {
             percentage: Decimal::percent(50),
         }))
         .unwrap();
+        reconcile_delegations(&mut app, &staker_addr, &owner).unwrap();
 
         move_days_forward(&mut app, 21);
 
@@ -306,6 +307,7 @@ mod claim {
             percentage: Decimal::percent(50),
         }))
         .unwrap();
+        reconcile_delegations(&mut app, &staker_addr, &owner).unwrap();
 
         // wait untill all unbondings complete
         move_days_forward(&mut app, 21);
@@ -403,6 +405,7 @@ mod claim {
             percentage: Decimal::percent(50),
         }))
         .unwrap();
+        reconcile_delegations(&mut app, &staker_addr, &owner).unwrap();
 
         // the remaining users unstake their max_withdraw which has been slashed
         let second_users = users[2..4].to_vec();
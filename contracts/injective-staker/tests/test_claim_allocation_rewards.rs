@@ -0,0 +1,240 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod claim_allocation_rewards {
+
+    use cosmwasm_std::{Addr, Uint128};
+    use cw_multi_test::error::AnyError;
+    use cw_multi_test::{App, AppResponse, Executor, IntoBech32};
+    use injective_staker::msg::{ExecuteMsg, GetClaimApprovalResponse, QueryMsg};
+
+    use crate::helpers::{
+        assert_error, blacklist_user, get_distribution_amounts,
+        instantiate_staker_with_min_deposit, move_days_forward, query_truinj_balance, set_dist_fee,
+        set_up_test_allocation, wasm_execute_msg,
+    };
+
+    const PERIOD: u64 = 7 * 24 * 60 * 60;
+
+    fn approve_recipient_claims(
+        app: &mut App,
+        staker_addr: &Addr,
+        distributor: &Addr,
+        recipient: &Addr,
+        max_per_period: u128,
+    ) -> Result<AppResponse, AnyError> {
+        app.execute(
+            distributor.clone(),
+            wasm_execute_msg(
+                staker_addr,
+                &ExecuteMsg::ApproveRecipientClaims {
+                    recipient: recipient.to_string(),
+                    max_per_period: max_per_period.into(),
+                    period: PERIOD,
+                },
+            )
+            .into(),
+        )
+    }
+
+    fn claim_allocation_rewards(
+        app: &mut App,
+        staker_addr: &Addr,
+        recipient: &Addr,
+        distributor: &Addr,
+    ) -> Result<AppResponse, AnyError> {
+        app.execute(
+            recipient.clone(),
+            wasm_execute_msg(
+                staker_addr,
+                &ExecuteMsg::ClaimAllocationRewards {
+                    distributor: distributor.to_string(),
+                },
+            )
+            .into(),
+        )
+    }
+
+    fn get_claim_approval(
+        app: &App,
+        staker_addr: &Addr,
+        distributor: &Addr,
+        recipient: &Addr,
+    ) -> GetClaimApprovalResponse {
+        app.wrap()
+            .query_wasm_smart(
+                staker_addr,
+                &QueryMsg::GetClaimApproval {
+                    distributor: distributor.to_string(),
+                    recipient: recipient.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    /// Sets up an allocation from a distributor to a recipient and accrues rewards.
+    fn setup() -> (App, Addr, Addr, Addr, Addr) {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) =
+            instantiate_staker_with_min_deposit(owner.clone(), "treasury".into_bech32(), 0);
+        set_dist_fee(&mut app, &staker_addr, &owner, 500);
+
+        let distributor: Addr = "distributor".into_bech32();
+        let recipient: Addr = "recipient".into_bech32();
+        set_up_test_allocation(
+            &mut app,
+            &owner,
+            &staker_addr,
+            &distributor,
+            &recipient,
+            100_000,
+        );
+
+        // accrue rewards
+        move_days_forward(&mut app, 30);
+
+        (app, staker_addr, owner, distributor, recipient)
+    }
+
+    #[test]
+    fn test_claim_allocation_rewards() {
+        let (mut app, staker_addr, _, distributor, recipient) = setup();
+        approve_recipient_claims(&mut app, &staker_addr, &distributor, &recipient, 100_000)
+            .unwrap();
+
+        let (_, shares, fees) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, Some(&recipient));
+        assert!(shares > 0);
+        let pre_distributor_balance = query_truinj_balance(&app, &distributor, &staker_addr);
+
+        let response =
+            claim_allocation_rewards(&mut app, &staker_addr, &recipient, &distributor).unwrap();
+
+        assert_eq!(query_truinj_balance(&app, &recipient, &staker_addr), shares);
+        assert_eq!(
+            query_truinj_balance(&app, &distributor, &staker_addr),
+            pre_distributor_balance - shares - fees
+        );
+        assert!(response
+            .events
+            .iter()
+            .any(|event| event.ty == "wasm-distributed_rewards"));
+
+        // the share price of the allocation is reset
+        let (_, shares, _) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, Some(&recipient));
+        assert_eq!(shares, 0);
+
+        let response = claim_allocation_rewards(&mut app, &staker_addr, &recipient, &distributor);
+        assert_error(response, "No allocation rewards to claim");
+    }
+
+    #[test]
+    fn test_claim_allocation_rewards_without_approval_fails() {
+        let (mut app, staker_addr, _, distributor, recipient) = setup();
+
+        let response = claim_allocation_rewards(&mut app, &staker_addr, &recipient, &distributor);
+        assert_error(response, "Recipient is not approved to claim rewards");
+
+        // a revoked approval can no longer be used
+        approve_recipient_claims(&mut app, &staker_addr, &distributor, &recipient, 100_000)
+            .unwrap();
+        app.execute(
+            distributor.clone(),
+            wasm_execute_msg(
+                &staker_addr,
+                &ExecuteMsg::RevokeRecipientClaims {
+                    recipient: recipient.to_string(),
+                },
+            )
+            .into(),
+        )
+        .unwrap();
+
+        let response = claim_allocation_rewards(&mut app, &staker_addr, &recipient, &distributor);
+        assert_error(response, "Recipient is not approved to claim rewards");
+    }
+
+    #[test]
+    fn test_claim_allocation_rewards_is_capped_at_allowance() {
+        let (mut app, staker_addr, _, distributor, recipient) = setup();
+
+        let (_, shares, fees) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, Some(&recipient));
+        let allowance = (shares + fees) / 2;
+        approve_recipient_claims(&mut app, &staker_addr, &distributor, &recipient, allowance)
+            .unwrap();
+        let pre_distributor_balance = query_truinj_balance(&app, &distributor, &staker_addr);
+
+        // the claim pays out the allowance left, net of fees
+        claim_allocation_rewards(&mut app, &staker_addr, &recipient, &distributor).unwrap();
+        let claimed_fees = allowance * 500 / 10_000;
+        assert_eq!(
+            query_truinj_balance(&app, &recipient, &staker_addr),
+            allowance - claimed_fees
+        );
+        assert_eq!(
+            query_truinj_balance(&app, &distributor, &staker_addr),
+            pre_distributor_balance - allowance
+        );
+
+        // the rewards left unpaid stay owed
+        let (_, remaining_shares, remaining_fees) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, Some(&recipient));
+        assert!((remaining_shares + remaining_fees).abs_diff(shares + fees - allowance) <= 1);
+
+        // no allowance is left for the period
+        let response = get_claim_approval(&app, &staker_addr, &distributor, &recipient);
+        assert_eq!(response.remaining_allowance, Uint128::zero());
+        let response = claim_allocation_rewards(&mut app, &staker_addr, &recipient, &distributor);
+        assert_error(response, "Claim exceeds the allowance for the period");
+    }
+
+    #[test]
+    fn test_claim_allowance_resets_each_period() {
+        let (mut app, staker_addr, _, distributor, recipient) = setup();
+
+        let (_, shares, fees) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, Some(&recipient));
+        approve_recipient_claims(
+            &mut app,
+            &staker_addr,
+            &distributor,
+            &recipient,
+            shares + fees,
+        )
+        .unwrap();
+        claim_allocation_rewards(&mut app, &staker_addr, &recipient, &distributor).unwrap();
+
+        let response = get_claim_approval(&app, &staker_addr, &distributor, &recipient);
+        assert_eq!(response.remaining_allowance, Uint128::zero());
+
+        // the allowance is restored once the period has ended
+        move_days_forward(&mut app, 7);
+        let response = get_claim_approval(&app, &staker_addr, &distributor, &recipient);
+        assert_eq!(response.remaining_allowance, Uint128::new(shares + fees));
+    }
+
+    #[test]
+    fn test_claim_allocation_rewards_by_frozen_recipient_fails() {
+        let (mut app, staker_addr, owner, distributor, recipient) = setup();
+        approve_recipient_claims(&mut app, &staker_addr, &distributor, &recipient, 100_000)
+            .unwrap();
+        blacklist_user(&mut app, &staker_addr, &owner, &recipient);
+
+        let response = claim_allocation_rewards(&mut app, &staker_addr, &recipient, &distributor);
+        assert_error(response, "Account is frozen");
+    }
+
+    #[test]
+    fn test_approve_recipient_claims_with_zero_allowance_fails() {
+        let (mut app, staker_addr, _, distributor, recipient) = setup();
+
+        let response =
+            approve_recipient_claims(&mut app, &staker_addr, &distributor, &recipient, 0);
+        assert_error(
+            response,
+            "Claim approval must have a non-zero allowance and period",
+        );
+    }
+}
@@ -0,0 +1,450 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod compliance {
+    use cosmwasm_std::{to_json_binary, Addr, Attribute, Uint128, WasmMsg};
+    use cw_multi_test::error::AnyError;
+    use cw_multi_test::{App, AppResponse, Executor, IntoBech32};
+    use injective_staker::{
+        msg::{ExecuteMsg, GetEscrowedAllocationResponse, QueryMsg},
+        ONE_INJ,
+    };
+
+    use crate::helpers::{
+        assert_error, blacklist_user, compliance_recover, get_claimable_assets, get_reserve_status,
+        instantiate_staker_with_min_deposit_and_initial_stake, mint_inj, move_days_forward,
+        query_truinj_balance, query_truinj_supply, set_dist_fee, set_up_test_allocation, stake,
+        unstake, wasm_execute_msg, whitelist_user,
+    };
+
+    /// Instantiates the staker and has alice stake 10 INJ.
+    fn setup() -> (App, Addr, Addr, Addr) {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker_with_min_deposit_and_initial_stake(
+            owner.clone(),
+            "treasury".into_bech32(),
+            0,
+            1_000_000,
+        );
+
+        let alice: Addr = "alice".into_bech32();
+        mint_inj(&mut app, &alice, 10 * ONE_INJ);
+        whitelist_user(&mut app, &staker_addr, &owner, &alice);
+        stake(&mut app, &alice, &staker_addr, 10 * ONE_INJ).unwrap();
+
+        (app, staker_addr, owner, alice)
+    }
+
+    fn transfer(
+        app: &mut App,
+        staker_addr: &Addr,
+        sender: &Addr,
+        recipient: &Addr,
+    ) -> Result<AppResponse, AnyError> {
+        app.execute(
+            sender.clone(),
+            WasmMsg::Execute {
+                contract_addr: staker_addr.to_string(),
+                msg: to_json_binary(&ExecuteMsg::Transfer {
+                    recipient: recipient.to_string(),
+                    amount: Uint128::new(1),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+        )
+    }
+
+    /// Has the allocator lock 2 TruINJ in escrow for the recipient.
+    fn allocate_escrowed(app: &mut App, staker_addr: &Addr, allocator: &Addr, recipient: &Addr) {
+        app.execute(
+            allocator.clone(),
+            wasm_execute_msg(
+                staker_addr,
+                &ExecuteMsg::AllocateEscrowed {
+                    recipient: recipient.to_string(),
+                    shares: Uint128::new(2 * ONE_INJ),
+                },
+            )
+            .into(),
+        )
+        .unwrap();
+    }
+
+    fn get_escrowed_allocation(
+        app: &App,
+        staker_addr: &Addr,
+        allocator: &Addr,
+        recipient: &Addr,
+    ) -> GetEscrowedAllocationResponse {
+        app.wrap()
+            .query_wasm_smart(
+                staker_addr,
+                &QueryMsg::GetEscrowedAllocation {
+                    allocator: allocator.to_string(),
+                    recipient: recipient.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_frozen_account_cannot_send_or_receive_truinj() {
+        let (mut app, staker_addr, owner, alice) = setup();
+        let bob: Addr = "bob".into_bech32();
+        let trufin: Addr = "trufin".into_bech32();
+
+        blacklist_user(&mut app, &staker_addr, &owner, &alice);
+
+        let response = transfer(&mut app, &staker_addr, &alice, &bob);
+        assert_error(response, "Account is frozen");
+
+        let response = transfer(&mut app, &staker_addr, &trufin, &alice);
+        assert_error(response, "Account is frozen");
+
+        // unfrozen accounts can still transfer
+        transfer(&mut app, &staker_addr, &trufin, &bob).unwrap();
+    }
+
+    #[test]
+    fn test_compliance_recover_transfers_truinj_and_claims() {
+        let (mut app, staker_addr, owner, alice) = setup();
+        let recovery: Addr = "recovery".into_bech32();
+
+        unstake(&mut app, &alice, &staker_addr, ONE_INJ).unwrap();
+        let claims = get_claimable_assets(&app, &staker_addr, &alice);
+        assert_eq!(claims.len(), 1);
+
+        blacklist_user(&mut app, &staker_addr, &owner, &alice);
+
+        let balance = query_truinj_balance(&app, &alice, &staker_addr);
+        let response = compliance_recover(
+            &mut app,
+            &staker_addr,
+            &owner,
+            &alice,
+            Some(&recovery),
+            balance,
+            true,
+            false,
+        )
+        .unwrap();
+
+        let event = response
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm-compliance_recovered")
+            .unwrap();
+        assert_eq!(
+            event.attributes[1..],
+            vec![
+                Attribute::new("operator", owner.to_string()),
+                Attribute::new("from", alice.to_string()),
+                Attribute::new("to", recovery.to_string()),
+                Attribute::new("action", "transfer"),
+                Attribute::new("amount", balance.to_string()),
+                Attribute::new("claims_recovered", "1"),
+                Attribute::new("claims_amount", claims[0].amount.to_string()),
+                Attribute::new("escrowed_allocations_recovered", "0"),
+                Attribute::new("escrowed_shares", "0"),
+                Attribute::new("remaining_balance", "0"),
+            ]
+        );
+
+        assert_eq!(query_truinj_balance(&app, &alice, &staker_addr), 0);
+        assert_eq!(query_truinj_balance(&app, &recovery, &staker_addr), balance);
+        assert!(get_claimable_assets(&app, &staker_addr, &alice).is_empty());
+        assert_eq!(get_claimable_assets(&app, &staker_addr, &recovery), claims);
+    }
+
+    #[test]
+    fn test_compliance_recover_burns_truinj_and_cancels_claims() {
+        let (mut app, staker_addr, owner, alice) = setup();
+
+        unstake(&mut app, &alice, &staker_addr, ONE_INJ).unwrap();
+        let claims = get_claimable_assets(&app, &staker_addr, &alice);
+        assert_eq!(claims.len(), 1);
+
+        blacklist_user(&mut app, &staker_addr, &owner, &alice);
+
+        let balance = query_truinj_balance(&app, &alice, &staker_addr);
+        let supply = query_truinj_supply(&app, &staker_addr);
+        let reserve = get_reserve_status(&app, &staker_addr).balance;
+        let response = compliance_recover(
+            &mut app,
+            &staker_addr,
+            &owner,
+            &alice,
+            None,
+            balance,
+            true,
+            false,
+        )
+        .unwrap();
+
+        let event = response
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm-compliance_recovered")
+            .unwrap();
+        assert_eq!(event.attributes[4], Attribute::new("action", "burn"));
+        assert_eq!(event.attributes[6], Attribute::new("claims_recovered", "1"));
+        assert_eq!(query_truinj_balance(&app, &alice, &staker_addr), 0);
+        assert_eq!(query_truinj_supply(&app, &staker_addr), supply - balance);
+
+        // the cancelled claims are only added to the reserve once their undelegation completes
+        assert!(get_claimable_assets(&app, &staker_addr, &alice).is_empty());
+        let status = get_reserve_status(&app, &staker_addr);
+        assert_eq!(status.balance, reserve);
+        assert_eq!(status.pending_cancelled_claims, claims[0].amount);
+
+        move_days_forward(&mut app, 21);
+        let status = get_reserve_status(&app, &staker_addr);
+        assert_eq!(status.balance, reserve + claims[0].amount);
+        assert!(status.pending_cancelled_claims.is_zero());
+    }
+
+    #[test]
+    fn test_compliance_recover_partially() {
+        let (mut app, staker_addr, owner, alice) = setup();
+        let bob: Addr = "bob".into_bech32();
+        let recovery: Addr = "recovery".into_bech32();
+
+        unstake(&mut app, &alice, &staker_addr, ONE_INJ).unwrap();
+        allocate_escrowed(&mut app, &staker_addr, &alice, &bob);
+        let claims = get_claimable_assets(&app, &staker_addr, &alice);
+        blacklist_user(&mut app, &staker_addr, &owner, &alice);
+
+        // recover part of the TruINJ only, leaving the claims and the escrowed principal in place
+        let balance = query_truinj_balance(&app, &alice, &staker_addr);
+        compliance_recover(
+            &mut app,
+            &staker_addr,
+            &owner,
+            &alice,
+            Some(&recovery),
+            ONE_INJ,
+            false,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            query_truinj_balance(&app, &alice, &staker_addr),
+            balance - ONE_INJ
+        );
+        assert_eq!(query_truinj_balance(&app, &recovery, &staker_addr), ONE_INJ);
+        assert_eq!(get_claimable_assets(&app, &staker_addr, &alice), claims);
+        assert!(get_claimable_assets(&app, &staker_addr, &recovery).is_empty());
+        assert!(get_escrowed_allocation(&app, &staker_addr, &alice, &bob)
+            .allocation
+            .is_some());
+    }
+
+    #[test]
+    fn test_compliance_recover_transfers_escrowed_principal() {
+        let (mut app, staker_addr, owner, alice) = setup();
+        let bob: Addr = "bob".into_bech32();
+        let recovery: Addr = "recovery".into_bech32();
+
+        allocate_escrowed(&mut app, &staker_addr, &alice, &bob);
+        blacklist_user(&mut app, &staker_addr, &owner, &alice);
+
+        let balance = query_truinj_balance(&app, &alice, &staker_addr);
+        let response = compliance_recover(
+            &mut app,
+            &staker_addr,
+            &owner,
+            &alice,
+            Some(&recovery),
+            balance,
+            false,
+            true,
+        )
+        .unwrap();
+
+        let event = response
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm-compliance_recovered")
+            .unwrap();
+        assert_eq!(
+            event.attributes[8..10],
+            vec![
+                Attribute::new("escrowed_allocations_recovered", "1"),
+                Attribute::new("escrowed_shares", (2 * ONE_INJ).to_string()),
+            ]
+        );
+
+        // the principal is moved to the recovery address along with the TruINJ
+        assert_eq!(
+            query_truinj_balance(&app, &recovery, &staker_addr),
+            balance + 2 * ONE_INJ
+        );
+        assert_eq!(
+            get_escrowed_allocation(&app, &staker_addr, &alice, &bob).allocation,
+            None
+        );
+    }
+
+    #[test]
+    fn test_compliance_recover_burns_escrowed_principal() {
+        let (mut app, staker_addr, owner, alice) = setup();
+        let bob: Addr = "bob".into_bech32();
+
+        allocate_escrowed(&mut app, &staker_addr, &alice, &bob);
+        blacklist_user(&mut app, &staker_addr, &owner, &alice);
+
+        // the escrowed principal alone can be recovered
+        let supply = query_truinj_supply(&app, &staker_addr);
+        compliance_recover(&mut app, &staker_addr, &owner, &alice, None, 0, false, true).unwrap();
+
+        assert_eq!(
+            query_truinj_supply(&app, &staker_addr),
+            supply - 2 * ONE_INJ
+        );
+        assert_eq!(
+            get_escrowed_allocation(&app, &staker_addr, &alice, &bob).allocation,
+            None
+        );
+    }
+
+    #[test]
+    fn test_distribute_rewards_skips_frozen_fee_recipient() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker_with_min_deposit_and_initial_stake(
+            owner.clone(),
+            "treasury".into_bech32(),
+            0,
+            1_000_000,
+        );
+        let treasury: Addr = "treasury".into_bech32();
+        let distributor: Addr = "distributor".into_bech32();
+        let recipient: Addr = "recipient".into_bech32();
+
+        set_dist_fee(&mut app, &staker_addr, &owner, 500);
+        set_up_test_allocation(
+            &mut app,
+            &owner,
+            &staker_addr,
+            &distributor,
+            &recipient,
+            100_000,
+        );
+        move_days_forward(&mut app, 30);
+
+        blacklist_user(&mut app, &staker_addr, &owner, &treasury);
+        let treasury_balance = query_truinj_balance(&app, &treasury, &staker_addr);
+        let distributor_balance = query_truinj_balance(&app, &distributor, &staker_addr);
+
+        // the distribution succeeds, without charging the fee of the frozen fee recipient
+        let response = app
+            .execute(
+                distributor.clone(),
+                WasmMsg::Execute {
+                    contract_addr: staker_addr.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::DistributeRewards {
+                        recipient: recipient.to_string(),
+                        in_inj: false,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+            )
+            .unwrap();
+
+        let recipient_balance = query_truinj_balance(&app, &recipient, &staker_addr);
+        assert!(recipient_balance > 0);
+        assert_eq!(
+            query_truinj_balance(&app, &treasury, &staker_addr),
+            treasury_balance
+        );
+        assert_eq!(
+            query_truinj_balance(&app, &distributor, &staker_addr),
+            distributor_balance - recipient_balance
+        );
+
+        let event = response
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm-distributed_rewards")
+            .unwrap();
+        assert!(event.attributes.contains(&Attribute::new("fees", "0")));
+    }
+
+    #[test]
+    fn test_compliance_recover_from_unfrozen_account_fails() {
+        let (mut app, staker_addr, owner, alice) = setup();
+
+        let response = compliance_recover(
+            &mut app,
+            &staker_addr,
+            &owner,
+            &alice,
+            None,
+            1,
+            false,
+            false,
+        );
+        assert_error(response, "Account is not frozen");
+    }
+
+    #[test]
+    fn test_compliance_recover_to_frozen_account_fails() {
+        let (mut app, staker_addr, owner, alice) = setup();
+        let bob: Addr = "bob".into_bech32();
+        blacklist_user(&mut app, &staker_addr, &owner, &alice);
+        blacklist_user(&mut app, &staker_addr, &owner, &bob);
+
+        let response = compliance_recover(
+            &mut app,
+            &staker_addr,
+            &owner,
+            &alice,
+            Some(&bob),
+            1,
+            false,
+            false,
+        );
+        assert_error(response, "Account is frozen");
+    }
+
+    #[test]
+    fn test_compliance_recover_by_non_owner_fails() {
+        let (mut app, staker_addr, owner, alice) = setup();
+        blacklist_user(&mut app, &staker_addr, &owner, &alice);
+
+        let response = compliance_recover(
+            &mut app,
+            &staker_addr,
+            &alice,
+            &alice,
+            None,
+            1,
+            false,
+            false,
+        );
+        assert_error(response, "Only the owner can call this method");
+    }
+
+    #[test]
+    fn test_compliance_recover_with_nothing_to_recover_fails() {
+        let (mut app, staker_addr, owner, alice) = setup();
+        let recovery: Addr = "recovery".into_bech32();
+        blacklist_user(&mut app, &staker_addr, &owner, &alice);
+
+        let response = compliance_recover(
+            &mut app,
+            &staker_addr,
+            &owner,
+            &alice,
+            Some(&recovery),
+            0,
+            true,
+            true,
+        );
+        assert_error(response, "Nothing to recover");
+    }
+}
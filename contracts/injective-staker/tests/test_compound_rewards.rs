@@ -3,14 +3,20 @@ pub mod helpers;
 #[cfg(test)]
 mod compound_rewards {
 
-    use cosmwasm_std::{to_json_binary, Addr, Uint128, WasmMsg};
+    use cosmwasm_std::{to_json_binary, Addr, Attribute, Uint128, WasmMsg};
     use cw_multi_test::{Executor, IntoBech32};
     use helpers::{mint_inj, stake};
-    use injective_staker::{msg::ExecuteMsg, FEE_PRECISION, SHARE_PRICE_SCALING_FACTOR};
+    use injective_staker::{
+        msg::{ExecuteMsg, GetCompoundConfigResponse, QueryMsg},
+        state::Role,
+        FEE_PRECISION, SHARE_PRICE_SCALING_FACTOR,
+    };
 
     use crate::helpers::{
-        self, add_validator, assert_event_with_attributes, get_share_price, get_total_rewards,
-        get_total_staked, instantiate_staker, move_days_forward, query_truinj_balance,
+        self, add_validator, assert_error, assert_event_with_attributes, compound_rewards,
+        compound_rewards_page, fee_recipient_balances, get_compound_config, get_share_price,
+        get_total_rewards, get_total_staked, grant_role, instantiate_staker, move_days_forward,
+        query_truinj_balance, set_compound_config, set_fee, set_fee_recipients,
         set_min_deposit_for_test_overflow, stake_to_specific_validator, whitelist_user,
     };
 
@@ -39,7 +45,11 @@ mod compound_rewards {
         let total_staked = get_total_staked(&app, &contract_addr);
         let total_rewards = get_total_rewards(&app, &contract_addr);
 
-        let msg = ExecuteMsg::CompoundRewards;
+        let msg = ExecuteMsg::CompoundRewards {
+            start_after: None,
+            limit: None,
+            min_reward: None,
+        };
 
         let cosmos_msg = WasmMsg::Execute {
             contract_addr: contract_addr.to_string(),
@@ -60,7 +70,14 @@ mod compound_rewards {
             vec![
                 ("amount", total_rewards).into(),
                 ("treasury_shares_minted", Uint128::zero()).into(),
-                ("treasury_balance", Uint128::zero()).into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&"treasury".into_bech32(), 0)]),
+                )
+                    .into(),
+                ("keeper", anyone.clone()).into(),
+                ("keeper_bounty", Uint128::zero()).into(),
+                ("sweep_complete", "true").into(),
             ],
             contract_addr,
         );
@@ -132,7 +149,11 @@ mod compound_rewards {
         let total_staked = get_total_staked(&app, &contract_addr);
         let total_rewards = get_total_rewards(&app, &contract_addr);
 
-        let msg = ExecuteMsg::CompoundRewards;
+        let msg = ExecuteMsg::CompoundRewards {
+            start_after: None,
+            limit: None,
+            min_reward: None,
+        };
 
         let cosmos_msg = WasmMsg::Execute {
             contract_addr: contract_addr.to_string(),
@@ -153,7 +174,14 @@ mod compound_rewards {
             vec![
                 ("amount", total_rewards).into(),
                 ("treasury_shares_minted", Uint128::zero()).into(),
-                ("treasury_balance", Uint128::zero()).into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&"treasury".into_bech32(), 0)]),
+                )
+                    .into(),
+                ("keeper", anyone.clone()).into(),
+                ("keeper_bounty", Uint128::zero()).into(),
+                ("sweep_complete", "true").into(),
             ],
             contract_addr,
         );
@@ -194,7 +222,11 @@ mod compound_rewards {
 
         let treasury_pre_balance = query_truinj_balance(&app, &treasury, &contract_addr);
 
-        let msg = ExecuteMsg::CompoundRewards;
+        let msg = ExecuteMsg::CompoundRewards {
+            start_after: None,
+            limit: None,
+            min_reward: None,
+        };
 
         let cosmos_msg = WasmMsg::Execute {
             contract_addr: contract_addr.to_string(),
@@ -222,7 +254,14 @@ mod compound_rewards {
             vec![
                 ("amount", total_rewards).into(),
                 ("treasury_shares_minted", Uint128::from(treasury_fee_shares)).into(),
-                ("treasury_balance", Uint128::from(treasury_fee_shares)).into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&treasury, treasury_fee_shares)]),
+                )
+                    .into(),
+                ("keeper", anyone.clone()).into(),
+                ("keeper_bounty", Uint128::zero()).into(),
+                ("sweep_complete", "true").into(),
             ],
             contract_addr.clone(),
         );
@@ -239,6 +278,97 @@ mod compound_rewards {
         );
     }
 
+    #[test]
+    fn test_compound_rewards_with_multiple_fee_recipients() {
+        let owner = "owner".into_bech32();
+        let treasury: Addr = "treasury".into_bech32();
+        let other_recipient: Addr = "other_recipient".into_bech32();
+        let (mut app, contract_addr, _) = instantiate_staker(owner.clone(), treasury.clone());
+
+        let anyone: Addr = "anyone".into_bech32();
+        let inj_to_mint = 100000000000;
+        mint_inj(&mut app, &anyone, inj_to_mint);
+        whitelist_user(&mut app, &contract_addr, &owner, &anyone);
+        set_min_deposit_for_test_overflow(&mut app, contract_addr.to_string(), owner.clone(), 0);
+        stake(&mut app, &anyone, &contract_addr, inj_to_mint).unwrap();
+
+        move_days_forward(&mut app, 1);
+
+        // split the fees 70/30 between the treasury and another recipient
+        set_fee(&mut app, &contract_addr, &owner, 1000);
+        set_fee_recipients(
+            &mut app,
+            &contract_addr,
+            &owner,
+            vec![(&treasury, 7000), (&other_recipient, 3000)],
+        );
+
+        let total_rewards = get_total_rewards(&app, &contract_addr);
+
+        let restake_res = app
+            .execute(
+                anyone.clone(),
+                WasmMsg::Execute {
+                    contract_addr: contract_addr.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::CompoundRewards {
+                        start_after: None,
+                        limit: None,
+                        min_reward: None,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+            )
+            .unwrap();
+
+        let share_price = get_share_price(&app, &contract_addr);
+        let treasury_fees = total_rewards.u128() * 1000 / u128::from(FEE_PRECISION);
+        let treasury_fee_shares = (treasury_fees * SHARE_PRICE_SCALING_FACTOR) / share_price;
+
+        // the other recipient receives its share rounded down, and the remainder goes to the first recipient
+        let other_recipient_shares = treasury_fee_shares * 3000 / u128::from(FEE_PRECISION);
+        let treasury_shares = treasury_fee_shares - other_recipient_shares;
+
+        assert_eq!(
+            query_truinj_balance(&app, &treasury, &contract_addr),
+            treasury_shares
+        );
+        assert_eq!(
+            query_truinj_balance(&app, &other_recipient, &contract_addr),
+            other_recipient_shares
+        );
+
+        assert_event_with_attributes(
+            &restake_res.events,
+            "wasm-restaked",
+            vec![
+                ("amount", total_rewards).into(),
+                ("treasury_shares_minted", Uint128::from(treasury_fee_shares)).into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![
+                        (&treasury, treasury_shares),
+                        (&other_recipient, other_recipient_shares),
+                    ]),
+                )
+                    .into(),
+                ("keeper", anyone.clone()).into(),
+                ("keeper_bounty", Uint128::zero()).into(),
+                ("sweep_complete", "true").into(),
+            ],
+            contract_addr.clone(),
+        );
+
+        // a separate mint event is emitted for each recipient
+        let mint_events: Vec<_> = restake_res
+            .events
+            .iter()
+            .filter(|e| e.ty == "wasm" && e.attributes.iter().any(|a| a.value == "mint"))
+            .collect();
+        assert_eq!(mint_events.len(), 2);
+    }
+
     #[test]
     fn test_compound_rewards_when_no_rewards_have_accrued() {
         let owner = "owner".into_bech32();
@@ -271,7 +401,11 @@ mod compound_rewards {
 
         let treasury_pre_balance = query_truinj_balance(&app, &treasury, &contract_addr);
 
-        let msg = ExecuteMsg::CompoundRewards;
+        let msg = ExecuteMsg::CompoundRewards {
+            start_after: None,
+            limit: None,
+            min_reward: None,
+        };
 
         let cosmos_msg = WasmMsg::Execute {
             contract_addr: contract_addr.to_string(),
@@ -289,4 +423,490 @@ mod compound_rewards {
         assert!(new_total_staked == total_staked);
         assert!(treasury_post_balance == treasury_pre_balance);
     }
+
+    #[test]
+    fn test_compound_rewards_pays_keeper_bounty() {
+        let owner = "owner".into_bech32();
+        let treasury: Addr = "treasury".into_bech32();
+        let (mut app, contract_addr, _) = instantiate_staker(owner.clone(), treasury.clone());
+
+        let keeper: Addr = "keeper".into_bech32();
+        let inj_to_mint = 100000000000;
+        mint_inj(&mut app, &keeper, inj_to_mint);
+        whitelist_user(&mut app, &contract_addr, &owner, &keeper);
+        set_min_deposit_for_test_overflow(&mut app, contract_addr.to_string(), owner.clone(), 0);
+        stake(&mut app, &keeper, &contract_addr, inj_to_mint).unwrap();
+
+        move_days_forward(&mut app, 1);
+
+        // pay a 1% bounty out of the 10% treasury fee
+        set_fee(&mut app, &contract_addr, &owner, 1000);
+        set_compound_config(&mut app, &contract_addr, &owner, 100, 0, false);
+
+        let total_rewards = get_total_rewards(&app, &contract_addr);
+        let share_price = get_share_price(&app, &contract_addr);
+        let keeper_pre_balance = query_truinj_balance(&app, &keeper, &contract_addr);
+
+        let restake_res = compound_rewards(&mut app, &contract_addr, &keeper).unwrap();
+
+        let fee_shares = (total_rewards.u128() * 1000 / u128::from(FEE_PRECISION))
+            * SHARE_PRICE_SCALING_FACTOR
+            / share_price;
+        let bounty_shares = (total_rewards.u128() * 100 / u128::from(FEE_PRECISION))
+            * SHARE_PRICE_SCALING_FACTOR
+            / share_price;
+        let treasury_shares = fee_shares - bounty_shares;
+
+        // the bounty is carved out of the treasury fees
+        assert_eq!(
+            query_truinj_balance(&app, &keeper, &contract_addr),
+            keeper_pre_balance + bounty_shares
+        );
+        assert_eq!(
+            query_truinj_balance(&app, &treasury, &contract_addr),
+            treasury_shares
+        );
+
+        assert_event_with_attributes(
+            &restake_res.events,
+            "wasm-restaked",
+            vec![
+                ("amount", total_rewards).into(),
+                ("treasury_shares_minted", Uint128::from(treasury_shares)).into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&treasury, treasury_shares)]),
+                )
+                    .into(),
+                ("keeper", keeper.clone()).into(),
+                ("keeper_bounty", Uint128::from(bounty_shares)).into(),
+                ("sweep_complete", "true").into(),
+            ],
+            contract_addr,
+        );
+    }
+
+    #[test]
+    fn test_compound_rewards_before_min_interval_fails() {
+        let owner = "owner".into_bech32();
+        let (mut app, contract_addr, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let anyone: Addr = "anyone".into_bech32();
+        let inj_to_mint = 1000000000;
+        mint_inj(&mut app, &anyone, inj_to_mint);
+        whitelist_user(&mut app, &contract_addr, &owner, &anyone);
+        set_min_deposit_for_test_overflow(&mut app, contract_addr.to_string(), owner.clone(), 0);
+        stake(&mut app, &anyone, &contract_addr, inj_to_mint).unwrap();
+
+        set_compound_config(&mut app, &contract_addr, &owner, 0, 2 * 24 * 60 * 60, false);
+
+        move_days_forward(&mut app, 1);
+        compound_rewards(&mut app, &contract_addr, &anyone).unwrap();
+
+        move_days_forward(&mut app, 1);
+        let response = compound_rewards(&mut app, &contract_addr, &anyone);
+        assert_error(
+            response,
+            "Minimum interval between compounds has not elapsed",
+        );
+
+        move_days_forward(&mut app, 1);
+        assert!(compound_rewards(&mut app, &contract_addr, &anyone).is_ok());
+    }
+
+    #[test]
+    fn test_compound_rewards_restricted_to_keepers() {
+        let owner = "owner".into_bech32();
+        let (mut app, contract_addr, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let anyone: Addr = "anyone".into_bech32();
+        let keeper: Addr = "keeper".into_bech32();
+        grant_role(&mut app, &contract_addr, &owner, Role::Keeper, &keeper);
+
+        set_compound_config(&mut app, &contract_addr, &owner, 0, 0, true);
+
+        let response = compound_rewards(&mut app, &contract_addr, &anyone);
+        assert_error(response, "Caller does not have the keeper role");
+
+        assert!(compound_rewards(&mut app, &contract_addr, &keeper).is_ok());
+        assert!(compound_rewards(&mut app, &contract_addr, &owner).is_ok());
+    }
+
+    #[test]
+    fn test_set_compound_config() {
+        let owner = "owner".into_bech32();
+        let (mut app, contract_addr, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+        set_fee(&mut app, &contract_addr, &owner, 1000);
+
+        let response = app
+            .execute(
+                owner.clone(),
+                WasmMsg::Execute {
+                    contract_addr: contract_addr.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::SetCompoundConfig {
+                        keeper_bounty: 50,
+                        min_compound_interval: 3600,
+                        keepers_only: true,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+            )
+            .unwrap();
+
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-set_compound_config",
+            vec![
+                ("keeper_bounty", "50").into(),
+                ("min_compound_interval", "3600").into(),
+                ("keepers_only", "true").into(),
+            ],
+            contract_addr.clone(),
+        );
+
+        let config: GetCompoundConfigResponse = app
+            .wrap()
+            .query_wasm_smart(&contract_addr, &QueryMsg::GetCompoundConfig {})
+            .unwrap();
+        assert_eq!(
+            config,
+            GetCompoundConfigResponse {
+                keeper_bounty: 50,
+                min_compound_interval: 3600,
+                keepers_only: true,
+                last_compound_time: 0,
+                compound_cursor: None,
+            }
+        );
+
+        // only fee managers can set the config
+        let response = app.execute(
+            "anyone".into_bech32(),
+            WasmMsg::Execute {
+                contract_addr: contract_addr.to_string(),
+                msg: to_json_binary(&ExecuteMsg::SetCompoundConfig {
+                    keeper_bounty: 50,
+                    min_compound_interval: 3600,
+                    keepers_only: true,
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+        );
+        assert_error(response, "Caller does not have the fee_manager role");
+    }
+
+    #[test]
+    fn test_set_compound_config_with_bounty_above_fee_fails() {
+        let owner = "owner".into_bech32();
+        let (mut app, contract_addr, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+        set_fee(&mut app, &contract_addr, &owner, 100);
+
+        let response = app.execute(
+            owner.clone(),
+            WasmMsg::Execute {
+                contract_addr: contract_addr.to_string(),
+                msg: to_json_binary(&ExecuteMsg::SetCompoundConfig {
+                    keeper_bounty: 101,
+                    min_compound_interval: 0,
+                    keepers_only: false,
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+        );
+        assert_error(
+            response,
+            "Keeper bounty cannot be larger than the treasury fee",
+        );
+
+        // the fee cannot be lowered below the configured bounty either
+        set_compound_config(&mut app, &contract_addr, &owner, 100, 0, false);
+        let response = app.execute(
+            owner.clone(),
+            WasmMsg::Execute {
+                contract_addr: contract_addr.to_string(),
+                msg: to_json_binary(&ExecuteMsg::SetFee { new_fee: 99 }).unwrap(),
+                funds: vec![],
+            }
+            .into(),
+        );
+        assert_error(
+            response,
+            "Keeper bounty cannot be larger than the treasury fee",
+        );
+    }
+
+    #[test]
+    fn test_compound_rewards_paginated() {
+        let owner = "owner".into_bech32();
+        let treasury: Addr = "treasury".into_bech32();
+        let (mut app, contract_addr, default_validator) =
+            instantiate_staker(owner.clone(), treasury.clone());
+
+        let anyone: Addr = "anyone".into_bech32();
+        let inj_to_mint = 1000000000000;
+        mint_inj(&mut app, &anyone, inj_to_mint * 2);
+        whitelist_user(&mut app, &contract_addr, &owner, &anyone);
+        set_min_deposit_for_test_overflow(&mut app, contract_addr.to_string(), owner.clone(), 0);
+
+        let second_validator: Addr = "second_validator".into_bech32();
+        add_validator(
+            &mut app,
+            owner.clone(),
+            &contract_addr,
+            second_validator.clone(),
+        )
+        .unwrap();
+
+        stake(&mut app, &anyone, &contract_addr, inj_to_mint).unwrap();
+        stake_to_specific_validator(
+            &mut app,
+            &anyone,
+            &contract_addr,
+            inj_to_mint,
+            &second_validator,
+        )
+        .unwrap();
+
+        move_days_forward(&mut app, 1);
+        set_fee(&mut app, &contract_addr, &owner, 1000);
+
+        // compound the rewards of the first validator only
+        let total_rewards = get_total_rewards(&app, &contract_addr);
+        let share_price = get_share_price(&app, &contract_addr);
+        let restake_res =
+            compound_rewards_page(&mut app, &contract_addr, &anyone, None, Some(1), None).unwrap();
+
+        let remaining_rewards = get_total_rewards(&app, &contract_addr);
+        let first_slice_rewards = total_rewards - remaining_rewards;
+        assert!(!first_slice_rewards.is_zero());
+        assert!(!remaining_rewards.is_zero());
+
+        let first_validator = if default_validator < second_validator {
+            default_validator
+        } else {
+            second_validator
+        };
+        assert_eq!(
+            get_compound_config(&app, &contract_addr).compound_cursor,
+            Some(first_validator.to_string())
+        );
+
+        // the fee shares are priced at the global share price when the sweep started
+        let first_fee_shares = (first_slice_rewards.u128() * 1000 / u128::from(FEE_PRECISION))
+            * SHARE_PRICE_SCALING_FACTOR
+            / share_price;
+        assert_eq!(
+            query_truinj_balance(&app, &treasury, &contract_addr),
+            first_fee_shares
+        );
+        let event = restake_res
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm-restaked")
+            .unwrap();
+        assert_eq!(
+            event.attributes.last().unwrap(),
+            Attribute::new("sweep_complete", "false")
+        );
+
+        // resume the sweep from the persisted cursor, still pricing the fee shares at the start of the sweep
+        let restake_res =
+            compound_rewards_page(&mut app, &contract_addr, &anyone, None, Some(1), None).unwrap();
+
+        assert!(get_total_rewards(&app, &contract_addr).is_zero());
+        assert_eq!(
+            get_compound_config(&app, &contract_addr).compound_cursor,
+            None
+        );
+
+        let second_fee_shares = (remaining_rewards.u128() * 1000 / u128::from(FEE_PRECISION))
+            * SHARE_PRICE_SCALING_FACTOR
+            / share_price;
+        assert_eq!(
+            query_truinj_balance(&app, &treasury, &contract_addr),
+            first_fee_shares + second_fee_shares
+        );
+        let event = restake_res
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm-restaked")
+            .unwrap();
+        assert_eq!(
+            event.attributes.last().unwrap(),
+            Attribute::new("sweep_complete", "true")
+        );
+    }
+
+    #[test]
+    fn test_compound_rewards_skips_validators_below_min_reward() {
+        let owner = "owner".into_bech32();
+        let (mut app, contract_addr, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let anyone: Addr = "anyone".into_bech32();
+        let inj_to_mint = 1000000000;
+        mint_inj(&mut app, &anyone, inj_to_mint);
+        whitelist_user(&mut app, &contract_addr, &owner, &anyone);
+        set_min_deposit_for_test_overflow(&mut app, contract_addr.to_string(), owner.clone(), 0);
+        stake(&mut app, &anyone, &contract_addr, inj_to_mint).unwrap();
+
+        move_days_forward(&mut app, 1);
+
+        let total_rewards = get_total_rewards(&app, &contract_addr);
+        let restake_res = compound_rewards_page(
+            &mut app,
+            &contract_addr,
+            &anyone,
+            None,
+            None,
+            Some(total_rewards + Uint128::one()),
+        )
+        .unwrap();
+
+        // no rewards were compounded but the sweep is complete
+        assert!(!restake_res.events.iter().any(|e| e.ty == "wasm-restaked"));
+        assert_eq!(get_total_rewards(&app, &contract_addr), total_rewards);
+        assert_eq!(
+            get_compound_config(&app, &contract_addr).compound_cursor,
+            None
+        );
+
+        compound_rewards_page(
+            &mut app,
+            &contract_addr,
+            &anyone,
+            None,
+            None,
+            Some(total_rewards),
+        )
+        .unwrap();
+        assert!(get_total_rewards(&app, &contract_addr).is_zero());
+    }
+
+    #[test]
+    fn test_compound_rewards_min_interval_applies_to_new_sweeps_only() {
+        let owner = "owner".into_bech32();
+        let (mut app, contract_addr, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let anyone: Addr = "anyone".into_bech32();
+        let inj_to_mint = 1000000000;
+        mint_inj(&mut app, &anyone, inj_to_mint * 2);
+        whitelist_user(&mut app, &contract_addr, &owner, &anyone);
+        set_min_deposit_for_test_overflow(&mut app, contract_addr.to_string(), owner.clone(), 0);
+
+        let second_validator: Addr = "second_validator".into_bech32();
+        add_validator(
+            &mut app,
+            owner.clone(),
+            &contract_addr,
+            second_validator.clone(),
+        )
+        .unwrap();
+        stake(&mut app, &anyone, &contract_addr, inj_to_mint).unwrap();
+        stake_to_specific_validator(
+            &mut app,
+            &anyone,
+            &contract_addr,
+            inj_to_mint,
+            &second_validator,
+        )
+        .unwrap();
+
+        set_compound_config(&mut app, &contract_addr, &owner, 0, 24 * 60 * 60, false);
+        move_days_forward(&mut app, 1);
+
+        // the sweep can be completed across several calls within the interval
+        compound_rewards_page(&mut app, &contract_addr, &anyone, None, Some(1), None).unwrap();
+        compound_rewards_page(&mut app, &contract_addr, &anyone, None, Some(1), None).unwrap();
+        assert!(get_total_rewards(&app, &contract_addr).is_zero());
+
+        // but a new sweep must wait for the interval to elapse
+        let response =
+            compound_rewards_page(&mut app, &contract_addr, &anyone, None, Some(1), None);
+        assert_error(
+            response,
+            "Minimum interval between compounds has not elapsed",
+        );
+    }
+
+    #[test]
+    fn test_compound_rewards_cannot_restart_sweep_in_progress() {
+        let owner = "owner".into_bech32();
+        let (mut app, contract_addr, default_validator) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let anyone: Addr = "anyone".into_bech32();
+        let inj_to_mint = 1000000000;
+        mint_inj(&mut app, &anyone, inj_to_mint * 2);
+        whitelist_user(&mut app, &contract_addr, &owner, &anyone);
+        set_min_deposit_for_test_overflow(&mut app, contract_addr.to_string(), owner.clone(), 0);
+
+        let second_validator: Addr = "second_validator".into_bech32();
+        add_validator(
+            &mut app,
+            owner.clone(),
+            &contract_addr,
+            second_validator.clone(),
+        )
+        .unwrap();
+        stake(&mut app, &anyone, &contract_addr, inj_to_mint).unwrap();
+        stake_to_specific_validator(
+            &mut app,
+            &anyone,
+            &contract_addr,
+            inj_to_mint,
+            &second_validator,
+        )
+        .unwrap();
+
+        set_compound_config(&mut app, &contract_addr, &owner, 0, 24 * 60 * 60, false);
+        move_days_forward(&mut app, 1);
+
+        compound_rewards_page(&mut app, &contract_addr, &anyone, None, Some(1), None).unwrap();
+        let cursor = get_compound_config(&app, &contract_addr)
+            .compound_cursor
+            .unwrap();
+        let other_validator = if cursor == default_validator.to_string() {
+            second_validator
+        } else {
+            default_validator
+        };
+
+        // the sweep cannot be restarted or skipped ahead with an explicit start
+        let response = compound_rewards_page(
+            &mut app,
+            &contract_addr,
+            &anyone,
+            Some(other_validator.to_string()),
+            Some(1),
+            None,
+        );
+        assert_error(
+            response,
+            "A compound sweep is in progress and must be resumed from its cursor",
+        );
+
+        // but it can be resumed explicitly from the cursor
+        compound_rewards_page(
+            &mut app,
+            &contract_addr,
+            &anyone,
+            Some(cursor),
+            Some(1),
+            None,
+        )
+        .unwrap();
+        assert!(get_total_rewards(&app, &contract_addr).is_zero());
+    }
 }
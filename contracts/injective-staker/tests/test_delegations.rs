@@ -0,0 +1,139 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod delegations {
+    use crate::helpers::{
+        add_validator, assert_error, assert_event_with_attributes, get_delegation,
+        get_total_staked, instantiate_staker_with_min_deposit_and_initial_stake, mint_inj,
+        reconcile_delegations, redelegate, stake, unstake, whitelist_user,
+    };
+
+    use cosmwasm_std::{Addr, Decimal, Uint128};
+    use cw_multi_test::{IntoBech32, StakingSudo};
+    use injective_staker::msg::{GetValidatorResponse, QueryMsg};
+
+    fn get_ledger_delegations(app: &cw_multi_test::App, staker_addr: &Addr) -> Vec<Uint128> {
+        let response: GetValidatorResponse = app
+            .wrap()
+            .query_wasm_smart(staker_addr, &QueryMsg::GetValidators {})
+            .unwrap();
+        response
+            .validators
+            .into_iter()
+            .map(|v| v.total_staked)
+            .collect()
+    }
+
+    #[test]
+    fn test_ledger_tracks_delegations() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, validator_addr) =
+            instantiate_staker_with_min_deposit_and_initial_stake(
+                owner.clone(),
+                "treasury".into_bech32(),
+                0,
+                0,
+            );
+
+        let second_validator: Addr = "second-validator".into_bech32();
+        add_validator(
+            &mut app,
+            owner.clone(),
+            &staker_addr,
+            second_validator.clone(),
+        )
+        .unwrap();
+
+        let user: Addr = "user".into_bech32();
+        mint_inj(&mut app, &user, 100_000);
+        whitelist_user(&mut app, &staker_addr, &owner, &user);
+        stake(&mut app, &user, &staker_addr, 100_000).unwrap();
+
+        redelegate(
+            &mut app,
+            &owner,
+            &staker_addr,
+            &validator_addr,
+            &second_validator,
+            40_000,
+        )
+        .unwrap();
+        unstake(&mut app, &user, &staker_addr, 10_000).unwrap();
+
+        // the ledger matches the delegations on chain
+        let default_delegation = get_delegation(&app, staker_addr.to_string(), &validator_addr);
+        let second_delegation = get_delegation(&app, staker_addr.to_string(), &second_validator);
+        assert_eq!(default_delegation.amount.amount.u128(), 50_000);
+        assert_eq!(second_delegation.amount.amount.u128(), 40_000);
+
+        let mut ledger = get_ledger_delegations(&app, &staker_addr);
+        ledger.sort();
+        assert_eq!(ledger, vec![Uint128::new(40_000), Uint128::new(50_000)]);
+        assert_eq!(get_total_staked(&app, &staker_addr).u128(), 90_000);
+
+        // reconciling reports no drift
+        let response = reconcile_delegations(&mut app, &staker_addr, &owner).unwrap();
+        assert!(!response
+            .events
+            .iter()
+            .any(|e| e.ty == "wasm-delegation_drift"));
+    }
+
+    #[test]
+    fn test_reconcile_delegations_after_slashing() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, validator_addr) =
+            instantiate_staker_with_min_deposit_and_initial_stake(
+                owner.clone(),
+                "treasury".into_bech32(),
+                0,
+                100_000,
+            );
+
+        app.sudo(cw_multi_test::SudoMsg::Staking(StakingSudo::Slash {
+            validator: validator_addr.to_string(),
+            percentage: Decimal::percent(50),
+        }))
+        .unwrap();
+
+        // the ledger is unaware of the slashing until reconciled
+        assert_eq!(get_total_staked(&app, &staker_addr).u128(), 100_000);
+
+        let response = reconcile_delegations(&mut app, &staker_addr, &owner).unwrap();
+
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-delegation_drift",
+            vec![
+                ("validator_addr", validator_addr.to_string()).into(),
+                ("recorded_amount", "100000").into(),
+                ("actual_amount", "50000").into(),
+            ],
+            staker_addr.clone(),
+        );
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-delegations_reconciled",
+            vec![
+                ("old_total_delegated", "100000").into(),
+                ("new_total_delegated", "50000").into(),
+            ],
+            staker_addr.clone(),
+        );
+        assert_eq!(get_total_staked(&app, &staker_addr).u128(), 50_000);
+    }
+
+    #[test]
+    fn test_reconcile_delegations_by_non_keeper_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker_with_min_deposit_and_initial_stake(
+            owner,
+            "treasury".into_bech32(),
+            0,
+            100_000,
+        );
+
+        let response = reconcile_delegations(&mut app, &staker_addr, &"user".into_bech32());
+        assert_error(response, "Caller does not have the keeper role");
+    }
+}
@@ -0,0 +1,312 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod distribute_all {
+
+    use cosmwasm_std::{coin, to_json_binary, Addr, Attribute, Coin, Event, WasmMsg};
+    use cw_multi_test::error::AnyError;
+    use cw_multi_test::{App, AppResponse, Executor, IntoBech32};
+    use injective_staker::{msg::ExecuteMsg, INJ};
+
+    use crate::helpers::{
+        assert_error, blacklist_user, get_distribution_amounts,
+        instantiate_staker_with_min_deposit, mint_inj, move_days_forward, query_inj_balance,
+        query_truinj_balance, set_dist_fee, set_up_test_allocation, whitelist_user,
+    };
+
+    fn distribute_all(
+        app: &mut App,
+        staker_addr: &Addr,
+        distributor: &Addr,
+        in_inj: bool,
+        funds: Vec<Coin>,
+    ) -> Result<AppResponse, AnyError> {
+        distribute_all_page(app, staker_addr, distributor, in_inj, funds, None, None)
+    }
+
+    fn distribute_all_page(
+        app: &mut App,
+        staker_addr: &Addr,
+        distributor: &Addr,
+        in_inj: bool,
+        funds: Vec<Coin>,
+        start_after: Option<&Addr>,
+        limit: Option<u32>,
+    ) -> Result<AppResponse, AnyError> {
+        app.execute(
+            distributor.clone(),
+            WasmMsg::Execute {
+                contract_addr: staker_addr.to_string(),
+                msg: to_json_binary(&ExecuteMsg::DistributeAll {
+                    in_inj,
+                    start_after: start_after.map(Addr::to_string),
+                    limit,
+                })
+                .unwrap(),
+                funds,
+            }
+            .into(),
+        )
+    }
+
+    fn distributed_all_event(response: &AppResponse) -> &Event {
+        response
+            .events
+            .iter()
+            .find(|event| event.ty == "wasm-distributed_all_rewards")
+            .unwrap()
+    }
+
+    /// Sets up allocations from a distributor to two recipients and accrues rewards.
+    fn setup() -> (App, Addr, Addr, Addr, Addr, Addr) {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) =
+            instantiate_staker_with_min_deposit(owner.clone(), "treasury".into_bech32(), 0);
+        set_dist_fee(&mut app, &staker_addr, &owner, 500);
+
+        let distributor: Addr = "distributor".into_bech32();
+        let alice: Addr = "alice".into_bech32();
+        let bob: Addr = "bob".into_bech32();
+        for recipient in [&alice, &bob] {
+            set_up_test_allocation(
+                &mut app,
+                &owner,
+                &staker_addr,
+                &distributor,
+                recipient,
+                100_000,
+            );
+        }
+
+        // accrue rewards
+        move_days_forward(&mut app, 30);
+
+        (app, staker_addr, owner, distributor, alice, bob)
+    }
+
+    #[test]
+    fn test_distribute_all_in_truinj() {
+        let (mut app, staker_addr, _, distributor, alice, bob) = setup();
+
+        let (_, truinj_to_alice, _) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, Some(&alice));
+        let (_, truinj_to_bob, _) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, Some(&bob));
+        let (total_inj, total_truinj, total_fees) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, None);
+        assert_eq!(total_truinj, truinj_to_alice + truinj_to_bob);
+
+        let pre_distributor_balance = query_truinj_balance(&app, &distributor, &staker_addr);
+        let response = distribute_all(&mut app, &staker_addr, &distributor, false, vec![]).unwrap();
+
+        assert_eq!(
+            query_truinj_balance(&app, &alice, &staker_addr),
+            truinj_to_alice
+        );
+        assert_eq!(
+            query_truinj_balance(&app, &bob, &staker_addr),
+            truinj_to_bob
+        );
+        assert_eq!(
+            query_truinj_balance(&app, &distributor, &staker_addr),
+            pre_distributor_balance - total_truinj - total_fees
+        );
+
+        assert_eq!(
+            response
+                .events
+                .iter()
+                .filter(|event| event.ty == "wasm-distributed_rewards")
+                .count(),
+            2
+        );
+        let event = distributed_all_event(&response);
+        assert_eq!(
+            event.attributes[1..],
+            vec![
+                Attribute::new("user", distributor.to_string()),
+                Attribute::new("recipients", "2"),
+                Attribute::new("inj_amount", total_inj.to_string()),
+                Attribute::new("shares", total_truinj.to_string()),
+                Attribute::new("fees", total_fees.to_string()),
+                Attribute::new("in_inj", "false"),
+                Attribute::new("last_recipient", alice.clone().max(bob.clone()).to_string()),
+            ]
+        );
+
+        // nothing is left to distribute
+        let (_, truinj_amount, _) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, None);
+        assert_eq!(truinj_amount, 0);
+    }
+
+    #[test]
+    fn test_distribute_all_in_inj_refunds_unused_inj() {
+        let (mut app, staker_addr, _, distributor, alice, bob) = setup();
+
+        let (inj_to_alice, _, _) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, Some(&alice));
+        let (inj_to_bob, _, _) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, Some(&bob));
+
+        let excess_inj = 1000;
+        mint_inj(
+            &mut app,
+            &distributor,
+            inj_to_alice + inj_to_bob + excess_inj,
+        );
+
+        distribute_all(
+            &mut app,
+            &staker_addr,
+            &distributor,
+            true,
+            vec![coin(inj_to_alice + inj_to_bob + excess_inj, INJ)],
+        )
+        .unwrap();
+
+        assert_eq!(query_inj_balance(&app, &alice), inj_to_alice);
+        assert_eq!(query_inj_balance(&app, &bob), inj_to_bob);
+        assert_eq!(query_inj_balance(&app, &distributor), excess_inj);
+    }
+
+    #[test]
+    fn test_distribute_all_in_inj_with_insufficient_inj_attached_fails() {
+        let (mut app, staker_addr, _, distributor, alice, _) = setup();
+
+        // attach only enough INJ for one of the recipients
+        let (inj_to_alice, _, _) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, Some(&alice));
+        mint_inj(&mut app, &distributor, inj_to_alice);
+
+        let response = distribute_all(
+            &mut app,
+            &staker_addr,
+            &distributor,
+            true,
+            vec![coin(inj_to_alice, INJ)],
+        );
+        assert_error(response, "Insufficient INJ attached");
+    }
+
+    #[test]
+    fn test_distribute_all_skips_allocations_without_rewards() {
+        let (mut app, staker_addr, _, distributor, alice, bob) = setup();
+
+        distribute_all(&mut app, &staker_addr, &distributor, false, vec![]).unwrap();
+
+        // a new allocation is made at the current share price
+        let carol: Addr = "carol".into_bech32();
+        app.execute(
+            distributor.clone(),
+            WasmMsg::Execute {
+                contract_addr: staker_addr.to_string(),
+                msg: to_json_binary(&ExecuteMsg::TestAllocate {
+                    recipient: carol.to_string(),
+                    amount: 10_000u128.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+        )
+        .unwrap();
+
+        let pre_alice_balance = query_truinj_balance(&app, &alice, &staker_addr);
+        let pre_bob_balance = query_truinj_balance(&app, &bob, &staker_addr);
+
+        // no rewards have accrued since, so nothing is distributed
+        let response = distribute_all(&mut app, &staker_addr, &distributor, false, vec![]).unwrap();
+        let event = distributed_all_event(&response);
+        assert_eq!(event.attributes[2], Attribute::new("recipients", "0"));
+
+        assert_eq!(
+            query_truinj_balance(&app, &alice, &staker_addr),
+            pre_alice_balance
+        );
+        assert_eq!(
+            query_truinj_balance(&app, &bob, &staker_addr),
+            pre_bob_balance
+        );
+        assert_eq!(query_truinj_balance(&app, &carol, &staker_addr), 0);
+    }
+
+    #[test]
+    fn test_distribute_all_skips_frozen_recipients() {
+        let (mut app, staker_addr, owner, distributor, alice, bob) = setup();
+        blacklist_user(&mut app, &staker_addr, &owner, &bob);
+
+        let (_, truinj_to_alice, _) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, Some(&alice));
+        distribute_all(&mut app, &staker_addr, &distributor, false, vec![]).unwrap();
+
+        assert_eq!(
+            query_truinj_balance(&app, &alice, &staker_addr),
+            truinj_to_alice
+        );
+        assert_eq!(query_truinj_balance(&app, &bob, &staker_addr), 0);
+    }
+
+    #[test]
+    fn test_distribute_all_without_allocations_fails() {
+        let (mut app, staker_addr, owner, _, alice, _) = setup();
+        whitelist_user(&mut app, &staker_addr, &owner, &alice);
+
+        let response = distribute_all(&mut app, &staker_addr, &alice, false, vec![]);
+        assert_error(response, "No allocations");
+    }
+
+    #[test]
+    fn test_distribute_all_is_paginated() {
+        let (mut app, staker_addr, _, distributor, alice, bob) = setup();
+        let (first, second) = if alice < bob {
+            (alice, bob)
+        } else {
+            (bob, alice)
+        };
+
+        let (_, truinj_to_first, _) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, Some(&first));
+        let (_, truinj_to_second, _) =
+            get_distribution_amounts(&app, &staker_addr, &distributor, Some(&second));
+
+        // the first page only distributes to the first recipient
+        let response = distribute_all_page(
+            &mut app,
+            &staker_addr,
+            &distributor,
+            false,
+            vec![],
+            None,
+            Some(1),
+        )
+        .unwrap();
+        let event = distributed_all_event(&response);
+        assert_eq!(event.attributes[2], Attribute::new("recipients", "1"));
+        assert_eq!(
+            event.attributes.last().unwrap(),
+            Attribute::new("last_recipient", first.to_string())
+        );
+        assert_eq!(
+            query_truinj_balance(&app, &first, &staker_addr),
+            truinj_to_first
+        );
+        assert_eq!(query_truinj_balance(&app, &second, &staker_addr), 0);
+
+        // the next page continues after the last recipient
+        distribute_all_page(
+            &mut app,
+            &staker_addr,
+            &distributor,
+            false,
+            vec![],
+            Some(&first),
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!(
+            query_truinj_balance(&app, &second, &staker_addr),
+            truinj_to_second
+        );
+    }
+}
@@ -8,11 +8,11 @@ mod distribute_rewards {
     use injective_staker::{msg::ExecuteMsg, INJ};
 
     use crate::helpers::{
-        assert_error, assert_event_with_attributes, clear_whitelist_status,
+        assert_error, assert_event_with_attributes, clear_whitelist_status, fee_recipient_balances,
         get_distribution_amounts, get_share_price_num_denom, get_total_allocated,
         instantiate_staker_with_min_deposit, mint_inj, move_days_forward, pause, query_inj_balance,
-        query_truinj_balance, set_dist_fee, set_up_test_allocation, transfer_truinj,
-        whitelist_user,
+        query_truinj_balance, reconcile_delegations, set_dist_fee, set_up_test_allocation,
+        transfer_truinj, whitelist_user,
     };
 
     #[test]
@@ -102,7 +102,11 @@ mod distribute_rewards {
                 ("recipient", recipient.clone()).into(),
                 ("user_balance", distributor_truinj_balance.to_string()).into(),
                 ("recipient_balance", recipient_truinj_balance.to_string()).into(),
-                ("treasury_balance", treasury_truinj_balance.to_string()).into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&treasury, treasury_truinj_balance)]),
+                )
+                    .into(),
                 ("fees", fees.to_string()).into(),
                 ("shares", truinj_to_distribute.to_string()).into(),
                 ("inj_amount", inj_to_distribute.to_string()).into(),
@@ -190,6 +194,7 @@ mod distribute_rewards {
             percentage: Decimal::percent(50),
         }))
         .unwrap();
+        reconcile_delegations(&mut app, &staker_addr, &owner).unwrap();
 
         // distribute rewards to recipient in trunj
         let distributor_pre_truinj_balance = query_truinj_balance(&app, &distributor, &staker_addr);
@@ -650,7 +655,11 @@ mod distribute_rewards {
                 ("recipient", recipient.clone()).into(),
                 ("user_balance", distributor_truinj_balance.to_string()).into(),
                 ("recipient_balance", recipient_truinj_balance.to_string()).into(),
-                ("treasury_balance", treasury_truinj_balance.to_string()).into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&treasury, treasury_truinj_balance)]),
+                )
+                    .into(),
                 ("fees", fees.to_string()).into(),
                 ("shares", truinj_to_distribute.to_string()).into(),
                 ("inj_amount", inj_to_distribute.to_string()).into(),
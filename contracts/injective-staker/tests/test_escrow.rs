@@ -0,0 +1,348 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod escrow {
+
+    use cosmwasm_std::{Addr, Uint128};
+    use cw_multi_test::error::AnyError;
+    use cw_multi_test::{App, AppResponse, Executor, IntoBech32};
+    use injective_staker::{
+        msg::{
+            ExecuteMsg, GetEscrowedAllocationResponse, GetEscrowedAllocationsToRecipientResponse,
+            QueryMsg,
+        },
+        ONE_INJ,
+    };
+
+    use crate::helpers::{
+        assert_error, blacklist_user, instantiate_staker_with_min_deposit, mint_inj,
+        query_truinj_balance, raise_share_price, set_dist_fee, stake, wasm_execute_msg,
+        whitelist_user,
+    };
+
+    fn allocate_escrowed(
+        app: &mut App,
+        staker_addr: &Addr,
+        allocator: &Addr,
+        recipient: &Addr,
+        shares: u128,
+    ) -> Result<AppResponse, AnyError> {
+        app.execute(
+            allocator.clone(),
+            wasm_execute_msg(
+                staker_addr,
+                &ExecuteMsg::AllocateEscrowed {
+                    recipient: recipient.to_string(),
+                    shares: shares.into(),
+                },
+            )
+            .into(),
+        )
+    }
+
+    fn deallocate_escrowed(
+        app: &mut App,
+        staker_addr: &Addr,
+        allocator: &Addr,
+        recipient: &Addr,
+        amount: u128,
+    ) -> Result<AppResponse, AnyError> {
+        app.execute(
+            allocator.clone(),
+            wasm_execute_msg(
+                staker_addr,
+                &ExecuteMsg::DeallocateEscrowed {
+                    recipient: recipient.to_string(),
+                    amount: amount.into(),
+                },
+            )
+            .into(),
+        )
+    }
+
+    fn claim_escrowed_rewards(
+        app: &mut App,
+        staker_addr: &Addr,
+        recipient: &Addr,
+        allocator: &Addr,
+    ) -> Result<AppResponse, AnyError> {
+        app.execute(
+            recipient.clone(),
+            wasm_execute_msg(
+                staker_addr,
+                &ExecuteMsg::ClaimEscrowedRewards {
+                    allocator: allocator.to_string(),
+                },
+            )
+            .into(),
+        )
+    }
+
+    fn get_escrowed_allocation(
+        app: &App,
+        staker_addr: &Addr,
+        allocator: &Addr,
+        recipient: &Addr,
+    ) -> GetEscrowedAllocationResponse {
+        app.wrap()
+            .query_wasm_smart(
+                staker_addr,
+                &QueryMsg::GetEscrowedAllocation {
+                    allocator: allocator.to_string(),
+                    recipient: recipient.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    fn get_escrowed_allocations_to_recipient(
+        app: &App,
+        staker_addr: &Addr,
+        recipient: &Addr,
+        start_after: Option<&Addr>,
+        limit: Option<u32>,
+    ) -> GetEscrowedAllocationsToRecipientResponse {
+        app.wrap()
+            .query_wasm_smart(
+                staker_addr,
+                &QueryMsg::GetEscrowedAllocationsToRecipient {
+                    recipient: recipient.to_string(),
+                    start_after: start_after.map(Addr::to_string),
+                    limit,
+                },
+            )
+            .unwrap()
+    }
+
+    /// Instantiates the staker with an allocator who has locked 10 TruINJ in escrow for a recipient.
+    fn setup() -> (App, Addr, Addr, Addr, Addr) {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) =
+            instantiate_staker_with_min_deposit(owner.clone(), "treasury".into_bech32(), 0);
+
+        let allocator: Addr = "allocator".into_bech32();
+        let recipient: Addr = "recipient".into_bech32();
+        whitelist_user(&mut app, &staker_addr, &owner, &allocator);
+        mint_inj(&mut app, &allocator, 20 * ONE_INJ);
+        stake(&mut app, &allocator, &staker_addr, 20 * ONE_INJ).unwrap();
+
+        allocate_escrowed(&mut app, &staker_addr, &allocator, &recipient, 10 * ONE_INJ).unwrap();
+
+        (app, staker_addr, owner, allocator, recipient)
+    }
+
+    #[test]
+    fn test_allocate_escrowed_locks_truinj() {
+        let (app, staker_addr, _, allocator, recipient) = setup();
+
+        assert_eq!(
+            query_truinj_balance(&app, &allocator, &staker_addr),
+            10 * ONE_INJ
+        );
+        assert_eq!(
+            query_truinj_balance(&app, &staker_addr, &staker_addr),
+            10 * ONE_INJ
+        );
+
+        let response = get_escrowed_allocation(&app, &staker_addr, &allocator, &recipient);
+        let allocation = response.allocation.unwrap();
+        assert_eq!(allocation.shares, Uint128::new(10 * ONE_INJ));
+        assert_eq!(allocation.inj_amount, Uint128::new(10 * ONE_INJ));
+        assert_eq!(response.claimable_shares, Uint128::zero());
+    }
+
+    #[test]
+    fn test_allocate_escrowed_under_one_inj_fails() {
+        let (mut app, staker_addr, _, allocator, _) = setup();
+
+        let response = allocate_escrowed(
+            &mut app,
+            &staker_addr,
+            &allocator,
+            &"other".into_bech32(),
+            ONE_INJ - 1,
+        );
+        assert_error(response, "Cannot allocate under 1 INJ");
+    }
+
+    #[test]
+    fn test_allocate_escrowed_to_self_fails() {
+        let (mut app, staker_addr, _, allocator, _) = setup();
+
+        let response = allocate_escrowed(&mut app, &staker_addr, &allocator, &allocator, ONE_INJ);
+        assert_error(response, "Cannot allocate to self");
+    }
+
+    #[test]
+    fn test_claim_escrowed_rewards() {
+        let (mut app, staker_addr, owner, allocator, recipient) = setup();
+        set_dist_fee(&mut app, &staker_addr, &owner, 500);
+        raise_share_price(&mut app, &staker_addr, &owner);
+
+        let response = get_escrowed_allocation(&app, &staker_addr, &allocator, &recipient);
+        let claimable_shares = response.claimable_shares.u128();
+        assert!(claimable_shares > 0);
+
+        let pre_treasury_balance =
+            query_truinj_balance(&app, &"treasury".into_bech32(), &staker_addr);
+        claim_escrowed_rewards(&mut app, &staker_addr, &recipient, &allocator).unwrap();
+
+        assert_eq!(
+            query_truinj_balance(&app, &recipient, &staker_addr),
+            claimable_shares
+        );
+        assert!(
+            query_truinj_balance(&app, &"treasury".into_bech32(), &staker_addr)
+                > pre_treasury_balance
+        );
+
+        // the principal keeps its INJ value and nothing is left to claim
+        let response = get_escrowed_allocation(&app, &staker_addr, &allocator, &recipient);
+        assert_eq!(
+            response.allocation.unwrap().inj_amount,
+            Uint128::new(10 * ONE_INJ)
+        );
+        assert_eq!(response.claimable_shares, Uint128::zero());
+
+        let response = claim_escrowed_rewards(&mut app, &staker_addr, &recipient, &allocator);
+        assert_error(response, "No escrowed rewards to claim");
+    }
+
+    #[test]
+    fn test_claim_escrowed_rewards_by_frozen_recipient_fails() {
+        let (mut app, staker_addr, owner, allocator, recipient) = setup();
+        raise_share_price(&mut app, &staker_addr, &owner);
+        blacklist_user(&mut app, &staker_addr, &owner, &recipient);
+
+        let response = claim_escrowed_rewards(&mut app, &staker_addr, &recipient, &allocator);
+        assert_error(response, "Account is frozen");
+    }
+
+    #[test]
+    fn test_deallocate_escrowed_keeps_owed_rewards() {
+        let (mut app, staker_addr, owner, allocator, recipient) = setup();
+        raise_share_price(&mut app, &staker_addr, &owner);
+
+        let claimable_shares =
+            get_escrowed_allocation(&app, &staker_addr, &allocator, &recipient).claimable_shares;
+
+        // the allocator unlocks the whole principal
+        deallocate_escrowed(&mut app, &staker_addr, &allocator, &recipient, 10 * ONE_INJ).unwrap();
+        let returned_shares = query_truinj_balance(&app, &allocator, &staker_addr) - 10 * ONE_INJ;
+        assert!(returned_shares < 10 * ONE_INJ);
+
+        // the rewards stay in escrow for the recipient
+        let response = get_escrowed_allocation(&app, &staker_addr, &allocator, &recipient);
+        let allocation = response.allocation.unwrap();
+        assert_eq!(allocation.inj_amount, Uint128::zero());
+        assert_eq!(allocation.shares.u128(), 10 * ONE_INJ - returned_shares);
+        assert_eq!(response.claimable_shares, claimable_shares);
+
+        // claiming the rewards closes the escrowed allocation
+        claim_escrowed_rewards(&mut app, &staker_addr, &recipient, &allocator).unwrap();
+        let response = get_escrowed_allocation(&app, &staker_addr, &allocator, &recipient);
+        assert_eq!(response.allocation, None);
+        assert_eq!(query_truinj_balance(&app, &staker_addr, &staker_addr), 0);
+    }
+
+    #[test]
+    fn test_deallocate_escrowed_partially() {
+        let (mut app, staker_addr, _, allocator, recipient) = setup();
+
+        deallocate_escrowed(&mut app, &staker_addr, &allocator, &recipient, 4 * ONE_INJ).unwrap();
+        assert_eq!(
+            query_truinj_balance(&app, &allocator, &staker_addr),
+            14 * ONE_INJ
+        );
+
+        let allocation = get_escrowed_allocation(&app, &staker_addr, &allocator, &recipient)
+            .allocation
+            .unwrap();
+        assert_eq!(allocation.inj_amount, Uint128::new(6 * ONE_INJ));
+
+        let response = deallocate_escrowed(
+            &mut app,
+            &staker_addr,
+            &allocator,
+            &recipient,
+            6 * ONE_INJ - 1,
+        );
+        assert_error(response, "Cannot allocate under 1 INJ");
+
+        let response = deallocate_escrowed(
+            &mut app,
+            &staker_addr,
+            &allocator,
+            &recipient,
+            6 * ONE_INJ + 1,
+        );
+        assert_error(response, "Cannot deallocate more than is allocated");
+    }
+
+    #[test]
+    fn test_deallocate_escrowed_without_allocation_fails() {
+        let (mut app, staker_addr, _, allocator, _) = setup();
+
+        let response = deallocate_escrowed(
+            &mut app,
+            &staker_addr,
+            &allocator,
+            &"other".into_bech32(),
+            ONE_INJ,
+        );
+        assert_error(response, "No escrowed allocation to recipient");
+    }
+
+    #[test]
+    fn test_get_escrowed_allocations_to_recipient() {
+        let (mut app, staker_addr, owner, allocator, recipient) = setup();
+
+        // a second allocator escrows TruINJ for the same recipient
+        let other: Addr = "other".into_bech32();
+        whitelist_user(&mut app, &staker_addr, &owner, &other);
+        mint_inj(&mut app, &other, 2 * ONE_INJ);
+        stake(&mut app, &other, &staker_addr, 2 * ONE_INJ).unwrap();
+        allocate_escrowed(&mut app, &staker_addr, &other, &recipient, 2 * ONE_INJ).unwrap();
+
+        raise_share_price(&mut app, &staker_addr, &owner);
+
+        let all = get_escrowed_allocations_to_recipient(&app, &staker_addr, &recipient, None, None)
+            .escrowed_allocations;
+        assert_eq!(all.len(), 2);
+        for escrowed in &all {
+            assert_eq!(escrowed.allocation.recipient, recipient);
+            let response = get_escrowed_allocation(
+                &app,
+                &staker_addr,
+                &escrowed.allocation.allocator,
+                &recipient,
+            );
+            assert_eq!(Some(&escrowed.allocation), response.allocation.as_ref());
+            assert_eq!(escrowed.claimable_shares, response.claimable_shares);
+            assert!(!escrowed.claimable_shares.is_zero());
+        }
+        let mut allocators: Vec<Addr> = all
+            .iter()
+            .map(|escrowed| escrowed.allocation.allocator.clone())
+            .collect();
+        allocators.sort();
+        let mut expected = vec![allocator, other];
+        expected.sort();
+        assert_eq!(allocators, expected);
+
+        // the allocations are paginated by allocator
+        let first_page =
+            get_escrowed_allocations_to_recipient(&app, &staker_addr, &recipient, None, Some(1))
+                .escrowed_allocations;
+        assert_eq!(first_page, all[..1]);
+        let second_page = get_escrowed_allocations_to_recipient(
+            &app,
+            &staker_addr,
+            &recipient,
+            Some(&first_page[0].allocation.allocator),
+            Some(1),
+        )
+        .escrowed_allocations;
+        assert_eq!(second_page, all[1..]);
+    }
+}
@@ -8,7 +8,7 @@ mod staker_init {
     use helpers::{contract_wrapper, instantiate_staker, mock_app_with_validator};
     use injective_staker::{
         msg::{GetStakerInfoResponse, GetValidatorResponse, InstantiateMsg, QueryMsg},
-        state::{ValidatorInfo, ValidatorState},
+        state::{FeeRecipient, PauseStatus, ValidatorInfo, ValidatorState},
         INJ, ONE_INJ,
     };
     use injective_test_tube::{Account, InjectiveTestApp, Module, Wasm};
@@ -33,11 +33,16 @@ mod staker_init {
             GetStakerInfoResponse {
                 default_validator: default_validator.to_string(),
                 owner: owner.to_string(),
-                treasury: treasury.to_string(),
+                fee_recipients: vec![FeeRecipient {
+                    address: treasury,
+                    bps: 10_000,
+                }],
                 fee: 0,
                 distribution_fee: 0,
+                deposit_fee: 0,
+                withdrawal_fee: 0,
                 min_deposit: ONE_INJ.into(),
-                is_paused: false,
+                pause_status: PauseStatus::Unpaused,
             }
         );
 
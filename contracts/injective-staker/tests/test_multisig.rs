@@ -0,0 +1,397 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod multisig {
+    use cosmwasm_std::{Addr, Attribute};
+    use cw_multi_test::{App, Executor, IntoBech32};
+    use injective_staker::{
+        msg::{ExecuteMsg, GetMultisigConfigResponse, QueryMsg},
+        state::{PauseStatus, ProposalStatus},
+    };
+
+    use crate::helpers::{
+        approve_proposal, assert_error, execute_proposal, get_proposal, get_staker_info,
+        instantiate_staker, list_proposals, move_days_forward, pause, propose, set_multisig_config,
+        soft_pause, wasm_execute_msg,
+    };
+
+    const ONE_DAY: u64 = 24 * 60 * 60;
+
+    /// Enables a 2-of-3 multisig and returns the signers.
+    fn enable_multisig(app: &mut App, staker_addr: &Addr, owner: &Addr) -> Vec<Addr> {
+        let signers: Vec<Addr> = vec![
+            "alice".into_bech32(),
+            "bob".into_bech32(),
+            "carol".into_bech32(),
+        ];
+        set_multisig_config(
+            app,
+            staker_addr,
+            owner,
+            &signers.iter().collect::<Vec<_>>(),
+            2,
+            ONE_DAY,
+        )
+        .unwrap();
+        signers
+    }
+
+    #[test]
+    fn test_set_multisig_config() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let signers = enable_multisig(&mut app, &staker_addr, &owner);
+
+        let config: GetMultisigConfigResponse = app
+            .wrap()
+            .query_wasm_smart(&staker_addr, &QueryMsg::GetMultisigConfig {})
+            .unwrap();
+        assert_eq!(
+            config,
+            GetMultisigConfigResponse {
+                signers,
+                threshold: 2,
+                proposal_duration: ONE_DAY,
+            }
+        );
+    }
+
+    #[test]
+    fn test_set_multisig_config_with_invalid_threshold_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        let alice: Addr = "alice".into_bech32();
+
+        let response = set_multisig_config(&mut app, &staker_addr, &owner, &[&alice], 2, ONE_DAY);
+        assert_error(
+            response,
+            "Multisig threshold must be between 1 and the number of signers",
+        );
+
+        let response = set_multisig_config(&mut app, &staker_addr, &owner, &[&alice], 0, ONE_DAY);
+        assert_error(
+            response,
+            "Multisig threshold must be between 1 and the number of signers",
+        );
+    }
+
+    #[test]
+    fn test_set_multisig_config_with_zero_proposal_duration_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        let alice: Addr = "alice".into_bech32();
+
+        let response = set_multisig_config(&mut app, &staker_addr, &owner, &[&alice], 1, 0);
+        assert_error(
+            response,
+            "Multisig proposal duration must be greater than zero",
+        );
+    }
+
+    #[test]
+    fn test_set_multisig_config_with_duplicate_signers_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        let alice: Addr = "alice".into_bech32();
+
+        let response = set_multisig_config(
+            &mut app,
+            &staker_addr,
+            &owner,
+            &[&alice, &alice],
+            1,
+            ONE_DAY,
+        );
+        assert_error(response, "Duplicate multisig signer");
+    }
+
+    #[test]
+    fn test_guarded_action_requires_proposal_when_multisig_enabled() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        enable_multisig(&mut app, &staker_addr, &owner);
+
+        let response = app.execute(
+            owner.clone(),
+            wasm_execute_msg(&staker_addr, &ExecuteMsg::SetFee { new_fee: 1000 }).into(),
+        );
+        assert_error(response, "This action requires a multisig proposal");
+
+        // the multisig config itself can no longer be changed directly
+        let response = set_multisig_config(&mut app, &staker_addr, &owner, &[], 0, ONE_DAY);
+        assert_error(response, "This action requires a multisig proposal");
+    }
+
+    #[test]
+    fn test_renounce_ownership_with_multisig_enabled_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        let signers = enable_multisig(&mut app, &staker_addr, &owner);
+
+        propose(
+            &mut app,
+            &staker_addr,
+            &signers[0],
+            ExecuteMsg::RenounceOwnership {
+                confirm: staker_addr.to_string(),
+            },
+        )
+        .unwrap();
+        approve_proposal(&mut app, &staker_addr, &signers[1], 1).unwrap();
+        let response = execute_proposal(&mut app, &staker_addr, &owner, 1);
+        assert_error(
+            response,
+            "Ownership cannot be renounced while the multisig is enabled",
+        );
+        assert_eq!(get_staker_info(&app, &staker_addr).owner, owner.to_string());
+
+        // once the multisig is disabled, ownership can be renounced
+        propose(
+            &mut app,
+            &staker_addr,
+            &signers[0],
+            ExecuteMsg::SetMultisigConfig {
+                signers: vec![],
+                threshold: 0,
+                proposal_duration: ONE_DAY,
+            },
+        )
+        .unwrap();
+        approve_proposal(&mut app, &staker_addr, &signers[1], 2).unwrap();
+        execute_proposal(&mut app, &staker_addr, &owner, 2).unwrap();
+
+        let response = app.execute(
+            owner.clone(),
+            wasm_execute_msg(
+                &staker_addr,
+                &ExecuteMsg::RenounceOwnership {
+                    confirm: staker_addr.to_string(),
+                },
+            )
+            .into(),
+        );
+        assert!(response.is_ok());
+        assert_eq!(get_staker_info(&app, &staker_addr).owner, "");
+    }
+
+    #[test]
+    fn test_relaxing_hard_pause_requires_proposal_when_multisig_enabled() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        let signers = enable_multisig(&mut app, &staker_addr, &owner);
+
+        // soft pausing an unpaused contract does not require a proposal
+        soft_pause(&mut app, &staker_addr, &owner);
+
+        pause(&mut app, &staker_addr, &owner);
+        let response = app.execute(
+            owner.clone(),
+            wasm_execute_msg(&staker_addr, &ExecuteMsg::SoftPause).into(),
+        );
+        assert_error(response, "This action requires a multisig proposal");
+
+        propose(&mut app, &staker_addr, &signers[0], ExecuteMsg::SoftPause).unwrap();
+        approve_proposal(&mut app, &staker_addr, &signers[1], 1).unwrap();
+        execute_proposal(&mut app, &staker_addr, &owner, 1).unwrap();
+        assert_eq!(
+            get_staker_info(&app, &staker_addr).pause_status,
+            PauseStatus::SoftPaused
+        );
+    }
+
+    #[test]
+    fn test_propose_and_execute_with_threshold() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        let signers = enable_multisig(&mut app, &staker_addr, &owner);
+
+        let now = app.block_info().time.seconds();
+        let response = propose(
+            &mut app,
+            &staker_addr,
+            &signers[0],
+            ExecuteMsg::SetFee { new_fee: 1000 },
+        )
+        .unwrap();
+
+        let event = response.events.last().unwrap();
+        assert_eq!(event.ty, "wasm-proposal_created");
+        assert_eq!(
+            event.attributes[1..],
+            vec![
+                Attribute::new("id", "1"),
+                Attribute::new("proposer", signers[0].to_string()),
+                Attribute::new("msg", r#"{"set_fee":{"new_fee":1000}}"#),
+                Attribute::new("expires_at", (now + ONE_DAY).to_string()),
+            ]
+        );
+
+        // the proposer's approval alone does not reach the threshold
+        let anyone: Addr = "anyone".into_bech32();
+        let response = execute_proposal(&mut app, &staker_addr, &anyone, 1);
+        assert_error(response, "Proposal approval threshold not reached");
+
+        approve_proposal(&mut app, &staker_addr, &signers[1], 1).unwrap();
+
+        // anyone can execute the proposal once the threshold is reached
+        let response = execute_proposal(&mut app, &staker_addr, &anyone, 1).unwrap();
+        assert!(response.events.iter().any(|e| e.ty == "wasm-set_fee"));
+        let event = response.events.last().unwrap();
+        assert_eq!(event.ty, "wasm-proposal_executed");
+        assert_eq!(event.attributes[1], Attribute::new("id", "1"));
+
+        assert_eq!(get_staker_info(&app, &staker_addr).fee, 1000);
+
+        let proposal = get_proposal(&app, &staker_addr, 1);
+        assert_eq!(proposal.status, ProposalStatus::Executed);
+        assert_eq!(proposal.approvals, signers[0..2].to_vec());
+
+        // an executed proposal cannot be executed again
+        let response = execute_proposal(&mut app, &staker_addr, &anyone, 1);
+        assert_error(response, "Proposal has already been executed");
+    }
+
+    #[test]
+    fn test_propose_by_non_signer_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        enable_multisig(&mut app, &staker_addr, &owner);
+
+        let response = propose(
+            &mut app,
+            &staker_addr,
+            &owner,
+            ExecuteMsg::SetFee { new_fee: 1000 },
+        );
+        assert_error(response, "Caller is not a multisig signer");
+    }
+
+    #[test]
+    fn test_propose_multisig_message_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        let signers = enable_multisig(&mut app, &staker_addr, &owner);
+
+        let response = propose(
+            &mut app,
+            &staker_addr,
+            &signers[0],
+            ExecuteMsg::ExecuteProposal { id: 1 },
+        );
+        assert_error(response, "This message cannot be proposed");
+    }
+
+    #[test]
+    fn test_approve_proposal_twice_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        let signers = enable_multisig(&mut app, &staker_addr, &owner);
+
+        propose(
+            &mut app,
+            &staker_addr,
+            &signers[0],
+            ExecuteMsg::SetFee { new_fee: 1000 },
+        )
+        .unwrap();
+
+        let response = approve_proposal(&mut app, &staker_addr, &signers[0], 1);
+        assert_error(response, "Proposal already approved by signer");
+    }
+
+    #[test]
+    fn test_expired_proposal_cannot_be_approved_or_executed() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        let signers = enable_multisig(&mut app, &staker_addr, &owner);
+
+        propose(
+            &mut app,
+            &staker_addr,
+            &signers[0],
+            ExecuteMsg::SetFee { new_fee: 1000 },
+        )
+        .unwrap();
+
+        move_days_forward(&mut app, 1);
+
+        let response = approve_proposal(&mut app, &staker_addr, &signers[1], 1);
+        assert_error(response, "Proposal has expired");
+
+        let response = execute_proposal(&mut app, &staker_addr, &signers[1], 1);
+        assert_error(response, "Proposal has expired");
+    }
+
+    #[test]
+    fn test_approvals_from_removed_signers_do_not_count() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        let signers = enable_multisig(&mut app, &staker_addr, &owner);
+
+        propose(
+            &mut app,
+            &staker_addr,
+            &signers[0],
+            ExecuteMsg::SetFee { new_fee: 1000 },
+        )
+        .unwrap();
+
+        // replace alice with dave through a second proposal
+        let dave: Addr = "dave".into_bech32();
+        propose(
+            &mut app,
+            &staker_addr,
+            &signers[1],
+            ExecuteMsg::SetMultisigConfig {
+                signers: vec![
+                    signers[1].to_string(),
+                    signers[2].to_string(),
+                    dave.to_string(),
+                ],
+                threshold: 2,
+                proposal_duration: ONE_DAY,
+            },
+        )
+        .unwrap();
+        approve_proposal(&mut app, &staker_addr, &signers[2], 2).unwrap();
+        execute_proposal(&mut app, &staker_addr, &owner, 2).unwrap();
+
+        // alice's approval of the first proposal no longer counts
+        approve_proposal(&mut app, &staker_addr, &signers[1], 1).unwrap();
+        let response = execute_proposal(&mut app, &staker_addr, &owner, 1);
+        assert_error(response, "Proposal approval threshold not reached");
+
+        approve_proposal(&mut app, &staker_addr, &dave, 1).unwrap();
+        execute_proposal(&mut app, &staker_addr, &owner, 1).unwrap();
+        assert_eq!(get_staker_info(&app, &staker_addr).fee, 1000);
+    }
+
+    #[test]
+    fn test_list_proposals() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        let signers = enable_multisig(&mut app, &staker_addr, &owner);
+
+        for new_fee in [100, 200, 300] {
+            propose(
+                &mut app,
+                &staker_addr,
+                &signers[0],
+                ExecuteMsg::SetFee { new_fee },
+            )
+            .unwrap();
+        }
+
+        let proposals = list_proposals(&app, &staker_addr, None, None);
+        assert_eq!(
+            proposals.iter().map(|p| p.id).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+
+        let proposals = list_proposals(&app, &staker_addr, Some(1), Some(1));
+        assert_eq!(proposals.len(), 1);
+        assert_eq!(proposals[0].id, 2);
+        assert_eq!(proposals[0].msg, ExecuteMsg::SetFee { new_fee: 200 });
+    }
+}
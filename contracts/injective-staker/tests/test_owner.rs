@@ -4,9 +4,15 @@ pub mod helpers;
 mod ownership {
     use cosmwasm_std::{Addr, Attribute};
     use cw_multi_test::{Executor, IntoBech32};
-    use injective_staker::msg::{ExecuteMsg, GetIsOwnerResponse, QueryMsg};
+    use injective_staker::msg::{
+        ExecuteMsg, GetIsOwnerResponse, GetPendingOwnerResponse, QueryMsg,
+    };
 
-    use crate::helpers::{assert_error, instantiate_staker, wasm_execute_msg};
+    use crate::helpers::{
+        assert_error, get_staker_info, instantiate_staker, move_days_forward, wasm_execute_msg,
+    };
+
+    const SEVEN_DAYS: u64 = 7 * 24 * 60 * 60;
 
     #[test]
     fn test_set_pending_owner() {
@@ -178,4 +184,156 @@ mod ownership {
 
         assert_error(response, "Only the pending owner can call this method");
     }
+
+    #[test]
+    fn test_claim_ownership_after_expiry_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let new_owner: Addr = "new_owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        // set pending owner
+        let msg = ExecuteMsg::SetPendingOwner {
+            new_owner: new_owner.to_string(),
+        };
+        let now = app.block_info().time.seconds();
+        app.execute(owner, wasm_execute_msg(&staker_addr, &msg).into())
+            .unwrap();
+
+        let pending_owner: GetPendingOwnerResponse = app
+            .wrap()
+            .query_wasm_smart(&staker_addr, &QueryMsg::GetPendingOwner {})
+            .unwrap();
+        assert_eq!(
+            pending_owner,
+            GetPendingOwnerResponse {
+                pending_owner: Some(new_owner.clone()),
+                expires_at: Some(now + SEVEN_DAYS),
+            }
+        );
+
+        move_days_forward(&mut app, 7);
+
+        // claim ownership
+        let msg = ExecuteMsg::ClaimOwnership {};
+        let response = app.execute(new_owner, wasm_execute_msg(&staker_addr, &msg).into());
+
+        assert_error(response, "The pending owner has expired");
+    }
+
+    #[test]
+    fn test_cancel_pending_owner() {
+        let owner: Addr = "owner".into_bech32();
+        let new_owner: Addr = "new_owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        // set pending owner
+        let msg = ExecuteMsg::SetPendingOwner {
+            new_owner: new_owner.to_string(),
+        };
+        app.execute(owner.clone(), wasm_execute_msg(&staker_addr, &msg).into())
+            .unwrap();
+
+        // cancel pending owner
+        let msg = ExecuteMsg::CancelPendingOwner {};
+        let response = app
+            .execute(owner.clone(), wasm_execute_msg(&staker_addr, &msg).into())
+            .unwrap();
+
+        let event = response.events.last().unwrap();
+        assert_eq!("wasm-cancelled_pending_owner", event.ty);
+        assert_eq!(
+            event.attributes[1..],
+            vec![
+                Attribute::new("current_owner", owner.to_string()),
+                Attribute::new("pending_owner", new_owner.to_string()),
+            ]
+        );
+
+        let pending_owner: GetPendingOwnerResponse = app
+            .wrap()
+            .query_wasm_smart(&staker_addr, &QueryMsg::GetPendingOwner {})
+            .unwrap();
+        assert_eq!(pending_owner.pending_owner, None);
+
+        // the cancelled pending owner can no longer claim ownership
+        let msg = ExecuteMsg::ClaimOwnership {};
+        let response = app.execute(new_owner, wasm_execute_msg(&staker_addr, &msg).into());
+        assert_error(response, "There is no pending owner set");
+
+        // cancelling again fails
+        let msg = ExecuteMsg::CancelPendingOwner {};
+        let response = app.execute(owner, wasm_execute_msg(&staker_addr, &msg).into());
+        assert_error(response, "There is no pending owner set");
+    }
+
+    #[test]
+    fn test_cancel_pending_owner_called_by_non_owner_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let new_owner: Addr = "new_owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let msg = ExecuteMsg::SetPendingOwner {
+            new_owner: new_owner.to_string(),
+        };
+        app.execute(owner, wasm_execute_msg(&staker_addr, &msg).into())
+            .unwrap();
+
+        let msg = ExecuteMsg::CancelPendingOwner {};
+        let response = app.execute(new_owner, wasm_execute_msg(&staker_addr, &msg).into());
+        assert_error(response, "Only the owner can call this method");
+    }
+
+    #[test]
+    fn test_renounce_ownership() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let msg = ExecuteMsg::RenounceOwnership {
+            confirm: staker_addr.to_string(),
+        };
+        let response = app
+            .execute(owner.clone(), wasm_execute_msg(&staker_addr, &msg).into())
+            .unwrap();
+
+        let event = response.events.last().unwrap();
+        assert_eq!("wasm-renounced_ownership", event.ty);
+        assert_eq!(
+            event.attributes[1],
+            Attribute::new("old_owner", owner.to_string())
+        );
+
+        let is_owner_response: GetIsOwnerResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staker_addr,
+                &QueryMsg::IsOwner {
+                    addr: owner.to_string(),
+                },
+            )
+            .unwrap();
+        assert!(!is_owner_response.is_owner);
+        assert_eq!(get_staker_info(&app, &staker_addr).owner, "");
+
+        // the former owner can no longer call owner only methods
+        let msg = ExecuteMsg::SetPendingOwner {
+            new_owner: owner.to_string(),
+        };
+        let response = app.execute(owner, wasm_execute_msg(&staker_addr, &msg).into());
+        assert_error(response, "Only the owner can call this method");
+    }
+
+    #[test]
+    fn test_renounce_ownership_without_confirmation_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let msg = ExecuteMsg::RenounceOwnership {
+            confirm: "yes".to_string(),
+        };
+        let response = app.execute(owner, wasm_execute_msg(&staker_addr, &msg).into());
+        assert_error(
+            response,
+            "Renouncing ownership must be confirmed with the contract address",
+        );
+    }
 }
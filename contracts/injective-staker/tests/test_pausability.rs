@@ -3,12 +3,16 @@ pub mod helpers;
 #[cfg(test)]
 mod pausability {
     use crate::helpers::{
-        self, assert_error, assert_event_with_attributes, pause, query_staker_info,
-        wasm_execute_msg,
+        self, assert_error, assert_event_with_attributes, claim, get_claimable_assets,
+        get_pause_matrix, mint_inj, move_days_forward, pause, pause_operations, query_inj_balance,
+        query_staker_info, redelegate, soft_pause, stake, stake_to_specific_validator,
+        unpause_operations, unstake, wasm_execute_msg, whitelist_user,
     };
+    use cosmwasm_std::{to_json_binary, Addr, WasmMsg};
     use cw_multi_test::{Executor, IntoBech32};
-    use helpers::instantiate_staker;
+    use helpers::{instantiate_staker, instantiate_staker_with_min_deposit};
     use injective_staker::msg::ExecuteMsg;
+    use injective_staker::state::{PausableOperation, PauseStatus};
 
     // Pause Tests //
 
@@ -21,7 +25,7 @@ mod pausability {
 
         // verify the contract is not paused
         let staker_info = query_staker_info(&app, &staker_contract);
-        assert!(!staker_info.is_paused);
+        assert_eq!(staker_info.pause_status, PauseStatus::Unpaused);
 
         // pause contract
         let response = app.execute(
@@ -32,7 +36,7 @@ mod pausability {
 
         // verify the contract is paused
         let staker_info = query_staker_info(&app, &staker_contract);
-        assert!(staker_info.is_paused);
+        assert_eq!(staker_info.pause_status, PauseStatus::HardPaused);
     }
 
     #[test]
@@ -53,13 +57,13 @@ mod pausability {
         assert_event_with_attributes(
             &response.unwrap().events,
             "wasm-paused",
-            vec![],
+            vec![("old_status", "unpaused").into()],
             staker_contract,
         );
     }
 
     #[test]
-    fn test_pause_with_non_owner_fails() {
+    fn test_pause_with_non_pauser_fails() {
         let owner = "owner".into_bech32();
         let non_onwer = "non-owner".into_bech32();
         let treasury = "treasury".into_bech32();
@@ -74,7 +78,7 @@ mod pausability {
         assert!(response.is_err());
 
         // verify the error message
-        assert_error(response, "Only the owner can call this method");
+        assert_error(response, "Caller does not have the pauser role");
     }
 
     #[test]
@@ -87,7 +91,7 @@ mod pausability {
         // pause the contract
         pause(&mut app, &staker_contract, &owner);
         let staker_info = query_staker_info(&app, &staker_contract);
-        assert!(staker_info.is_paused);
+        assert_eq!(staker_info.pause_status, PauseStatus::HardPaused);
 
         // try to pause contract again
         let response = app.execute(
@@ -100,6 +104,160 @@ mod pausability {
         assert_error(response, "Contract is paused")
     }
 
+    // Soft pause tests //
+
+    #[test]
+    fn test_soft_pause() {
+        let owner = "owner".into_bech32();
+        let treasury = "treasury".into_bech32();
+
+        let (mut app, staker_contract, _) = instantiate_staker(owner.clone(), treasury);
+
+        // soft pause contract
+        let response = app
+            .execute(
+                owner,
+                wasm_execute_msg(&staker_contract, &ExecuteMsg::SoftPause).into(),
+            )
+            .unwrap();
+
+        // verify that the event was emitted
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-soft_paused",
+            vec![("old_status", "unpaused").into()],
+            staker_contract.clone(),
+        );
+
+        // verify the contract is soft paused
+        let staker_info = query_staker_info(&app, &staker_contract);
+        assert_eq!(staker_info.pause_status, PauseStatus::SoftPaused);
+    }
+
+    #[test]
+    fn test_soft_pause_with_non_pauser_fails() {
+        let owner = "owner".into_bech32();
+        let treasury = "treasury".into_bech32();
+
+        let (mut app, staker_contract, _) = instantiate_staker(owner, treasury);
+
+        // try to soft pause the contract
+        let response = app.execute(
+            "non-owner".into_bech32(),
+            wasm_execute_msg(&staker_contract, &ExecuteMsg::SoftPause).into(),
+        );
+
+        // verify the error message
+        assert_error(response, "Caller does not have the pauser role");
+    }
+
+    #[test]
+    fn test_soft_pause_with_soft_paused_contract_fails() {
+        let owner = "owner".into_bech32();
+        let treasury = "treasury".into_bech32();
+
+        let (mut app, staker_contract, _) = instantiate_staker(owner.clone(), treasury);
+        soft_pause(&mut app, &staker_contract, &owner);
+
+        // try to soft pause contract again
+        let response = app.execute(
+            owner,
+            wasm_execute_msg(&staker_contract, &ExecuteMsg::SoftPause).into(),
+        );
+
+        // verify the error message
+        assert_error(response, "Contract is already soft paused")
+    }
+
+    #[test]
+    fn test_pause_modes_can_be_switched() {
+        let owner = "owner".into_bech32();
+        let treasury = "treasury".into_bech32();
+
+        let (mut app, staker_contract, _) = instantiate_staker(owner.clone(), treasury);
+
+        // escalate a soft pause to a hard pause
+        soft_pause(&mut app, &staker_contract, &owner);
+        let response = app
+            .execute(
+                owner.clone(),
+                wasm_execute_msg(&staker_contract, &ExecuteMsg::Pause).into(),
+            )
+            .unwrap();
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-paused",
+            vec![("old_status", "soft_paused").into()],
+            staker_contract.clone(),
+        );
+        let staker_info = query_staker_info(&app, &staker_contract);
+        assert_eq!(staker_info.pause_status, PauseStatus::HardPaused);
+
+        // relax the hard pause to a soft pause
+        soft_pause(&mut app, &staker_contract, &owner);
+        let staker_info = query_staker_info(&app, &staker_contract);
+        assert_eq!(staker_info.pause_status, PauseStatus::SoftPaused);
+    }
+
+    #[test]
+    fn test_soft_pause_blocks_deposits_and_transfers() {
+        let owner: Addr = "owner".into_bech32();
+        let treasury = "treasury".into_bech32();
+
+        let (mut app, staker_contract, _) =
+            instantiate_staker_with_min_deposit(owner.clone(), treasury, 0);
+
+        let alice: Addr = "alice".into_bech32();
+        mint_inj(&mut app, &alice, 2_000_000);
+        whitelist_user(&mut app, &staker_contract, &owner, &alice);
+        stake(&mut app, &alice, &staker_contract, 1_000_000).unwrap();
+
+        soft_pause(&mut app, &staker_contract, &owner);
+
+        // staking is blocked
+        let response = stake(&mut app, &alice, &staker_contract, 1_000_000);
+        assert_error(response, "Contract is paused");
+
+        // transfers are blocked
+        let transfer = WasmMsg::Execute {
+            contract_addr: staker_contract.to_string(),
+            msg: to_json_binary(&ExecuteMsg::Transfer {
+                recipient: owner.to_string(),
+                amount: 1u128.into(),
+            })
+            .unwrap(),
+            funds: vec![],
+        };
+        let response = app.execute(alice.clone(), transfer.into());
+        assert_error(response, "Contract is paused");
+    }
+
+    #[test]
+    fn test_soft_pause_allows_unstake_and_claim() {
+        let owner: Addr = "owner".into_bech32();
+        let treasury = "treasury".into_bech32();
+
+        let (mut app, staker_contract, _) =
+            instantiate_staker_with_min_deposit(owner.clone(), treasury, 0);
+
+        let alice: Addr = "alice".into_bech32();
+        mint_inj(&mut app, &alice, 1_000_000);
+        whitelist_user(&mut app, &staker_contract, &owner, &alice);
+        stake(&mut app, &alice, &staker_contract, 1_000_000).unwrap();
+
+        soft_pause(&mut app, &staker_contract, &owner);
+
+        // alice can still unstake
+        unstake(&mut app, &alice, &staker_contract, 1_000_000).unwrap();
+        let claimable = get_claimable_assets(&app, &staker_contract, &alice);
+        assert_eq!(claimable[0].amount.u128(), 1_000_000);
+
+        // and claim once the unbonding period has passed
+        move_days_forward(&mut app, 22);
+        claim(&mut app, &alice, &staker_contract).unwrap();
+        assert_eq!(query_inj_balance(&app, &alice), 1_000_000);
+    }
+
     // Unpause tests //
 
     #[test]
@@ -112,7 +270,7 @@ mod pausability {
         // pause the contract
         pause(&mut app, &staker_contract, &owner);
         let staker_info = query_staker_info(&app, &staker_contract);
-        assert!(staker_info.is_paused);
+        assert_eq!(staker_info.pause_status, PauseStatus::HardPaused);
 
         // unpause the contract
         let response = app.execute(
@@ -123,7 +281,7 @@ mod pausability {
 
         // verify the contract is unpaused
         let staker_info = query_staker_info(&app, &staker_contract);
-        assert!(!staker_info.is_paused);
+        assert_eq!(staker_info.pause_status, PauseStatus::Unpaused);
     }
 
     #[test]
@@ -145,7 +303,7 @@ mod pausability {
         assert_event_with_attributes(
             &response.unwrap().events,
             "wasm-unpaused",
-            vec![],
+            vec![("old_status", "hard_paused").into()],
             staker_contract,
         );
     }
@@ -164,7 +322,7 @@ mod pausability {
         // try to unpause the contract
         let response = app.execute(
             non_onwer,
-            wasm_execute_msg(&staker_contract, &ExecuteMsg::Pause).into(),
+            wasm_execute_msg(&staker_contract, &ExecuteMsg::Unpause).into(),
         );
         assert!(response.is_err());
 
@@ -181,7 +339,7 @@ mod pausability {
 
         // verify the contract is not paused
         let staker_info = query_staker_info(&app, &staker_contract);
-        assert!(!staker_info.is_paused);
+        assert_eq!(staker_info.pause_status, PauseStatus::Unpaused);
 
         // try to unpause contract
         let response = app.execute(
@@ -193,4 +351,173 @@ mod pausability {
         // verify the error message
         assert_error(response, "Contract is not paused")
     }
+
+    #[test]
+    fn test_unpause_soft_paused_contract() {
+        let owner = "owner".into_bech32();
+        let treasury = "treasury".into_bech32();
+
+        let (mut app, staker_contract, _) = instantiate_staker(owner.clone(), treasury);
+        soft_pause(&mut app, &staker_contract, &owner);
+
+        // unpause the contract
+        let response = app
+            .execute(
+                owner,
+                wasm_execute_msg(&staker_contract, &ExecuteMsg::Unpause).into(),
+            )
+            .unwrap();
+
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-unpaused",
+            vec![("old_status", "soft_paused").into()],
+            staker_contract.clone(),
+        );
+        let staker_info = query_staker_info(&app, &staker_contract);
+        assert_eq!(staker_info.pause_status, PauseStatus::Unpaused);
+    }
+
+    // Operation pause tests //
+
+    #[test]
+    fn test_pause_operations() {
+        let owner: Addr = "owner".into_bech32();
+        let treasury = "treasury".into_bech32();
+
+        let (mut app, staker_contract, validator_addr) =
+            instantiate_staker_with_min_deposit(owner.clone(), treasury, 0);
+
+        let alice: Addr = "alice".into_bech32();
+        mint_inj(&mut app, &alice, 3_000_000);
+        whitelist_user(&mut app, &staker_contract, &owner, &alice);
+        stake(&mut app, &alice, &staker_contract, 1_000_000).unwrap();
+
+        // pause redelegations and stakes to specific validators
+        let operations = vec![
+            PausableOperation::Redelegate,
+            PausableOperation::StakeToSpecificValidator,
+        ];
+        let response =
+            pause_operations(&mut app, &staker_contract, &owner, operations.clone()).unwrap();
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-operations_paused",
+            vec![(
+                "operations",
+                r#"["redelegate","stake_to_specific_validator"]"#,
+            )
+                .into()],
+            staker_contract.clone(),
+        );
+
+        // the paused operations are blocked
+        let response = stake_to_specific_validator(
+            &mut app,
+            &alice,
+            &staker_contract,
+            1_000_000,
+            &validator_addr,
+        );
+        assert_error(response, "Operation stake_to_specific_validator is paused");
+        let response = redelegate(
+            &mut app,
+            &owner,
+            &staker_contract,
+            &validator_addr,
+            &validator_addr,
+            1,
+        );
+        assert_error(response, "Operation redelegate is paused");
+
+        // other operations are still allowed
+        stake(&mut app, &alice, &staker_contract, 1_000_000).unwrap();
+
+        // unpausing the operations allows them again
+        let response = unpause_operations(&mut app, &staker_contract, &owner, operations).unwrap();
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-operations_unpaused",
+            vec![(
+                "operations",
+                r#"["redelegate","stake_to_specific_validator"]"#,
+            )
+                .into()],
+            staker_contract.clone(),
+        );
+        stake_to_specific_validator(
+            &mut app,
+            &alice,
+            &staker_contract,
+            1_000_000,
+            &validator_addr,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_pause_matrix() {
+        let owner: Addr = "owner".into_bech32();
+        let treasury = "treasury".into_bech32();
+
+        let (mut app, staker_contract, _) = instantiate_staker(owner.clone(), treasury);
+
+        let matrix = get_pause_matrix(&app, &staker_contract);
+        assert_eq!(matrix.pause_status, PauseStatus::Unpaused);
+        assert_eq!(matrix.operations.len(), PausableOperation::ALL.len());
+        assert!(matrix.operations.iter().all(|o| !o.is_paused));
+
+        soft_pause(&mut app, &staker_contract, &owner);
+        pause_operations(
+            &mut app,
+            &staker_contract,
+            &owner,
+            vec![PausableOperation::DistributeRewards],
+        )
+        .unwrap();
+
+        let matrix = get_pause_matrix(&app, &staker_contract);
+        assert_eq!(matrix.pause_status, PauseStatus::SoftPaused);
+        for state in matrix.operations {
+            assert_eq!(
+                state.is_paused,
+                state.operation == PausableOperation::DistributeRewards
+            );
+        }
+    }
+
+    #[test]
+    fn test_pause_operations_with_non_owner_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let treasury = "treasury".into_bech32();
+
+        let (mut app, staker_contract, _) = instantiate_staker(owner.clone(), treasury);
+
+        let response = pause_operations(
+            &mut app,
+            &staker_contract,
+            &"non-owner".into_bech32(),
+            vec![PausableOperation::Claim],
+        );
+        assert_error(response, "Caller does not have the pauser role");
+
+        let response = unpause_operations(
+            &mut app,
+            &staker_contract,
+            &"non-owner".into_bech32(),
+            vec![PausableOperation::Claim],
+        );
+        assert_error(response, "Only the owner can call this method");
+    }
+
+    #[test]
+    fn test_pause_operations_with_no_operations_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let treasury = "treasury".into_bech32();
+
+        let (mut app, staker_contract, _) = instantiate_staker(owner.clone(), treasury);
+
+        let response = pause_operations(&mut app, &staker_contract, &owner, vec![]);
+        assert_error(response, "No operations provided");
+    }
 }
@@ -0,0 +1,206 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod recipient_allocations {
+
+    use cosmwasm_std::{Addr, Uint128};
+    use cw_multi_test::{App, IntoBech32};
+    use injective_staker::msg::{GetPendingRewardsForRecipientResponse, QueryMsg};
+
+    use crate::helpers::{
+        get_allocations_to_recipient, get_distribution_amounts,
+        instantiate_staker_with_min_deposit, move_days_forward, set_dist_fee,
+        set_up_test_allocation,
+    };
+
+    fn get_pending_rewards(
+        app: &App,
+        staker_addr: &Addr,
+        recipient: &Addr,
+    ) -> GetPendingRewardsForRecipientResponse {
+        get_pending_rewards_page(app, staker_addr, recipient, None, None)
+    }
+
+    fn get_pending_rewards_page(
+        app: &App,
+        staker_addr: &Addr,
+        recipient: &Addr,
+        start_after: Option<&Addr>,
+        limit: Option<u32>,
+    ) -> GetPendingRewardsForRecipientResponse {
+        app.wrap()
+            .query_wasm_smart(
+                staker_addr,
+                &QueryMsg::GetPendingRewardsForRecipient {
+                    recipient: recipient.to_string(),
+                    start_after: start_after.map(Addr::to_string),
+                    limit,
+                },
+            )
+            .unwrap()
+    }
+
+    /// Sets up allocations from two distributors to the same recipient.
+    fn setup() -> (App, Addr, Addr, Addr, Addr, Addr) {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) =
+            instantiate_staker_with_min_deposit(owner.clone(), "treasury".into_bech32(), 0);
+        set_dist_fee(&mut app, &staker_addr, &owner, 500);
+
+        let alice: Addr = "alice".into_bech32();
+        let bob: Addr = "bob".into_bech32();
+        let recipient: Addr = "recipient".into_bech32();
+        set_up_test_allocation(&mut app, &owner, &staker_addr, &alice, &recipient, 100_000);
+        set_up_test_allocation(&mut app, &owner, &staker_addr, &bob, &recipient, 50_000);
+
+        (app, staker_addr, owner, alice, bob, recipient)
+    }
+
+    #[test]
+    fn test_get_allocations_to_recipient() {
+        let (mut app, staker_addr, owner, alice, bob, recipient) = setup();
+
+        // an allocation to another recipient is not returned
+        set_up_test_allocation(
+            &mut app,
+            &owner,
+            &staker_addr,
+            &alice,
+            &"other".into_bech32(),
+            100_000,
+        );
+
+        let allocations = get_allocations_to_recipient(&app, &staker_addr, &recipient, None, None);
+        let mut allocators: Vec<Addr> = allocations
+            .iter()
+            .map(|allocation| allocation.allocator.clone())
+            .collect();
+        allocators.sort();
+        let mut expected = vec![alice, bob];
+        expected.sort();
+        assert_eq!(allocators, expected);
+        assert!(allocations
+            .iter()
+            .all(|allocation| allocation.recipient == recipient));
+    }
+
+    #[test]
+    fn test_get_allocations_to_recipient_is_paginated() {
+        let (app, staker_addr, _, _, _, recipient) = setup();
+
+        let all = get_allocations_to_recipient(&app, &staker_addr, &recipient, None, None);
+        assert_eq!(all.len(), 2);
+
+        let first_page =
+            get_allocations_to_recipient(&app, &staker_addr, &recipient, None, Some(1));
+        assert_eq!(first_page, all[..1]);
+
+        let second_page = get_allocations_to_recipient(
+            &app,
+            &staker_addr,
+            &recipient,
+            Some(&first_page[0].allocator),
+            Some(1),
+        );
+        assert_eq!(second_page, all[1..]);
+    }
+
+    #[test]
+    fn test_get_pending_rewards_for_recipient() {
+        let (mut app, staker_addr, _, alice, bob, recipient) = setup();
+
+        // no rewards are owed before rewards accrue
+        let response = get_pending_rewards(&app, &staker_addr, &recipient);
+        assert_eq!(response.truinj_amount, Uint128::zero());
+        assert!(response.pending_rewards.is_empty());
+
+        // accrue rewards
+        move_days_forward(&mut app, 30);
+
+        let (inj_from_alice, truinj_from_alice, _) =
+            get_distribution_amounts(&app, &staker_addr, &alice, Some(&recipient));
+        let (inj_from_bob, truinj_from_bob, _) =
+            get_distribution_amounts(&app, &staker_addr, &bob, Some(&recipient));
+        assert!(truinj_from_alice > 0 && truinj_from_bob > 0);
+
+        let response = get_pending_rewards(&app, &staker_addr, &recipient);
+        assert_eq!(
+            response.inj_amount,
+            Uint128::new(inj_from_alice + inj_from_bob)
+        );
+        assert_eq!(
+            response.truinj_amount,
+            Uint128::new(truinj_from_alice + truinj_from_bob)
+        );
+
+        let owed_by_alice = response
+            .pending_rewards
+            .iter()
+            .find(|rewards| rewards.distributor == alice)
+            .unwrap();
+        assert_eq!(owed_by_alice.inj_amount, Uint128::new(inj_from_alice));
+        assert_eq!(owed_by_alice.truinj_amount, Uint128::new(truinj_from_alice));
+    }
+
+    #[test]
+    fn test_get_pending_rewards_for_recipient_without_allocations() {
+        let (app, staker_addr, _, _, _, _) = setup();
+
+        let response = get_pending_rewards(&app, &staker_addr, &"nobody".into_bech32());
+        assert_eq!(response.inj_amount, Uint128::zero());
+        assert_eq!(response.truinj_amount, Uint128::zero());
+        assert!(response.pending_rewards.is_empty());
+    }
+
+    #[test]
+    fn test_get_pending_rewards_for_recipient_is_paginated() {
+        let (mut app, staker_addr, _, alice, bob, recipient) = setup();
+        move_days_forward(&mut app, 30);
+
+        let all = get_pending_rewards(&app, &staker_addr, &recipient);
+        assert_eq!(all.pending_rewards.len(), 2);
+
+        let first_page = get_pending_rewards_page(&app, &staker_addr, &recipient, None, Some(1));
+        assert_eq!(first_page.pending_rewards, all.pending_rewards[..1]);
+        assert_eq!(
+            first_page.truinj_amount,
+            first_page.pending_rewards[0].truinj_amount
+        );
+        let first_distributor = first_page.last_distributor.unwrap();
+        assert_eq!(first_distributor, alice.clone().min(bob.clone()));
+
+        let second_page = get_pending_rewards_page(
+            &app,
+            &staker_addr,
+            &recipient,
+            Some(&first_distributor),
+            Some(1),
+        );
+        assert_eq!(second_page.pending_rewards, all.pending_rewards[1..]);
+        assert_eq!(
+            first_page.truinj_amount + second_page.truinj_amount,
+            all.truinj_amount
+        );
+        assert_eq!(second_page.last_distributor, Some(alice.max(bob)));
+    }
+
+    #[test]
+    fn test_get_pending_rewards_for_recipient_pages_past_allocations_without_rewards() {
+        let (app, staker_addr, _, _, _, recipient) = setup();
+
+        // allocations without rewards are scanned, so the cursor still advances
+        let first_page = get_pending_rewards_page(&app, &staker_addr, &recipient, None, Some(1));
+        assert!(first_page.pending_rewards.is_empty());
+        assert!(first_page.last_distributor.is_some());
+
+        let second_page = get_pending_rewards_page(
+            &app,
+            &staker_addr,
+            &recipient,
+            first_page.last_distributor.as_ref(),
+            Some(1),
+        );
+        assert!(second_page.pending_rewards.is_empty());
+        assert_ne!(second_page.last_distributor, first_page.last_distributor);
+    }
+}
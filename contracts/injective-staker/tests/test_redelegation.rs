@@ -105,7 +105,7 @@ mod redelegation {
     }
 
     #[test]
-    fn test_redelegation_can_only_be_called_by_the_owner() {
+    fn test_redelegation_can_only_be_called_by_validator_managers() {
         let owner = "owner".into_bech32();
         let (mut app, staker_addr, _) = instantiate_staker(owner, "treasury".into_bech32());
 
@@ -125,7 +125,7 @@ mod redelegation {
 
         assert_eq!(
             error_source.to_string(),
-            "Only the owner can call this method"
+            "Caller does not have the validator_manager role"
         );
     }
 
@@ -0,0 +1,241 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod reserve {
+    use crate::helpers::{
+        assert_error, assert_event_with_attributes, claim, get_max_withdraw, get_reserve_status,
+        instantiate_staker_with_min_deposit_and_initial_stake, mint_inj, move_days_forward,
+        query_inj_balance, stake, top_up_reserve, unstake_when_rewards_accrue, wasm_execute_msg,
+        whitelist_user, withdraw_reserve,
+    };
+
+    use cosmwasm_std::{Addr, Decimal};
+    use cw_multi_test::{Executor, IntoBech32, StakingSudo, SudoMsg};
+    use injective_staker::msg::ExecuteMsg;
+
+    #[test]
+    fn test_reserve_is_funded_at_instantiation() {
+        let owner: Addr = "owner".into_bech32();
+        let (app, staker_addr, _) = instantiate_staker_with_min_deposit_and_initial_stake(
+            owner,
+            "treasury".into_bech32(),
+            0,
+            0,
+        );
+
+        let status = get_reserve_status(&app, &staker_addr);
+        assert_eq!(status.balance.u128(), 1);
+        assert_eq!(status.total_subsidies.u128(), 0);
+        assert_eq!(status.low_water_mark.u128(), 0);
+        assert!(!status.is_below_low_water_mark);
+    }
+
+    #[test]
+    fn test_top_up_reserve() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker_with_min_deposit_and_initial_stake(
+            owner.clone(),
+            "treasury".into_bech32(),
+            0,
+            0,
+        );
+
+        mint_inj(&mut app, &owner, 1_000);
+        let response = top_up_reserve(&mut app, &staker_addr, &owner, 1_000).unwrap();
+
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-reserve_topped_up",
+            vec![
+                ("amount", "1000").into(),
+                ("reserve_balance", "1001").into(),
+            ],
+            staker_addr.clone(),
+        );
+        assert_eq!(get_reserve_status(&app, &staker_addr).balance.u128(), 1_001);
+        assert_eq!(query_inj_balance(&app, &staker_addr), 1_001);
+    }
+
+    #[test]
+    fn test_top_up_reserve_by_non_owner_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker_with_min_deposit_and_initial_stake(
+            owner,
+            "treasury".into_bech32(),
+            0,
+            0,
+        );
+
+        let user: Addr = "user".into_bech32();
+        mint_inj(&mut app, &user, 1_000);
+        let response = top_up_reserve(&mut app, &staker_addr, &user, 1_000);
+        assert_error(response, "Only the owner can call this method");
+    }
+
+    #[test]
+    fn test_withdraw_reserve() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker_with_min_deposit_and_initial_stake(
+            owner.clone(),
+            "treasury".into_bech32(),
+            0,
+            0,
+        );
+
+        mint_inj(&mut app, &owner, 1_000);
+        top_up_reserve(&mut app, &staker_addr, &owner, 1_000).unwrap();
+        let owner_balance = query_inj_balance(&app, &owner);
+
+        let response = withdraw_reserve(&mut app, &staker_addr, &owner, 600).unwrap();
+
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-reserve_withdrawn",
+            vec![("amount", "600").into(), ("reserve_balance", "401").into()],
+            staker_addr.clone(),
+        );
+        assert_eq!(get_reserve_status(&app, &staker_addr).balance.u128(), 401);
+        assert_eq!(query_inj_balance(&app, &owner), owner_balance + 600);
+    }
+
+    #[test]
+    fn test_withdraw_reserve_above_balance_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker_with_min_deposit_and_initial_stake(
+            owner.clone(),
+            "treasury".into_bech32(),
+            0,
+            0,
+        );
+
+        let response = withdraw_reserve(&mut app, &staker_addr, &owner, 2);
+        assert_error(response, "Insufficient reserve balance");
+    }
+
+    #[test]
+    fn test_withdraw_reserve_by_non_owner_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker_with_min_deposit_and_initial_stake(
+            owner,
+            "treasury".into_bech32(),
+            0,
+            0,
+        );
+
+        let response = withdraw_reserve(&mut app, &staker_addr, &"user".into_bech32(), 1);
+        assert_error(response, "Only the owner can call this method");
+    }
+
+    #[test]
+    fn test_set_reserve_low_water_mark() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker_with_min_deposit_and_initial_stake(
+            owner.clone(),
+            "treasury".into_bech32(),
+            0,
+            0,
+        );
+
+        let msg = ExecuteMsg::SetReserveLowWaterMark {
+            low_water_mark: 100u128.into(),
+        };
+        let response = app
+            .execute(owner.clone(), wasm_execute_msg(&staker_addr, &msg).into())
+            .unwrap();
+
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-set_reserve_low_water_mark",
+            vec![
+                ("old_low_water_mark", "0").into(),
+                ("new_low_water_mark", "100").into(),
+            ],
+            staker_addr.clone(),
+        );
+
+        let status = get_reserve_status(&app, &staker_addr);
+        assert_eq!(status.low_water_mark.u128(), 100);
+        assert!(status.is_below_low_water_mark);
+
+        // topping up above the mark clears the alert
+        mint_inj(&mut app, &owner, 100);
+        top_up_reserve(&mut app, &staker_addr, &owner, 100).unwrap();
+        assert!(!get_reserve_status(&app, &staker_addr).is_below_low_water_mark);
+
+        // only the owner can set the low-water mark
+        let response = app.execute(
+            "user".into_bech32(),
+            wasm_execute_msg(&staker_addr, &msg).into(),
+        );
+        assert_error(response, "Only the owner can call this method");
+    }
+
+    #[test]
+    fn test_claim_shortfall_is_paid_from_reserve() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, validator_addr) =
+            instantiate_staker_with_min_deposit_and_initial_stake(
+                owner.clone(),
+                "treasury".into_bech32(),
+                0,
+                33_333,
+            );
+
+        mint_inj(&mut app, &owner, 1_000);
+        top_up_reserve(&mut app, &staker_addr, &owner, 1_000).unwrap();
+
+        let alice: Addr = "alice".into_bech32();
+        mint_inj(&mut app, &alice, 100_000);
+        whitelist_user(&mut app, &staker_addr, &owner, &alice);
+        stake(&mut app, &alice, &staker_addr, 100_000).unwrap();
+
+        move_days_forward(&mut app, 1);
+
+        // unstaking the max withdraw amount rounds up in the user's favour, without touching the reserve
+        let max_withdraw = get_max_withdraw(&app, &staker_addr, &alice);
+        unstake_when_rewards_accrue(
+            &mut app,
+            &alice,
+            &staker_addr,
+            max_withdraw,
+            &validator_addr,
+        )
+        .unwrap();
+        assert_eq!(
+            get_reserve_status(&app, &staker_addr)
+                .total_subsidies
+                .u128(),
+            0
+        );
+
+        // a small slash while unbonding leaves the contract short of the claimed INJ
+        app.sudo(SudoMsg::Staking(StakingSudo::Slash {
+            validator: validator_addr.to_string(),
+            percentage: Decimal::permille(5),
+        }))
+        .unwrap();
+        move_days_forward(&mut app, 21);
+
+        // the shortfall is paid from the reserve when claiming
+        let alice_balance = query_inj_balance(&app, &alice);
+        let response = claim(&mut app, &alice, &staker_addr).unwrap();
+        assert_eq!(
+            query_inj_balance(&app, &alice),
+            alice_balance + max_withdraw
+        );
+
+        let status = get_reserve_status(&app, &staker_addr);
+        assert!(status.total_subsidies.u128() > 0);
+        assert_eq!(status.balance.u128(), 1_001 - status.total_subsidies.u128());
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-rounding_subsidy_paid",
+            vec![
+                ("amount", status.total_subsidies.to_string()).into(),
+                ("reserve_balance", status.balance.to_string()).into(),
+                ("total_subsidies", status.total_subsidies.to_string()).into(),
+            ],
+            staker_addr.clone(),
+        );
+    }
+}
@@ -0,0 +1,257 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod roles {
+    use crate::helpers::{
+        add_validator, assert_error, assert_event_with_attributes, get_staker_info, grant_role,
+        instantiate_staker, pause, query_is_agent, set_fee, soft_pause, wasm_execute_msg,
+    };
+
+    use cosmwasm_std::Addr;
+    use cw_multi_test::{App, Executor, IntoBech32};
+    use injective_staker::msg::{ExecuteMsg, GetHasRoleResponse, GetRoleMembersResponse, QueryMsg};
+    use injective_staker::state::{PauseStatus, Role};
+
+    fn has_role(app: &App, staker_addr: &Addr, role: Role, account: &Addr) -> bool {
+        let response: GetHasRoleResponse = app
+            .wrap()
+            .query_wasm_smart(
+                staker_addr,
+                &QueryMsg::HasRole {
+                    role,
+                    account: account.to_string(),
+                },
+            )
+            .unwrap();
+        response.has_role
+    }
+
+    fn role_members(app: &App, staker_addr: &Addr, role: Role) -> Vec<Addr> {
+        let response: GetRoleMembersResponse = app
+            .wrap()
+            .query_wasm_smart(staker_addr, &QueryMsg::GetRoleMembers { role })
+            .unwrap();
+        response.members
+    }
+
+    #[test]
+    fn test_grant_and_revoke_role() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let pauser: Addr = "pauser".into_bech32();
+        assert!(!has_role(&app, &staker_addr, Role::Pauser, &pauser));
+
+        let grant_msg = ExecuteMsg::GrantRole {
+            role: Role::Pauser,
+            account: pauser.to_string(),
+        };
+        let response = app
+            .execute(
+                owner.clone(),
+                wasm_execute_msg(&staker_addr, &grant_msg).into(),
+            )
+            .unwrap();
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-role_granted",
+            vec![
+                ("role", "pauser").into(),
+                ("account", pauser.to_string()).into(),
+            ],
+            staker_addr.clone(),
+        );
+        assert!(has_role(&app, &staker_addr, Role::Pauser, &pauser));
+        assert_eq!(
+            role_members(&app, &staker_addr, Role::Pauser),
+            vec![pauser.clone()]
+        );
+
+        // granting the role twice fails
+        let response = app.execute(
+            owner.clone(),
+            wasm_execute_msg(&staker_addr, &grant_msg).into(),
+        );
+        assert_error(response, "Role pauser already granted");
+
+        let revoke_msg = ExecuteMsg::RevokeRole {
+            role: Role::Pauser,
+            account: pauser.to_string(),
+        };
+        let response = app
+            .execute(
+                owner.clone(),
+                wasm_execute_msg(&staker_addr, &revoke_msg).into(),
+            )
+            .unwrap();
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-role_revoked",
+            vec![
+                ("role", "pauser").into(),
+                ("account", pauser.to_string()).into(),
+            ],
+            staker_addr.clone(),
+        );
+        assert!(!has_role(&app, &staker_addr, Role::Pauser, &pauser));
+        assert!(role_members(&app, &staker_addr, Role::Pauser).is_empty());
+
+        // revoking a role that is not held fails
+        let response = app.execute(owner, wasm_execute_msg(&staker_addr, &revoke_msg).into());
+        assert_error(response, "Role pauser not granted");
+    }
+
+    #[test]
+    fn test_only_owner_can_grant_and_revoke_roles() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let pauser: Addr = "pauser".into_bech32();
+        grant_role(&mut app, &staker_addr, &owner, Role::Pauser, &pauser);
+
+        // role members cannot administer roles
+        let response = app.execute(
+            pauser.clone(),
+            wasm_execute_msg(
+                &staker_addr,
+                &ExecuteMsg::GrantRole {
+                    role: Role::Pauser,
+                    account: "anyone".into_bech32().to_string(),
+                },
+            )
+            .into(),
+        );
+        assert_error(response, "Only the owner can call this method");
+
+        let response = app.execute(
+            pauser.clone(),
+            wasm_execute_msg(
+                &staker_addr,
+                &ExecuteMsg::RevokeRole {
+                    role: Role::Pauser,
+                    account: pauser.to_string(),
+                },
+            )
+            .into(),
+        );
+        assert_error(response, "Only the owner can call this method");
+    }
+
+    #[test]
+    fn test_owner_holds_every_role() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        for role in [
+            Role::Pauser,
+            Role::ValidatorManager,
+            Role::FeeManager,
+            Role::KycAgent,
+            Role::Keeper,
+        ] {
+            assert!(has_role(&app, &staker_addr, role, &owner));
+        }
+
+        // the owner cannot be granted a role explicitly
+        let response = app.execute(
+            owner.clone(),
+            wasm_execute_msg(
+                &staker_addr,
+                &ExecuteMsg::GrantRole {
+                    role: Role::Keeper,
+                    account: owner.to_string(),
+                },
+            )
+            .into(),
+        );
+        assert_error(response, "Ower cannot be added");
+    }
+
+    #[test]
+    fn test_roles_grant_access_to_their_operations() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let pauser: Addr = "pauser".into_bech32();
+        let validator_manager: Addr = "validator-manager".into_bech32();
+        let fee_manager: Addr = "fee-manager".into_bech32();
+        grant_role(&mut app, &staker_addr, &owner, Role::Pauser, &pauser);
+        grant_role(
+            &mut app,
+            &staker_addr,
+            &owner,
+            Role::ValidatorManager,
+            &validator_manager,
+        );
+        grant_role(
+            &mut app,
+            &staker_addr,
+            &owner,
+            Role::FeeManager,
+            &fee_manager,
+        );
+
+        // the validator manager can add validators, but the fee manager cannot
+        let new_validator: Addr = "new-validator".into_bech32();
+        let response = app.execute(
+            fee_manager.clone(),
+            wasm_execute_msg(
+                &staker_addr,
+                &ExecuteMsg::AddValidator {
+                    validator: new_validator.to_string(),
+                },
+            )
+            .into(),
+        );
+        assert_error(response, "Caller does not have the validator_manager role");
+        add_validator(&mut app, validator_manager, &staker_addr, new_validator).unwrap();
+
+        // the fee manager can set the fee
+        set_fee(&mut app, &staker_addr, &fee_manager, 500);
+        assert_eq!(get_staker_info(&app, &staker_addr).fee, 500);
+
+        // the pauser can pause, but only the owner can unpause
+        pause(&mut app, &staker_addr, &pauser);
+        assert_eq!(
+            get_staker_info(&app, &staker_addr).pause_status,
+            PauseStatus::HardPaused
+        );
+        let response = app.execute(
+            pauser.clone(),
+            wasm_execute_msg(&staker_addr, &ExecuteMsg::Unpause).into(),
+        );
+        assert_error(response, "Only the owner can call this method");
+
+        // nor can the pauser relax the hard pause to a soft pause
+        let response = app.execute(
+            pauser,
+            wasm_execute_msg(&staker_addr, &ExecuteMsg::SoftPause).into(),
+        );
+        assert_error(response, "Only the owner can call this method");
+        assert_eq!(
+            get_staker_info(&app, &staker_addr).pause_status,
+            PauseStatus::HardPaused
+        );
+
+        soft_pause(&mut app, &staker_addr, &owner);
+        assert_eq!(
+            get_staker_info(&app, &staker_addr).pause_status,
+            PauseStatus::SoftPaused
+        );
+    }
+
+    #[test]
+    fn test_kyc_agent_role_is_the_agent_whitelist() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let kyc_agent: Addr = "kyc-agent".into_bech32();
+        grant_role(&mut app, &staker_addr, &owner, Role::KycAgent, &kyc_agent);
+
+        assert!(query_is_agent(&app, &kyc_agent, &staker_addr));
+        assert_eq!(
+            role_members(&app, &staker_addr, Role::KycAgent),
+            vec![kyc_agent]
+        );
+    }
+}
@@ -7,11 +7,12 @@ mod setters {
         self, add_validator, assert_error, assert_event_with_attributes, disable_validator,
     };
 
-    use cosmwasm_std::{to_json_binary, Addr, Attribute, Uint128, WasmMsg};
+    use cosmwasm_std::{to_json_binary, to_json_string, Addr, Attribute, Uint128, WasmMsg};
     use cw_multi_test::{Executor, IntoBech32};
     use helpers::instantiate_staker;
     use injective_staker::{
         msg::{ExecuteMsg, GetStakerInfoResponse, QueryMsg},
+        state::FeeRecipient,
         ONE_INJ,
     };
 
@@ -83,7 +84,7 @@ mod setters {
     }
 
     #[test]
-    fn test_set_fee_can_only_be_called_by_the_owner() {
+    fn test_set_fee_can_only_be_called_by_fee_managers() {
         let owner = "owner".into_bech32();
 
         let (mut app, staker_addr, _) = instantiate_staker(owner, "treasury".into_bech32());
@@ -99,7 +100,7 @@ mod setters {
 
         assert_eq!(
             error_source.to_string(),
-            "Only the owner can call this method"
+            "Caller does not have the fee_manager role"
         );
     }
 
@@ -176,7 +177,7 @@ mod setters {
     }
 
     #[test]
-    fn test_set_distribution_fee_can_only_be_called_by_the_owner() {
+    fn test_set_distribution_fee_can_only_be_called_by_fee_managers() {
         let owner = "owner".into_bech32();
 
         let (mut app, staker_addr, _) = instantiate_staker(owner, "treasury".into_bech32());
@@ -195,7 +196,199 @@ mod setters {
 
         assert_eq!(
             error_source.to_string(),
-            "Only the owner can call this method"
+            "Caller does not have the fee_manager role"
+        );
+    }
+
+    #[test]
+    fn test_set_deposit_fee() {
+        let owner = "owner".into_bech32();
+        let new_fee: u16 = 1000;
+
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let msg = WasmMsg::Execute {
+            contract_addr: staker_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::SetDepositFee {
+                new_deposit_fee: new_fee,
+            })
+            .unwrap(),
+            funds: vec![],
+        };
+
+        let response = app.execute(owner, msg.into());
+
+        assert!(response.is_ok());
+
+        let response_event = response.as_ref().unwrap().events.last().unwrap();
+
+        assert_eq!(response_event.ty, "wasm-set_deposit_fee");
+
+        assert_eq!(
+            response_event.attributes.get(1).unwrap(),
+            Attribute {
+                key: "old_deposit_fee".to_string(),
+                value: "0".to_string()
+            }
+        );
+
+        assert_eq!(
+            response_event.attributes.get(2).unwrap(),
+            Attribute {
+                key: "new_deposit_fee".to_string(),
+                value: "1000".to_string()
+            }
+        );
+
+        let staker_info: GetStakerInfoResponse = app
+            .wrap()
+            .query_wasm_smart(staker_addr, &QueryMsg::GetStakerInfo {})
+            .unwrap();
+
+        assert_eq!(staker_info.deposit_fee, new_fee);
+    }
+
+    #[test]
+    fn test_set_deposit_fee_entered_fee_above_precision() {
+        let owner = "owner".into_bech32();
+
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let msg = WasmMsg::Execute {
+            contract_addr: staker_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::SetDepositFee {
+                new_deposit_fee: 10000,
+            })
+            .unwrap(),
+            funds: vec![],
+        };
+
+        let error_response = app.execute(owner, msg.into()).unwrap_err();
+        let error_source = error_response.source().unwrap();
+
+        assert_eq!(
+            error_source.to_string(),
+            "Fee cannot be larger than fee precision"
+        );
+    }
+
+    #[test]
+    fn test_set_deposit_fee_can_only_be_called_by_fee_managers() {
+        let owner = "owner".into_bech32();
+
+        let (mut app, staker_addr, _) = instantiate_staker(owner, "treasury".into_bech32());
+
+        let msg = WasmMsg::Execute {
+            contract_addr: staker_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::SetDepositFee {
+                new_deposit_fee: 1000,
+            })
+            .unwrap(),
+            funds: vec![],
+        };
+
+        let error_response = app.execute("user".into_bech32(), msg.into()).unwrap_err();
+        let error_source = error_response.source().unwrap();
+
+        assert_eq!(
+            error_source.to_string(),
+            "Caller does not have the fee_manager role"
+        );
+    }
+
+    #[test]
+    fn test_set_withdrawal_fee() {
+        let owner = "owner".into_bech32();
+        let new_fee: u16 = 1000;
+
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let msg = WasmMsg::Execute {
+            contract_addr: staker_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::SetWithdrawalFee {
+                new_withdrawal_fee: new_fee,
+            })
+            .unwrap(),
+            funds: vec![],
+        };
+
+        let response = app.execute(owner, msg.into());
+
+        assert!(response.is_ok());
+
+        let response_event = response.as_ref().unwrap().events.last().unwrap();
+
+        assert_eq!(response_event.ty, "wasm-set_withdrawal_fee");
+
+        assert_eq!(
+            response_event.attributes.get(1).unwrap(),
+            Attribute {
+                key: "old_withdrawal_fee".to_string(),
+                value: "0".to_string()
+            }
+        );
+
+        assert_eq!(
+            response_event.attributes.get(2).unwrap(),
+            Attribute {
+                key: "new_withdrawal_fee".to_string(),
+                value: "1000".to_string()
+            }
+        );
+
+        let staker_info: GetStakerInfoResponse = app
+            .wrap()
+            .query_wasm_smart(staker_addr, &QueryMsg::GetStakerInfo {})
+            .unwrap();
+
+        assert_eq!(staker_info.withdrawal_fee, new_fee);
+    }
+
+    #[test]
+    fn test_set_withdrawal_fee_entered_fee_above_precision() {
+        let owner = "owner".into_bech32();
+
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let msg = WasmMsg::Execute {
+            contract_addr: staker_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::SetWithdrawalFee {
+                new_withdrawal_fee: 10000,
+            })
+            .unwrap(),
+            funds: vec![],
+        };
+
+        let error_response = app.execute(owner, msg.into()).unwrap_err();
+        let error_source = error_response.source().unwrap();
+
+        assert_eq!(
+            error_source.to_string(),
+            "Fee cannot be larger than fee precision"
+        );
+    }
+
+    #[test]
+    fn test_set_withdrawal_fee_can_only_be_called_by_fee_managers() {
+        let owner = "owner".into_bech32();
+
+        let (mut app, staker_addr, _) = instantiate_staker(owner, "treasury".into_bech32());
+
+        let msg = WasmMsg::Execute {
+            contract_addr: staker_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::SetWithdrawalFee {
+                new_withdrawal_fee: 1000,
+            })
+            .unwrap(),
+            funds: vec![],
+        };
+
+        let error_response = app.execute("user".into_bech32(), msg.into()).unwrap_err();
+        let error_source = error_response.source().unwrap();
+
+        assert_eq!(
+            error_source.to_string(),
+            "Caller does not have the fee_manager role"
         );
     }
 
@@ -247,7 +440,7 @@ mod setters {
     }
 
     #[test]
-    fn test_set_min_deposit_can_only_be_called_by_the_owner() {
+    fn test_set_min_deposit_can_only_be_called_by_fee_managers() {
         let owner = "owner".into_bech32();
 
         let (mut app, staker_addr, _) = instantiate_staker(owner, "treasury".into_bech32());
@@ -269,7 +462,7 @@ mod setters {
 
         assert_eq!(
             error_source.to_string(),
-            "Only the owner can call this method"
+            "Caller does not have the fee_manager role"
         );
     }
 
@@ -300,18 +493,21 @@ mod setters {
     }
 
     #[test]
-    fn test_set_treasury() {
+    fn test_set_fee_recipients() {
         let owner = "owner".into_bech32();
-        let old_treasury_addr = "treasury".into_bech32();
-        let new_treasury_addr = "new_treasury_addr".into_bech32();
+        let treasury: Addr = "treasury".into_bech32();
+        let first_recipient: Addr = "first_recipient".into_bech32();
+        let second_recipient: Addr = "second_recipient".into_bech32();
 
-        let (mut app, staker_addr, _) =
-            instantiate_staker(owner.clone(), old_treasury_addr.clone());
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), treasury.clone());
 
         let msg = WasmMsg::Execute {
             contract_addr: staker_addr.to_string(),
-            msg: to_json_binary(&ExecuteMsg::SetTreasury {
-                new_treasury_addr: new_treasury_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::SetFeeRecipients {
+                new_fee_recipients: vec![
+                    (first_recipient.to_string(), 7000),
+                    (second_recipient.to_string(), 3000),
+                ],
             })
             .unwrap(),
             funds: vec![],
@@ -320,12 +516,34 @@ mod setters {
         let response = app.execute(owner, msg.into());
         assert!(response.is_ok());
 
+        let new_fee_recipients = vec![
+            FeeRecipient {
+                address: first_recipient,
+                bps: 7000,
+            },
+            FeeRecipient {
+                address: second_recipient,
+                bps: 3000,
+            },
+        ];
+        let old_fee_recipients = vec![FeeRecipient {
+            address: treasury,
+            bps: 10_000,
+        }];
         assert_event_with_attributes(
             &response.unwrap().events,
-            "wasm-set_treasury",
+            "wasm-set_fee_recipients",
             vec![
-                ("new_treasury_addr", new_treasury_addr.clone()).into(),
-                ("old_treasury_addr", old_treasury_addr).into(),
+                (
+                    "new_fee_recipients",
+                    to_json_string(&new_fee_recipients).unwrap(),
+                )
+                    .into(),
+                (
+                    "old_fee_recipients",
+                    to_json_string(&old_fee_recipients).unwrap(),
+                )
+                    .into(),
             ],
             staker_addr.clone(),
         );
@@ -335,12 +553,12 @@ mod setters {
             .query_wasm_smart(staker_addr, &QueryMsg::GetStakerInfo {})
             .unwrap();
 
-        assert_eq!(staker_info.treasury, new_treasury_addr.to_string());
+        assert_eq!(staker_info.fee_recipients, new_fee_recipients);
     }
 
     #[test]
     #[should_panic]
-    fn test_set_treasury_to_wrong_address_fails() {
+    fn test_set_fee_recipients_to_wrong_address_fails() {
         let owner = "owner".into_bech32();
         let old_treasury_addr = "treasury".into_bech32();
         let new_treasury_addr = Addr::unchecked("new_treasury_addr");
@@ -349,8 +567,8 @@ mod setters {
 
         let msg = WasmMsg::Execute {
             contract_addr: staker_addr.to_string(),
-            msg: to_json_binary(&ExecuteMsg::SetTreasury {
-                new_treasury_addr: new_treasury_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::SetFeeRecipients {
+                new_fee_recipients: vec![(new_treasury_addr.to_string(), 10_000)],
             })
             .unwrap(),
             funds: vec![],
@@ -360,7 +578,51 @@ mod setters {
     }
 
     #[test]
-    fn test_set_treasury_not_called_by_owner_fails() {
+    fn test_set_fee_recipients_with_invalid_shares_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let first_recipient: Addr = "first_recipient".into_bech32();
+        let second_recipient: Addr = "second_recipient".into_bech32();
+
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let cases = vec![
+            (
+                vec![
+                    (first_recipient.to_string(), 5000),
+                    (second_recipient.to_string(), 4000),
+                ],
+                "Fee recipient shares must sum to fee precision",
+            ),
+            (vec![], "Fee recipient shares must sum to fee precision"),
+            (
+                vec![
+                    (first_recipient.to_string(), 10_000),
+                    (second_recipient.to_string(), 0),
+                ],
+                "Fee recipient share cannot be zero",
+            ),
+            (
+                vec![
+                    (first_recipient.to_string(), 5000),
+                    (first_recipient.to_string(), 5000),
+                ],
+                "Duplicate fee recipient",
+            ),
+        ];
+
+        for (new_fee_recipients, error) in cases {
+            let msg = WasmMsg::Execute {
+                contract_addr: staker_addr.to_string(),
+                msg: to_json_binary(&ExecuteMsg::SetFeeRecipients { new_fee_recipients }).unwrap(),
+                funds: vec![],
+            };
+            let response = app.execute(owner.clone(), msg.into());
+            assert_error(response, error);
+        }
+    }
+
+    #[test]
+    fn test_set_fee_recipients_not_called_by_fee_manager_fails() {
         let owner = "owner".into_bech32();
         let old_treasury_addr = "treasury".into_bech32();
         let new_treasury_addr = "new_treasury_addr".into_bech32();
@@ -369,15 +631,15 @@ mod setters {
 
         let msg = WasmMsg::Execute {
             contract_addr: staker_addr.to_string(),
-            msg: to_json_binary(&ExecuteMsg::SetTreasury {
-                new_treasury_addr: new_treasury_addr.to_string(),
+            msg: to_json_binary(&ExecuteMsg::SetFeeRecipients {
+                new_fee_recipients: vec![(new_treasury_addr.to_string(), 10_000)],
             })
             .unwrap(),
             funds: vec![],
         };
 
         let response = app.execute(new_treasury_addr, msg.into());
-        assert_error(response, "Only the owner can call this method");
+        assert_error(response, "Caller does not have the fee_manager role");
     }
 
     #[test]
@@ -489,7 +751,7 @@ mod setters {
     }
 
     #[test]
-    fn test_set_default_validator_not_called_by_owner_fails() {
+    fn test_set_default_validator_not_called_by_validator_manager_fails() {
         let owner = "owner".into_bech32();
         let new_default_validator_addr = "new_default_validator_addr".into_bech32();
 
@@ -505,6 +767,6 @@ mod setters {
         };
 
         let response = app.execute(new_default_validator_addr, msg.into());
-        assert_error(response, "Only the owner can call this method");
+        assert_error(response, "Caller does not have the validator_manager role");
     }
 }
@@ -5,9 +5,10 @@ mod stake {
 
     use crate::helpers::{
         self, add_validator, assert_error, assert_event_with_attributes, disable_validator,
-        get_share_price, get_share_price_num_denom, get_total_rewards, get_total_staked,
-        instantiate_staker, instantiate_staker_with_min_deposit_and_initial_stake,
-        move_days_forward, pause, query_truinj_balance, query_truinj_supply, set_fee,
+        fee_recipient_balances, get_share_price, get_share_price_num_denom, get_total_rewards,
+        get_total_staked, instantiate_staker,
+        instantiate_staker_with_min_deposit_and_initial_stake, move_days_forward, pause,
+        query_truinj_balance, query_truinj_supply, reconcile_delegations, set_deposit_fee, set_fee,
         stake_to_specific_validator, stake_when_rewards_accrued, whitelist_user,
     };
     use cosmwasm_std::{Addr, Attribute, Decimal, Uint128, Uint256};
@@ -60,8 +61,13 @@ mod stake {
                 ("amount", event_attribute_staked).into(),
                 ("contract_rewards", Uint128::zero()).into(),
                 ("user_shares_minted", event_attribute_staked).into(),
+                ("deposit_fee_shares", Uint128::zero()).into(),
                 ("treasury_shares_minted", treasury_shares_minted).into(),
-                ("treasury_balance", treasury_shares_minted).into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&"treasury".into_bech32(), 0)]),
+                )
+                    .into(),
                 ("total_staked", event_attribute_staked).into(),
                 ("total_supply", event_attribute_staked).into(),
                 ("share_price_num", Uint256::from(SHARE_PRICE_SCALING_FACTOR)).into(),
@@ -120,6 +126,7 @@ mod stake {
             percentage: Decimal::percent(50),
         }))
         .unwrap();
+        reconcile_delegations(&mut app, &contract_addr, &owner).unwrap();
 
         stake(&mut app, &anyone, &contract_addr, inj_to_mint).unwrap();
 
@@ -188,8 +195,13 @@ mod stake {
                 ("amount", event_attribute_staked).into(),
                 ("contract_rewards", Uint128::zero()).into(),
                 ("user_shares_minted", event_attribute_staked).into(),
+                ("deposit_fee_shares", Uint128::zero()).into(),
                 ("treasury_shares_minted", treasury_shares_minted).into(),
-                ("treasury_balance", treasury_shares_minted).into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&"treasury".into_bech32(), 0)]),
+                )
+                    .into(),
                 ("total_staked", event_attribute_staked).into(),
                 ("total_supply", event_attribute_staked).into(),
                 ("share_price_num", Uint256::from(SHARE_PRICE_SCALING_FACTOR)).into(),
@@ -293,12 +305,17 @@ mod stake {
                 ("amount", Uint128::from(stake_amount)).into(),
                 ("contract_rewards", Uint128::zero()).into(),
                 ("user_shares_minted", Uint128::from(user_shares_balance)).into(),
+                ("deposit_fee_shares", Uint128::zero()).into(),
                 (
                     "treasury_shares_minted",
                     Uint128::from(expected_treasury_fees),
                 )
                     .into(),
-                ("treasury_balance", Uint128::from(expected_treasury_fees)).into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&treasury, expected_treasury_fees)]),
+                )
+                    .into(),
                 ("total_staked", total_staked).into(),
                 ("total_supply", Uint128::from(shares_suply)).into(),
                 ("share_price_num", share_price_num).into(),
@@ -347,6 +364,78 @@ mod stake {
         assert_eq!(treasury_tryinj_balance, expected_treasury_fees);
     }
 
+    #[test]
+    fn test_stake_with_deposit_fee() {
+        let owner = "owner".into_bech32();
+        let treasury = "treasury".into_bech32();
+        let (mut app, contract_addr, validator_addr) =
+            instantiate_staker_with_min_deposit_and_initial_stake(
+                owner.clone(),
+                treasury.clone(),
+                ONE_INJ,
+                0,
+            );
+
+        // set 1% deposit fee
+        set_deposit_fee(&mut app, &contract_addr, &owner, 100);
+
+        // mint INJ tokens to the 'anyone' user
+        let anyone: Addr = "anyone".into_bech32();
+        let stake_amount = 10 * ONE_INJ;
+        mint_inj(&mut app, &anyone, stake_amount);
+
+        // whitelist user
+        whitelist_user(&mut app, &contract_addr, &owner, &anyone);
+
+        // user stakes
+        let stake_res = stake(&mut app, &anyone, &contract_addr, stake_amount).unwrap();
+
+        // verify the deposit fee was minted to the treasury and the rest to the user
+        let expected_deposit_fee = stake_amount / 100;
+        let user_balance = query_truinj_balance(&app, &anyone, &contract_addr);
+        assert_eq!(user_balance, stake_amount - expected_deposit_fee);
+        let treasury_balance = query_truinj_balance(&app, &treasury, &contract_addr);
+        assert_eq!(treasury_balance, expected_deposit_fee);
+
+        // verify the whole deposit was staked and the share price is unchanged
+        assert_eq!(get_total_staked(&app, &contract_addr).u128(), stake_amount);
+        assert_eq!(query_truinj_supply(&app, &contract_addr), stake_amount);
+        assert_eq!(
+            get_share_price(&app, &contract_addr),
+            SHARE_PRICE_SCALING_FACTOR
+        );
+
+        // verify the deposited event was emitted
+        assert_event_with_attributes(
+            &stake_res.events,
+            "wasm-deposited",
+            vec![
+                ("user", anyone.to_string()).into(),
+                ("validator_addr", validator_addr).into(),
+                ("amount", Uint128::from(stake_amount)).into(),
+                ("contract_rewards", Uint128::zero()).into(),
+                ("user_shares_minted", Uint128::from(user_balance)).into(),
+                ("deposit_fee_shares", Uint128::from(expected_deposit_fee)).into(),
+                (
+                    "treasury_shares_minted",
+                    Uint128::from(expected_deposit_fee),
+                )
+                    .into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&treasury, expected_deposit_fee)]),
+                )
+                    .into(),
+                ("total_staked", Uint128::from(stake_amount)).into(),
+                ("total_supply", Uint128::from(stake_amount)).into(),
+                ("share_price_num", Uint256::from(SHARE_PRICE_SCALING_FACTOR)).into(),
+                ("share_price_denom", Uint256::one()).into(),
+                ("user_balance", Uint128::from(user_balance)).into(),
+            ],
+            contract_addr,
+        );
+    }
+
     #[test]
     fn test_stake_with_non_whitelisted_user_fails() {
         let owner = "owner".into_bech32();
@@ -0,0 +1,196 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod tiers {
+    use cosmwasm_std::{Addr, Uint128};
+    use cw_multi_test::error::AnyError;
+    use cw_multi_test::{App, AppResponse, Executor, IntoBech32};
+    use injective_staker::{
+        msg::{ExecuteMsg, GetUserTierResponse, ListKycTiersResponse, QueryMsg},
+        state::KycTier,
+        ONE_INJ,
+    };
+
+    use crate::helpers::{
+        add_agent, allocate, assert_error, instantiate_staker, mint_inj, stake, wasm_execute_msg,
+        whitelist_user,
+    };
+
+    fn retail_tier() -> KycTier {
+        KycTier {
+            max_holding: Some(Uint128::new(15 * ONE_INJ)),
+            max_deposit: Some(Uint128::new(10 * ONE_INJ)),
+            can_allocate: false,
+            can_distribute: false,
+        }
+    }
+
+    fn set_kyc_tier(
+        app: &mut App,
+        staker_addr: &Addr,
+        sender: &Addr,
+        name: &str,
+        tier: KycTier,
+    ) -> Result<AppResponse, AnyError> {
+        app.execute(
+            sender.clone(),
+            wasm_execute_msg(
+                staker_addr,
+                &ExecuteMsg::SetKycTier {
+                    name: name.to_string(),
+                    tier,
+                },
+            )
+            .into(),
+        )
+    }
+
+    fn set_user_tier(
+        app: &mut App,
+        staker_addr: &Addr,
+        sender: &Addr,
+        user: &Addr,
+        tier: Option<&str>,
+    ) -> Result<AppResponse, AnyError> {
+        app.execute(
+            sender.clone(),
+            wasm_execute_msg(
+                staker_addr,
+                &ExecuteMsg::SetUserTier {
+                    user: user.to_string(),
+                    tier: tier.map(str::to_string),
+                },
+            )
+            .into(),
+        )
+    }
+
+    /// Instantiates the staker with a retail tier and a whitelisted retail user holding 30 INJ.
+    fn setup() -> (App, Addr, Addr, Addr) {
+        let owner: Addr = "owner".into_bech32();
+        let user: Addr = "user".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        set_kyc_tier(&mut app, &staker_addr, &owner, "retail", retail_tier()).unwrap();
+        whitelist_user(&mut app, &staker_addr, &owner, &user);
+        set_user_tier(&mut app, &staker_addr, &owner, &user, Some("retail")).unwrap();
+        mint_inj(&mut app, &user, 30 * ONE_INJ);
+
+        (app, staker_addr, owner, user)
+    }
+
+    #[test]
+    fn test_set_kyc_tier() {
+        let (app, staker_addr, _, user) = setup();
+
+        let response: ListKycTiersResponse = app
+            .wrap()
+            .query_wasm_smart(&staker_addr, &QueryMsg::ListKycTiers {})
+            .unwrap();
+        assert_eq!(response.tiers, vec![("retail".to_string(), retail_tier())]);
+
+        let response: GetUserTierResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staker_addr,
+                &QueryMsg::GetUserTier {
+                    user: user.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(response.tier, Some("retail".to_string()));
+    }
+
+    #[test]
+    fn test_set_kyc_tier_by_non_owner_fails() {
+        let (mut app, staker_addr, _, user) = setup();
+
+        let response = set_kyc_tier(&mut app, &staker_addr, &user, "retail", retail_tier());
+        assert_error(response, "Only the owner can call this method");
+    }
+
+    #[test]
+    fn test_set_user_tier_by_non_agent_fails() {
+        let (mut app, staker_addr, _, user) = setup();
+
+        let response = set_user_tier(&mut app, &staker_addr, &user, &user, None);
+        assert_error(response, "Caller is not an agent");
+    }
+
+    #[test]
+    fn test_set_user_tier_to_unknown_tier_fails() {
+        let (mut app, staker_addr, owner, user) = setup();
+        let agent: Addr = "agent".into_bech32();
+        add_agent(&mut app, &staker_addr, &owner, &agent);
+
+        let response = set_user_tier(&mut app, &staker_addr, &agent, &user, Some("unknown"));
+        assert_error(response, "KYC tier not found");
+    }
+
+    #[test]
+    fn test_stake_above_tier_deposit_limit_fails() {
+        let (mut app, staker_addr, _, user) = setup();
+
+        let response = stake(&mut app, &user, &staker_addr, 10 * ONE_INJ + 1);
+        assert_error(response, "Deposit exceeds the KYC tier limit");
+
+        stake(&mut app, &user, &staker_addr, 10 * ONE_INJ).unwrap();
+    }
+
+    #[test]
+    fn test_stake_above_tier_holding_limit_fails() {
+        let (mut app, staker_addr, _, user) = setup();
+
+        stake(&mut app, &user, &staker_addr, 10 * ONE_INJ).unwrap();
+
+        let response = stake(&mut app, &user, &staker_addr, 6 * ONE_INJ);
+        assert_error(response, "Holding exceeds the KYC tier limit");
+
+        stake(&mut app, &user, &staker_addr, 5 * ONE_INJ).unwrap();
+    }
+
+    #[test]
+    fn test_user_without_tier_is_not_limited() {
+        let (mut app, staker_addr, owner, user) = setup();
+
+        set_user_tier(&mut app, &staker_addr, &owner, &user, None).unwrap();
+        stake(&mut app, &user, &staker_addr, 30 * ONE_INJ).unwrap();
+    }
+
+    #[test]
+    fn test_allocate_not_allowed_for_tier_fails() {
+        let (mut app, staker_addr, owner, user) = setup();
+        let recipient: Addr = "recipient".into_bech32();
+        stake(&mut app, &user, &staker_addr, 10 * ONE_INJ).unwrap();
+
+        let response = allocate(&mut app, &user, &staker_addr, ONE_INJ, &recipient);
+        assert_error(response, "KYC tier does not allow allocations");
+
+        // the tier permissions can be updated by the owner
+        let tier = KycTier {
+            can_allocate: true,
+            ..retail_tier()
+        };
+        set_kyc_tier(&mut app, &staker_addr, &owner, "retail", tier).unwrap();
+        allocate(&mut app, &user, &staker_addr, ONE_INJ, &recipient).unwrap();
+    }
+
+    #[test]
+    fn test_distribute_not_allowed_for_tier_fails() {
+        let (mut app, staker_addr, _, user) = setup();
+        let recipient: Addr = "recipient".into_bech32();
+
+        let response = app.execute(
+            user,
+            wasm_execute_msg(
+                &staker_addr,
+                &ExecuteMsg::DistributeRewards {
+                    recipient: recipient.to_string(),
+                    in_inj: false,
+                },
+            )
+            .into(),
+        );
+        assert_error(response, "KYC tier does not allow distributions");
+    }
+}
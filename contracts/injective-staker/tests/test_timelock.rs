@@ -0,0 +1,401 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod timelock {
+    use cosmwasm_std::{Addr, Attribute};
+    use cw_multi_test::{Executor, IntoBech32};
+    use injective_staker::{
+        msg::{ExecuteMsg, GetPendingChangesResponse, GetTimelockConfigResponse, QueryMsg},
+        state::{ParameterChange, PendingParameterChange, TimelockedParameter},
+        MAX_TIMELOCK_DELAY,
+    };
+
+    use crate::helpers::{
+        assert_error, execute_parameter_change, get_pending_changes, get_staker_info,
+        instantiate_staker, move_days_forward, schedule_parameter_change, wasm_execute_msg,
+    };
+
+    const ONE_DAY: u64 = 24 * 60 * 60;
+
+    /// Places the fee and fee recipients behind a one day timelock.
+    fn enable_timelock(app: &mut cw_multi_test::App, staker_addr: &Addr, owner: &Addr) {
+        let now = app.block_info().time.seconds();
+        schedule_parameter_change(
+            app,
+            staker_addr,
+            owner,
+            ParameterChange::TimelockConfig {
+                min_delay: ONE_DAY,
+                timelocked_parameters: vec![
+                    TimelockedParameter::Fee,
+                    TimelockedParameter::FeeRecipients,
+                ],
+            },
+            now,
+        )
+        .unwrap();
+        let id = get_pending_changes(app, staker_addr).last().unwrap().id;
+        execute_parameter_change(app, staker_addr, owner, id).unwrap();
+    }
+
+    #[test]
+    fn test_schedule_parameter_change() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let eta = app.block_info().time.seconds() + ONE_DAY;
+        let response = schedule_parameter_change(
+            &mut app,
+            &staker_addr,
+            &owner,
+            ParameterChange::Fee { new_fee: 1000 },
+            eta,
+        )
+        .unwrap();
+
+        let event = response.events.last().unwrap();
+        assert_eq!(event.ty, "wasm-parameter_change_scheduled");
+        assert_eq!(
+            event.attributes[1..],
+            vec![
+                Attribute::new("id", "1"),
+                Attribute::new("change", r#"{"fee":{"new_fee":1000}}"#),
+                Attribute::new("eta", eta.to_string()),
+            ]
+        );
+
+        // verify the change is pending and the fee is unchanged
+        assert_eq!(
+            get_pending_changes(&app, &staker_addr),
+            vec![PendingParameterChange {
+                id: 1,
+                change: ParameterChange::Fee { new_fee: 1000 },
+                eta,
+            }]
+        );
+        assert_eq!(get_staker_info(&app, &staker_addr).fee, 0);
+    }
+
+    #[test]
+    fn test_execute_parameter_change_after_eta() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        enable_timelock(&mut app, &staker_addr, &owner);
+
+        let eta = app.block_info().time.seconds() + ONE_DAY;
+        schedule_parameter_change(
+            &mut app,
+            &staker_addr,
+            &owner,
+            ParameterChange::Fee { new_fee: 1000 },
+            eta,
+        )
+        .unwrap();
+        let id = get_pending_changes(&app, &staker_addr)[0].id;
+
+        move_days_forward(&mut app, 1);
+
+        // anyone can execute the change once the ETA has been reached
+        let anyone: Addr = "anyone".into_bech32();
+        let response = execute_parameter_change(&mut app, &staker_addr, &anyone, id).unwrap();
+
+        assert!(response.events.iter().any(|e| e.ty == "wasm-set_fee"));
+        let event = response.events.last().unwrap();
+        assert_eq!(event.ty, "wasm-parameter_change_executed");
+        assert_eq!(event.attributes[1], Attribute::new("id", id.to_string()));
+
+        assert_eq!(get_staker_info(&app, &staker_addr).fee, 1000);
+        assert!(get_pending_changes(&app, &staker_addr).is_empty());
+    }
+
+    #[test]
+    fn test_execute_parameter_change_before_eta_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let eta = app.block_info().time.seconds() + ONE_DAY;
+        schedule_parameter_change(
+            &mut app,
+            &staker_addr,
+            &owner,
+            ParameterChange::Fee { new_fee: 1000 },
+            eta,
+        )
+        .unwrap();
+
+        let response = execute_parameter_change(&mut app, &staker_addr, &owner, 1);
+        assert_error(response, "Parameter change ETA has not been reached");
+    }
+
+    #[test]
+    fn test_execute_unknown_parameter_change_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let response = execute_parameter_change(&mut app, &staker_addr, &owner, 1);
+        assert_error(response, "Parameter change not found");
+    }
+
+    #[test]
+    fn test_schedule_parameter_change_below_min_delay_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        enable_timelock(&mut app, &staker_addr, &owner);
+
+        let eta = app.block_info().time.seconds() + ONE_DAY - 1;
+        let response = schedule_parameter_change(
+            &mut app,
+            &staker_addr,
+            &owner,
+            ParameterChange::Fee { new_fee: 1000 },
+            eta,
+        );
+        assert_error(response, "ETA does not respect the timelock minimum delay");
+    }
+
+    #[test]
+    fn test_schedule_timelock_config_above_max_delay_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let eta = app.block_info().time.seconds();
+        let response = schedule_parameter_change(
+            &mut app,
+            &staker_addr,
+            &owner,
+            ParameterChange::TimelockConfig {
+                min_delay: MAX_TIMELOCK_DELAY + 1,
+                timelocked_parameters: vec![],
+            },
+            eta,
+        );
+        assert_error(
+            response,
+            &format!("Timelock minimum delay cannot exceed {MAX_TIMELOCK_DELAY} seconds"),
+        );
+    }
+
+    #[test]
+    fn test_get_pending_changes_is_paginated() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let eta = app.block_info().time.seconds() + ONE_DAY;
+        for new_fee in [100, 200, 300] {
+            schedule_parameter_change(
+                &mut app,
+                &staker_addr,
+                &owner,
+                ParameterChange::Fee { new_fee },
+                eta,
+            )
+            .unwrap();
+        }
+
+        let response: GetPendingChangesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staker_addr,
+                &QueryMsg::GetPendingChanges {
+                    start_after: Some(1),
+                    limit: Some(1),
+                },
+            )
+            .unwrap();
+        assert_eq!(response.changes.len(), 1);
+        assert_eq!(response.changes[0].id, 2);
+    }
+
+    #[test]
+    fn test_schedule_invalid_parameter_change_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let eta = app.block_info().time.seconds();
+        let response = schedule_parameter_change(
+            &mut app,
+            &staker_addr,
+            &owner,
+            ParameterChange::DistributionFee {
+                new_distribution_fee: 10000,
+            },
+            eta,
+        );
+        assert_error(response, "Fee cannot be larger than fee precision");
+
+        let response = schedule_parameter_change(
+            &mut app,
+            &staker_addr,
+            &owner,
+            ParameterChange::DefaultValidator {
+                new_default_validator_addr: "unknown".into_bech32().to_string(),
+            },
+            eta,
+        );
+        assert_error(response, "Validator does not exist");
+    }
+
+    #[test]
+    fn test_schedule_parameter_change_by_non_owner_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner, "treasury".into_bech32());
+
+        let eta = app.block_info().time.seconds() + ONE_DAY;
+        let response = schedule_parameter_change(
+            &mut app,
+            &staker_addr,
+            &"user".into_bech32(),
+            ParameterChange::Fee { new_fee: 1000 },
+            eta,
+        );
+        assert_error(response, "Only the owner can call this method");
+    }
+
+    #[test]
+    fn test_timelocked_parameter_cannot_be_set_directly() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        enable_timelock(&mut app, &staker_addr, &owner);
+
+        let response = app.execute(
+            owner.clone(),
+            wasm_execute_msg(&staker_addr, &ExecuteMsg::SetFee { new_fee: 1000 }).into(),
+        );
+        assert_error(response, "Parameter change must go through the timelock");
+
+        let response = app.execute(
+            owner.clone(),
+            wasm_execute_msg(
+                &staker_addr,
+                &ExecuteMsg::SetFeeRecipients {
+                    new_fee_recipients: vec![("new_treasury".into_bech32().to_string(), 10_000)],
+                },
+            )
+            .into(),
+        );
+        assert_error(response, "Parameter change must go through the timelock");
+
+        // parameters that are not timelocked can still be set directly
+        let response = app.execute(
+            owner,
+            wasm_execute_msg(
+                &staker_addr,
+                &ExecuteMsg::SetDistributionFee {
+                    new_distribution_fee: 1000,
+                },
+            )
+            .into(),
+        );
+        assert!(response.is_ok());
+    }
+
+    #[test]
+    fn test_cancel_parameter_change() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let eta = app.block_info().time.seconds() + ONE_DAY;
+        schedule_parameter_change(
+            &mut app,
+            &staker_addr,
+            &owner,
+            ParameterChange::Fee { new_fee: 1000 },
+            eta,
+        )
+        .unwrap();
+
+        let response = app
+            .execute(
+                owner,
+                wasm_execute_msg(&staker_addr, &ExecuteMsg::CancelParameterChange { id: 1 }).into(),
+            )
+            .unwrap();
+
+        let event = response.events.last().unwrap();
+        assert_eq!(event.ty, "wasm-parameter_change_cancelled");
+        assert_eq!(event.attributes[1], Attribute::new("id", "1"));
+        assert!(get_pending_changes(&app, &staker_addr).is_empty());
+    }
+
+    #[test]
+    fn test_cancel_parameter_change_after_eta_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let eta = app.block_info().time.seconds() + ONE_DAY;
+        schedule_parameter_change(
+            &mut app,
+            &staker_addr,
+            &owner,
+            ParameterChange::Fee { new_fee: 1000 },
+            eta,
+        )
+        .unwrap();
+
+        move_days_forward(&mut app, 1);
+
+        let response = app.execute(
+            owner,
+            wasm_execute_msg(&staker_addr, &ExecuteMsg::CancelParameterChange { id: 1 }).into(),
+        );
+        assert_error(response, "Parameter change ETA has already been reached");
+    }
+
+    #[test]
+    fn test_cancel_parameter_change_by_non_owner_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let eta = app.block_info().time.seconds() + ONE_DAY;
+        schedule_parameter_change(
+            &mut app,
+            &staker_addr,
+            &owner,
+            ParameterChange::Fee { new_fee: 1000 },
+            eta,
+        )
+        .unwrap();
+
+        let response = app.execute(
+            "user".into_bech32(),
+            wasm_execute_msg(&staker_addr, &ExecuteMsg::CancelParameterChange { id: 1 }).into(),
+        );
+        assert_error(response, "Only the owner can call this method");
+    }
+
+    #[test]
+    fn test_timelock_config_change_requires_timelock() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        enable_timelock(&mut app, &staker_addr, &owner);
+
+        let config: GetTimelockConfigResponse = app
+            .wrap()
+            .query_wasm_smart(&staker_addr, &QueryMsg::GetTimelockConfig {})
+            .unwrap();
+        assert_eq!(
+            config,
+            GetTimelockConfigResponse {
+                min_delay: ONE_DAY,
+                timelocked_parameters: vec![
+                    TimelockedParameter::Fee,
+                    TimelockedParameter::FeeRecipients
+                ],
+            }
+        );
+
+        // disabling the timelock is itself subject to the current delay
+        let now = app.block_info().time.seconds();
+        let response = schedule_parameter_change(
+            &mut app,
+            &staker_addr,
+            &owner,
+            ParameterChange::TimelockConfig {
+                min_delay: 0,
+                timelocked_parameters: vec![],
+            },
+            now,
+        );
+        assert_error(response, "ETA does not respect the timelock minimum delay");
+    }
+}
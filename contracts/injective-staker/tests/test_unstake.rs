@@ -13,10 +13,10 @@ mod unstake {
 
     use crate::helpers::{
         self, add_validator, assert_error, assert_event_with_attributes, clear_whitelist_status,
-        get_claimable_assets, get_max_withdraw, get_share_price, get_total_rewards,
-        get_total_staked, instantiate_staker_with_min_deposit,
+        fee_recipient_balances, get_claimable_assets, get_max_withdraw, get_share_price,
+        get_total_rewards, get_total_staked, instantiate_staker_with_min_deposit,
         instantiate_staker_with_min_deposit_and_initial_stake, move_days_forward, pause,
-        query_inj_balance, query_truinj_balance, query_truinj_supply, set_fee,
+        query_inj_balance, query_truinj_balance, query_truinj_supply, set_fee, set_withdrawal_fee,
         stake_to_specific_validator, stake_when_rewards_accrued, unstake,
         unstake_when_rewards_accrue, whitelist_user,
     };
@@ -108,11 +108,16 @@ mod unstake {
             vec![
                 ("user", alice.as_str()).into(),
                 ("amount", "40000").into(),
+                ("withdrawal_fee_inj", "0").into(),
                 ("validator_addr", validator_addr.to_string()).into(),
                 ("user_balance", user_shares.to_string()).into(),
                 ("user_shares_burned", shares_burned.to_string()).into(),
                 ("treasury_shares_minted", Uint128::zero()).into(),
-                ("treasury_balance", Uint128::zero()).into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&"treasury".into_bech32(), 0)]),
+                )
+                    .into(),
                 ("total_staked", total_staked.to_string()).into(),
                 ("total_supply", total_supply.to_string()).into(),
                 ("expires_at", expiration.get_value().to_string()).into(),
@@ -461,6 +466,7 @@ mod unstake {
             vec![
                 ("user", alice.as_str()).into(),
                 ("amount", "40000").into(),
+                ("withdrawal_fee_inj", "0").into(),
                 ("validator_addr", validator_addr.to_string()).into(),
                 ("user_balance", user_shares.to_string()).into(),
                 ("user_shares_burned", shares_burned.to_string()).into(),
@@ -469,7 +475,11 @@ mod unstake {
                     Uint128::from(expected_treasury_fees),
                 )
                     .into(),
-                ("treasury_balance", Uint128::from(expected_treasury_fees)).into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&treasury, expected_treasury_fees)]),
+                )
+                    .into(),
                 ("total_staked", total_staked.to_string()).into(),
                 ("total_supply", total_supply.to_string()).into(),
                 ("expires_at", expiration.get_value().to_string()).into(),
@@ -512,6 +522,91 @@ mod unstake {
         );
     }
 
+    #[test]
+    fn test_unstake_with_withdrawal_fee() {
+        let owner = "owner".into_bech32();
+        let treasury: Addr = "treasury".into_bech32();
+        let (mut app, staker_addr, validator_addr) =
+            instantiate_staker_with_min_deposit_and_initial_stake(
+                owner.clone(),
+                treasury.clone(),
+                0,
+                1_000_000,
+            );
+
+        // set 5% withdrawal fee
+        set_withdrawal_fee(&mut app, &staker_addr, &owner, 500);
+
+        // mint some INJ tokens to alice
+        let alice: Addr = "alice".into_bech32();
+        mint_inj(&mut app, &alice, 100_000);
+        whitelist_user(&mut app, &staker_addr, &owner, &alice);
+
+        // alice stakes
+        stake(&mut app, &alice, &staker_addr, 100_000).unwrap();
+
+        let pre_total_staked = get_total_staked(&app, &staker_addr).u128();
+        let pre_total_supply = query_truinj_supply(&app, &staker_addr);
+
+        // alice unstakes a partial amount
+        let unstake_amount = 40_000;
+        let unstake_res = unstake(&mut app, &alice, &staker_addr, unstake_amount).unwrap();
+
+        // verify the withdrawal fee was deducted from the claim
+        let expected_withdrawal_fee = 2_000;
+        let claimable = get_claimable_assets(&app, &staker_addr, &alice);
+        assert_eq!(
+            claimable[0].amount.u128(),
+            unstake_amount - expected_withdrawal_fee
+        );
+
+        // verify the withdrawal fee remains staked
+        let total_staked = get_total_staked(&app, &staker_addr).u128();
+        assert_eq!(
+            total_staked,
+            pre_total_staked - unstake_amount + expected_withdrawal_fee
+        );
+
+        // verify the treasury was minted the TruINJ equivalent of the withdrawal fee
+        let treasury_balance = query_truinj_balance(&app, &treasury, &staker_addr);
+        assert_eq!(treasury_balance, expected_withdrawal_fee);
+
+        let user_shares = query_truinj_balance(&app, &alice, &staker_addr);
+        assert_eq!(user_shares, 100_000 - unstake_amount);
+
+        let total_supply = query_truinj_supply(&app, &staker_addr);
+        assert_eq!(
+            total_supply,
+            pre_total_supply - unstake_amount + expected_withdrawal_fee
+        );
+
+        let expiration = UNBONDING_PERIOD.after(&app.block_info());
+
+        // verify the unstaked event was emitted
+        assert_event_with_attributes(
+            &unstake_res.events,
+            "wasm-unstaked",
+            vec![
+                ("user", alice.as_str()).into(),
+                ("amount", "40000").into(),
+                ("withdrawal_fee_inj", "2000").into(),
+                ("validator_addr", validator_addr.to_string()).into(),
+                ("user_balance", user_shares.to_string()).into(),
+                ("user_shares_burned", unstake_amount.to_string()).into(),
+                ("treasury_shares_minted", "2000").into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&treasury, 2000)]),
+                )
+                    .into(),
+                ("total_staked", total_staked.to_string()).into(),
+                ("total_supply", total_supply.to_string()).into(),
+                ("expires_at", expiration.get_value().to_string()).into(),
+            ],
+            staker_addr,
+        );
+    }
+
     #[test]
     pub fn test_unstake_when_user_not_whitelisted_fails() {
         let owner = "owner".into_bech32();
@@ -744,11 +839,16 @@ mod unstake {
             vec![
                 ("user", alice.as_str()).into(),
                 ("amount", "40000").into(),
+                ("withdrawal_fee_inj", "0").into(),
                 ("validator_addr", second_validator.to_string()).into(),
                 ("user_balance", user_shares.to_string()).into(),
                 ("user_shares_burned", shares_burned.to_string()).into(),
                 ("treasury_shares_minted", Uint128::zero()).into(),
-                ("treasury_balance", Uint128::zero()).into(),
+                (
+                    "fee_recipient_balances",
+                    fee_recipient_balances(vec![(&"treasury".into_bech32(), 0)]),
+                )
+                    .into(),
                 ("total_staked", total_staked.to_string()).into(),
                 ("total_supply", total_supply.to_string()).into(),
                 ("expires_at", expiration.get_value().to_string()).into(),
@@ -189,7 +189,7 @@ mod validators {
     }
 
     #[test]
-    fn test_add_validator_with_non_owner_fails() {
+    fn test_add_validator_with_non_validator_manager_fails() {
         let owner: Addr = "owner".into_bech32();
         let treasury: Addr = "treasury".into_bech32();
         let validator: Addr = "validator".into_bech32();
@@ -198,11 +198,16 @@ mod validators {
 
         let err = add_validator(&mut app, treasury, &staker_addr, validator).unwrap_err();
 
-        assert_eq!(ContractError::OnlyOwner {}, err.downcast().unwrap());
+        assert_eq!(
+            ContractError::MissingRole {
+                role: "validator_manager".to_string()
+            },
+            err.downcast().unwrap()
+        );
     }
 
     #[test]
-    fn test_enable_validator_with_non_owner_fails() {
+    fn test_enable_validator_with_non_validator_manager_fails() {
         let owner: Addr = "owner".into_bech32();
         let treasury: Addr = "treasury".into_bech32();
         let validator: Addr = "validator".into_bech32();
@@ -211,11 +216,16 @@ mod validators {
 
         let err = enable_validator(&mut app, treasury, &staker_addr, validator).unwrap_err();
 
-        assert_eq!(ContractError::OnlyOwner {}, err.downcast().unwrap());
+        assert_eq!(
+            ContractError::MissingRole {
+                role: "validator_manager".to_string()
+            },
+            err.downcast().unwrap()
+        );
     }
 
     #[test]
-    fn test_disable_validator_with_non_owner_fails() {
+    fn test_disable_validator_with_non_validator_manager_fails() {
         let owner: Addr = "owner".into_bech32();
         let treasury: Addr = "treasury".into_bech32();
         let validator: Addr = "validator".into_bech32();
@@ -224,7 +234,12 @@ mod validators {
 
         let err = disable_validator(&mut app, treasury, &staker_addr, validator).unwrap_err();
 
-        assert_eq!(ContractError::OnlyOwner {}, err.downcast().unwrap());
+        assert_eq!(
+            ContractError::MissingRole {
+                role: "validator_manager".to_string()
+            },
+            err.downcast().unwrap()
+        );
     }
 
     #[test]
@@ -5,7 +5,7 @@ mod view {
 
     use cosmwasm_std::{Addr, Decimal, Uint128, Uint256};
     use cw_multi_test::{IntoBech32, StakingSudo};
-    use helpers::{mint_inj, stake};
+    use helpers::{mint_inj, reconcile_delegations, stake};
     use injective_staker::constants::INJ;
     use injective_staker::msg::{
         GetDistributionAmountsResponse, GetMaxWithdrawResponse, GetTotalAssetsResponse,
@@ -343,6 +343,7 @@ mod view {
             percentage: Decimal::percent(50),
         }))
         .unwrap();
+        reconcile_delegations(&mut app, &staker_contract, &owner).unwrap();
 
         // verify share price decreases
         move_days_forward(&mut app, 1);
@@ -966,6 +967,7 @@ mod view {
             percentage: Decimal::percent(50),
         }))
         .unwrap();
+        reconcile_delegations(&mut app, &staker_addr, &owner).unwrap();
 
         // verify alice max withdraw is now half the pre-slash amount
         assert_eq!(
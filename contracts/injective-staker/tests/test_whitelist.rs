@@ -3,17 +3,25 @@ pub mod helpers;
 #[cfg(test)]
 mod whitelist {
 
-    use cosmwasm_std::Addr;
+    use cosmwasm_std::{from_json, Addr};
     use cw_multi_test::{Executor, IntoBech32};
     use std::vec;
 
     use helpers::instantiate_staker;
-    use injective_staker::{msg::ExecuteMsg, state::UserStatus};
+    use injective_staker::{
+        msg::{
+            BatchUpdateUserStatusResponse, CountUsersByStatusResponse, ExecuteMsg,
+            GetCurrentUserStatusResponse, GetExpiringWhitelistResponse,
+            GetUserStatusHistoryResponse, ListAgentsResponse, ListUsersByStatusResponse, QueryMsg,
+            SkippedUserStatusEntry, UserStatusEntry, WhitelistExpiryEntry,
+        },
+        state::{UserStatus, UserStatusChange},
+    };
 
     use crate::helpers::{
         self, add_agent, assert_error, assert_event_with_attributes, blacklist_user,
-        is_user_blacklisted, is_user_whitelisted, query_is_agent, query_user_status,
-        wasm_execute_msg, whitelist_user,
+        clear_whitelist_status, is_user_blacklisted, is_user_whitelisted, move_days_forward,
+        query_is_agent, query_user_status, wasm_execute_msg, whitelist_user,
     };
 
     #[test]
@@ -101,12 +109,16 @@ mod whitelist {
     }
 
     #[test]
-    fn test_add_agent_when_caller_not_agent_fails() {
+    fn test_add_agent_when_caller_not_owner_fails() {
         let owner: Addr = "owner".into_bech32();
         let user: Addr = "user".into_bech32();
         let new_agent: Addr = "agent".into_bech32();
 
-        let (mut app, staker_contract, _) = instantiate_staker(owner, "treasury".into_bech32());
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        // an existing agent cannot add agents either
+        add_agent(&mut app, &staker_contract, &owner, &user);
 
         // execute add agent
         let msg = wasm_execute_msg(
@@ -120,7 +132,7 @@ mod whitelist {
         assert!(response.is_err());
 
         // verify the error message
-        assert_error(response, "Caller is not an agent");
+        assert_error(response, "Only the owner can call this method");
     }
 
     #[test]
@@ -232,7 +244,7 @@ mod whitelist {
     }
 
     #[test]
-    fn test_remove_agent_when_caller_not_agent_fails() {
+    fn test_remove_agent_when_caller_not_owner_fails() {
         let owner: Addr = "owner".into_bech32();
         let user: Addr = "user".into_bech32();
         let agent: Addr = "agent".into_bech32();
@@ -240,8 +252,9 @@ mod whitelist {
         let (mut app, staker_contract, _) =
             instantiate_staker(owner.clone(), "treasury".into_bech32());
 
-        // add an agent
+        // add two agents, one of which tries to remove the other
         add_agent(&mut app, &staker_contract, &owner, &agent);
+        add_agent(&mut app, &staker_contract, &owner, &user);
 
         // execute remove agent
         let msg = wasm_execute_msg(
@@ -255,7 +268,7 @@ mod whitelist {
         assert!(response.is_err());
 
         // verify the error message
-        assert_error(response, "Caller is not an agent");
+        assert_error(response, "Only the owner can call this method");
     }
 
     #[test]
@@ -320,6 +333,8 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::AddUserToWhitelist {
                     user: user.to_string(),
+                    valid_until: None,
+                    reason: None,
                 },
             )
             .into(),
@@ -348,6 +363,8 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::AddUserToWhitelist {
                     user: user.to_string(),
+                    valid_until: None,
+                    reason: None,
                 },
             )
             .into(),
@@ -372,6 +389,8 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::AddUserToWhitelist {
                     user: user.to_string(),
+                    valid_until: None,
+                    reason: None,
                 },
             )
             .into(),
@@ -405,6 +424,8 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::AddUserToWhitelist {
                     user: user.to_string(),
+                    valid_until: None,
+                    reason: None,
                 },
             )
             .into(),
@@ -439,6 +460,8 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::AddUserToWhitelist {
                     user: user.to_string(),
+                    valid_until: None,
+                    reason: None,
                 },
             )
             .into(),
@@ -469,6 +492,7 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::AddUserToBlacklist {
                     user: user.to_string(),
+                    reason: None,
                 },
             )
             .into(),
@@ -498,6 +522,7 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::AddUserToBlacklist {
                     user: user.to_string(),
+                    reason: None,
                 },
             )
             .into(),
@@ -534,6 +559,7 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::AddUserToBlacklist {
                     user: user.to_string(),
+                    reason: None,
                 },
             )
             .into(),
@@ -561,6 +587,7 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::AddUserToBlacklist {
                     user: user.to_string(),
+                    reason: None,
                 },
             )
             .into(),
@@ -586,6 +613,7 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::AddUserToBlacklist {
                     user: user.to_string(),
+                    reason: None,
                 },
             )
             .into(),
@@ -620,6 +648,7 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::AddUserToBlacklist {
                     user: user.to_string(),
+                    reason: None,
                 },
             )
             .into(),
@@ -651,6 +680,7 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::ClearUserStatus {
                     user: user.to_string(),
+                    reason: None,
                 },
             )
             .into(),
@@ -683,6 +713,7 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::ClearUserStatus {
                     user: user.to_string(),
+                    reason: None,
                 },
             )
             .into(),
@@ -720,6 +751,7 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::ClearUserStatus {
                     user: user.to_string(),
+                    reason: None,
                 },
             )
             .into(),
@@ -745,6 +777,7 @@ mod whitelist {
                 &staker_contract,
                 &ExecuteMsg::ClearUserStatus {
                     user: user.to_string(),
+                    reason: None,
                 },
             )
             .into(),
@@ -754,4 +787,699 @@ mod whitelist {
         // verify the error message
         assert_error(response, "User status already cleared");
     }
+
+    // Batch Update User Status Tests //
+
+    fn entry(user: String, status: UserStatus) -> UserStatusEntry {
+        UserStatusEntry {
+            user,
+            status,
+            valid_until: None,
+            reason: None,
+        }
+    }
+
+    #[test]
+    fn test_batch_update_user_status() {
+        let owner: Addr = "owner".into_bech32();
+        let alice: Addr = "alice".into_bech32();
+        let bob: Addr = "bob".into_bech32();
+        let carol: Addr = "carol".into_bech32();
+
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+        whitelist_user(&mut app, &staker_contract, &owner, &carol);
+
+        let response = app
+            .execute(
+                owner,
+                wasm_execute_msg(
+                    &staker_contract,
+                    &ExecuteMsg::BatchUpdateUserStatus {
+                        entries: vec![
+                            entry(alice.to_string(), UserStatus::Whitelisted),
+                            entry(bob.to_string(), UserStatus::Blacklisted),
+                            entry(carol.to_string(), UserStatus::NoStatus),
+                        ],
+                        skip_invalid: false,
+                    },
+                )
+                .into(),
+            )
+            .unwrap();
+
+        // verify an event was emitted for every entry
+        let events: Vec<_> = response
+            .events
+            .iter()
+            .filter(|e| e.ty == "wasm-whitelisting_status_changed")
+            .collect();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[2].attributes[1].value, carol.to_string());
+        assert_eq!(events[2].attributes[2].value, "whitelisted");
+        assert_eq!(events[2].attributes[3].value, "no_status");
+
+        // verify the new statuses
+        assert!(is_user_whitelisted(&app, &alice, &staker_contract));
+        assert!(is_user_blacklisted(&app, &bob, &staker_contract));
+        assert_eq!(
+            query_user_status(&app, &carol, &staker_contract),
+            UserStatus::NoStatus
+        );
+    }
+
+    #[test]
+    fn test_batch_update_user_status_with_expiry_and_reason() {
+        let owner: Addr = "owner".into_bech32();
+        let alice: Addr = "alice".into_bech32();
+        let bob: Addr = "bob".into_bech32();
+
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let valid_until = app.block_info().time.seconds() + 365 * 24 * 60 * 60;
+        app.execute(
+            owner.clone(),
+            wasm_execute_msg(
+                &staker_contract,
+                &ExecuteMsg::BatchUpdateUserStatus {
+                    entries: vec![UserStatusEntry {
+                        user: alice.to_string(),
+                        status: UserStatus::Whitelisted,
+                        valid_until: Some(valid_until),
+                        reason: Some("KYC_RENEWED".to_string()),
+                    }],
+                    skip_invalid: false,
+                },
+            )
+            .into(),
+        )
+        .unwrap();
+
+        // verify the expiry and the reason are recorded
+        let response: GetCurrentUserStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staker_contract,
+                &QueryMsg::GetCurrentUserStatus {
+                    user: alice.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(response.valid_until, Some(valid_until));
+        let response: GetUserStatusHistoryResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staker_contract,
+                &QueryMsg::GetUserStatusHistory {
+                    user: alice.to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(response.history[0].valid_until, Some(valid_until));
+        assert_eq!(response.history[0].reason, Some("KYC_RENEWED".to_string()));
+
+        // an expiry can only be set when whitelisting
+        let response = app.execute(
+            owner,
+            wasm_execute_msg(
+                &staker_contract,
+                &ExecuteMsg::BatchUpdateUserStatus {
+                    entries: vec![UserStatusEntry {
+                        user: bob.to_string(),
+                        status: UserStatus::Blacklisted,
+                        valid_until: Some(valid_until),
+                        reason: None,
+                    }],
+                    skip_invalid: false,
+                },
+            )
+            .into(),
+        );
+        assert_error(
+            response,
+            "Whitelist expiry must be in the future and can only be set when whitelisting",
+        );
+    }
+
+    #[test]
+    fn test_batch_update_user_status_with_invalid_entry_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let alice: Addr = "alice".into_bech32();
+        let bob: Addr = "bob".into_bech32();
+
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+        whitelist_user(&mut app, &staker_contract, &owner, &bob);
+
+        let response = app.execute(
+            owner,
+            wasm_execute_msg(
+                &staker_contract,
+                &ExecuteMsg::BatchUpdateUserStatus {
+                    entries: vec![
+                        entry(alice.to_string(), UserStatus::Whitelisted),
+                        entry(bob.to_string(), UserStatus::Whitelisted),
+                    ],
+                    skip_invalid: false,
+                },
+            )
+            .into(),
+        );
+        assert_error(response, "User already whitelisted");
+
+        // verify that no entry was applied
+        assert!(!is_user_whitelisted(&app, &alice, &staker_contract));
+    }
+
+    #[test]
+    fn test_batch_update_user_status_skips_invalid_entries() {
+        let owner: Addr = "owner".into_bech32();
+        let alice: Addr = "alice".into_bech32();
+        let bob: Addr = "bob".into_bech32();
+
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+        whitelist_user(&mut app, &staker_contract, &owner, &bob);
+
+        // execute the contract directly to get its response data unwrapped
+        let response = app
+            .execute_contract(
+                owner,
+                staker_contract.clone(),
+                &ExecuteMsg::BatchUpdateUserStatus {
+                    entries: vec![
+                        entry("invalid".to_string(), UserStatus::Whitelisted),
+                        entry(alice.to_string(), UserStatus::Whitelisted),
+                        entry(bob.to_string(), UserStatus::Whitelisted),
+                    ],
+                    skip_invalid: true,
+                },
+                &[],
+            )
+            .unwrap();
+
+        // verify the skipped entries are returned
+        let data: BatchUpdateUserStatusResponse = from_json(response.data.unwrap()).unwrap();
+        assert_eq!(data.skipped.len(), 2);
+        assert_eq!(data.skipped[0].user, "invalid");
+        assert_eq!(
+            data.skipped[1],
+            SkippedUserStatusEntry {
+                user: bob.to_string(),
+                status: UserStatus::Whitelisted,
+                reason: "User already whitelisted".to_string(),
+            }
+        );
+
+        // verify the valid entry was applied
+        assert!(is_user_whitelisted(&app, &alice, &staker_contract));
+        assert_eq!(
+            response
+                .events
+                .iter()
+                .filter(|e| e.ty == "wasm-whitelisting_status_changed")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_batch_update_user_status_when_caller_is_not_agent_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let non_agent: Addr = "non-agent".into_bech32();
+        let user: Addr = "user".into_bech32();
+
+        let (mut app, staker_contract, _) = instantiate_staker(owner, "treasury".into_bech32());
+
+        let response = app.execute(
+            non_agent,
+            wasm_execute_msg(
+                &staker_contract,
+                &ExecuteMsg::BatchUpdateUserStatus {
+                    entries: vec![entry(user.to_string(), UserStatus::Whitelisted)],
+                    skip_invalid: true,
+                },
+            )
+            .into(),
+        );
+        assert_error(response, "Caller is not an agent");
+    }
+
+    // Whitelist Expiry Tests //
+
+    const ONE_DAY: u64 = 24 * 60 * 60;
+
+    fn whitelist_user_until(
+        app: &mut cw_multi_test::App,
+        contract: &Addr,
+        agent: &Addr,
+        user: &Addr,
+        valid_until: u64,
+    ) -> Result<cw_multi_test::AppResponse, cw_multi_test::error::AnyError> {
+        app.execute(
+            agent.clone(),
+            wasm_execute_msg(
+                contract,
+                &ExecuteMsg::AddUserToWhitelist {
+                    user: user.to_string(),
+                    valid_until: Some(valid_until),
+                    reason: None,
+                },
+            )
+            .into(),
+        )
+    }
+
+    #[test]
+    fn test_whitelisted_user_expires() {
+        let owner: Addr = "owner".into_bech32();
+        let user: Addr = "user".into_bech32();
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let valid_until = app.block_info().time.seconds() + ONE_DAY;
+        let response =
+            whitelist_user_until(&mut app, &staker_contract, &owner, &user, valid_until).unwrap();
+
+        // verify that the event includes the expiry
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-whitelisting_status_changed",
+            vec![
+                ("user", user.to_string()).into(),
+                ("old_status", "no_status").into(),
+                ("new_status", "whitelisted").into(),
+                ("valid_until", valid_until.to_string()).into(),
+            ],
+            staker_contract.clone(),
+        );
+        assert!(is_user_whitelisted(&app, &user, &staker_contract));
+
+        move_days_forward(&mut app, 1);
+
+        // verify that the user is no longer whitelisted but keeps their status
+        assert!(!is_user_whitelisted(&app, &user, &staker_contract));
+        let response: GetCurrentUserStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staker_contract,
+                &QueryMsg::GetCurrentUserStatus {
+                    user: user.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            response,
+            GetCurrentUserStatusResponse {
+                user_status: UserStatus::Whitelisted,
+                valid_until: Some(valid_until),
+            }
+        );
+    }
+
+    #[test]
+    fn test_renew_expired_whitelisted_user() {
+        let owner: Addr = "owner".into_bech32();
+        let user: Addr = "user".into_bech32();
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let valid_until = app.block_info().time.seconds() + ONE_DAY;
+        whitelist_user_until(&mut app, &staker_contract, &owner, &user, valid_until).unwrap();
+        move_days_forward(&mut app, 2);
+
+        // renew the user's approval
+        let valid_until = app.block_info().time.seconds() + 365 * ONE_DAY;
+        whitelist_user_until(&mut app, &staker_contract, &owner, &user, valid_until).unwrap();
+        assert!(is_user_whitelisted(&app, &user, &staker_contract));
+
+        // renewing with the same expiry fails
+        let response = whitelist_user_until(&mut app, &staker_contract, &owner, &user, valid_until);
+        assert_error(response, "User already whitelisted");
+    }
+
+    #[test]
+    fn test_whitelist_user_with_past_expiry_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let user: Addr = "user".into_bech32();
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let now = app.block_info().time.seconds();
+        let response = whitelist_user_until(&mut app, &staker_contract, &owner, &user, now);
+        assert_error(
+            response,
+            "Whitelist expiry must be in the future and can only be set when whitelisting",
+        );
+    }
+
+    #[test]
+    fn test_get_expiring_whitelist() {
+        let owner: Addr = "owner".into_bech32();
+        let alice: Addr = "alice".into_bech32();
+        let bob: Addr = "bob".into_bech32();
+        let carol: Addr = "carol".into_bech32();
+        let dave: Addr = "dave".into_bech32();
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let now = app.block_info().time.seconds();
+        whitelist_user_until(
+            &mut app,
+            &staker_contract,
+            &owner,
+            &alice,
+            now + 3 * ONE_DAY,
+        )
+        .unwrap();
+        whitelist_user_until(&mut app, &staker_contract, &owner, &bob, now + ONE_DAY).unwrap();
+        whitelist_user_until(
+            &mut app,
+            &staker_contract,
+            &owner,
+            &carol,
+            now + 30 * ONE_DAY,
+        )
+        .unwrap();
+        whitelist_user(&mut app, &staker_contract, &owner, &dave);
+
+        // blacklisted users are no longer reported
+        whitelist_user_until(&mut app, &staker_contract, &owner, &dave, now + ONE_DAY).unwrap();
+        blacklist_user(&mut app, &staker_contract, &owner, &dave);
+
+        let query = |start_after: Option<&Addr>, limit: Option<u32>| {
+            let response: GetExpiringWhitelistResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &staker_contract,
+                    &QueryMsg::GetExpiringWhitelist {
+                        before: now + 7 * ONE_DAY,
+                        start_after: start_after.map(|user| user.to_string()),
+                        limit,
+                    },
+                )
+                .unwrap();
+            response.entries
+        };
+
+        // verify the users expiring within a week are returned by expiry
+        assert_eq!(
+            query(None, None),
+            vec![
+                WhitelistExpiryEntry {
+                    user: bob.clone(),
+                    valid_until: now + ONE_DAY,
+                },
+                WhitelistExpiryEntry {
+                    user: alice.clone(),
+                    valid_until: now + 3 * ONE_DAY,
+                },
+            ]
+        );
+
+        // verify pagination
+        let first_page = query(None, Some(1));
+        assert_eq!(first_page.len(), 1);
+        assert_eq!(first_page[0].user, bob);
+        let second_page = query(Some(&bob), Some(1));
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page[0].user, alice);
+    }
+
+    // Enumeration Tests //
+
+    #[test]
+    fn test_list_agents() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let mut agents: Vec<Addr> = vec![
+            "agent1".into_bech32(),
+            "agent2".into_bech32(),
+            "agent3".into_bech32(),
+        ];
+        for agent in &agents {
+            add_agent(&mut app, &staker_contract, &owner, agent);
+        }
+        agents.sort();
+
+        let list_agents = |start_after: Option<&Addr>, limit: Option<u32>| {
+            let response: ListAgentsResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &staker_contract,
+                    &QueryMsg::ListAgents {
+                        start_after: start_after.map(|agent| agent.to_string()),
+                        limit,
+                    },
+                )
+                .unwrap();
+            response.agents
+        };
+
+        // verify that all agents but the owner are listed
+        assert_eq!(list_agents(None, None), agents);
+
+        // verify pagination
+        assert_eq!(list_agents(None, Some(2)), agents[..2]);
+        assert_eq!(list_agents(Some(&agents[1]), Some(2)), agents[2..]);
+    }
+
+    #[test]
+    fn test_list_and_count_users_by_status() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let mut whitelisted: Vec<Addr> = vec![
+            "user1".into_bech32(),
+            "user2".into_bech32(),
+            "user3".into_bech32(),
+        ];
+        let blacklisted: Addr = "user4".into_bech32();
+        for user in &whitelisted {
+            whitelist_user(&mut app, &staker_contract, &owner, user);
+        }
+        whitelist_user(&mut app, &staker_contract, &owner, &blacklisted);
+        blacklist_user(&mut app, &staker_contract, &owner, &blacklisted);
+        whitelisted.sort();
+
+        let list_users = |status: UserStatus, start_after: Option<&Addr>, limit: Option<u32>| {
+            let response: ListUsersByStatusResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &staker_contract,
+                    &QueryMsg::ListUsersByStatus {
+                        status,
+                        start_after: start_after.map(|user| user.to_string()),
+                        limit,
+                    },
+                )
+                .unwrap();
+            response.users
+        };
+        let count_users = |status: UserStatus| {
+            let response: CountUsersByStatusResponse = app
+                .wrap()
+                .query_wasm_smart(&staker_contract, &QueryMsg::CountUsersByStatus { status })
+                .unwrap();
+            response.count
+        };
+
+        // verify the users are listed by status
+        assert_eq!(list_users(UserStatus::Whitelisted, None, None), whitelisted);
+        assert_eq!(
+            list_users(UserStatus::Blacklisted, None, None),
+            vec![blacklisted.clone()]
+        );
+        assert!(list_users(UserStatus::NoStatus, None, None).is_empty());
+
+        // verify pagination
+        assert_eq!(
+            list_users(UserStatus::Whitelisted, Some(&whitelisted[0]), Some(1)),
+            whitelisted[1..2]
+        );
+
+        // verify the counts
+        assert_eq!(count_users(UserStatus::Whitelisted), 3);
+        assert_eq!(count_users(UserStatus::Blacklisted), 1);
+        assert_eq!(count_users(UserStatus::NoStatus), 0);
+    }
+
+    #[test]
+    fn test_count_users_by_status_after_clearing_status() {
+        let owner: Addr = "owner".into_bech32();
+        let user: Addr = "user".into_bech32();
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        whitelist_user(&mut app, &staker_contract, &owner, &user);
+        clear_whitelist_status(&mut app, &staker_contract, &owner, &user);
+
+        let response: CountUsersByStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staker_contract,
+                &QueryMsg::CountUsersByStatus {
+                    status: UserStatus::Whitelisted,
+                },
+            )
+            .unwrap();
+        assert_eq!(response.count, 0);
+    }
+
+    // Status History Tests //
+
+    #[test]
+    fn test_user_status_history() {
+        let owner: Addr = "owner".into_bech32();
+        let agent: Addr = "agent".into_bech32();
+        let user: Addr = "user".into_bech32();
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+        add_agent(&mut app, &staker_contract, &owner, &agent);
+
+        let whitelisted_at = app.block_info().time.seconds();
+        app.execute(
+            agent.clone(),
+            wasm_execute_msg(
+                &staker_contract,
+                &ExecuteMsg::AddUserToWhitelist {
+                    user: user.to_string(),
+                    valid_until: None,
+                    reason: Some("KYC_PASSED".to_string()),
+                },
+            )
+            .into(),
+        )
+        .unwrap();
+
+        move_days_forward(&mut app, 1);
+        let blacklisted_at = app.block_info().time.seconds();
+        let response = app
+            .execute(
+                owner.clone(),
+                wasm_execute_msg(
+                    &staker_contract,
+                    &ExecuteMsg::AddUserToBlacklist {
+                        user: user.to_string(),
+                        reason: Some("SANCTIONS_HIT".to_string()),
+                    },
+                )
+                .into(),
+            )
+            .unwrap();
+        assert_event_with_attributes(
+            &response.events,
+            "wasm-whitelisting_status_changed",
+            vec![
+                ("user", user.to_string()).into(),
+                ("old_status", "whitelisted").into(),
+                ("new_status", "blacklisted").into(),
+                ("reason", "SANCTIONS_HIT").into(),
+            ],
+            staker_contract.clone(),
+        );
+
+        clear_whitelist_status(&mut app, &staker_contract, &agent, &user);
+
+        let query_history = |start_after: Option<u64>, limit: Option<u32>| {
+            app.wrap()
+                .query_wasm_smart::<GetUserStatusHistoryResponse>(
+                    &staker_contract,
+                    &QueryMsg::GetUserStatusHistory {
+                        user: user.to_string(),
+                        start_after,
+                        limit,
+                    },
+                )
+                .unwrap()
+                .history
+        };
+
+        assert_eq!(
+            query_history(None, None),
+            vec![
+                UserStatusChange {
+                    id: 1,
+                    old_status: UserStatus::NoStatus,
+                    new_status: UserStatus::Whitelisted,
+                    valid_until: None,
+                    agent: agent.clone(),
+                    timestamp: whitelisted_at,
+                    reason: Some("KYC_PASSED".to_string()),
+                },
+                UserStatusChange {
+                    id: 2,
+                    old_status: UserStatus::Whitelisted,
+                    new_status: UserStatus::Blacklisted,
+                    valid_until: None,
+                    agent: owner.clone(),
+                    timestamp: blacklisted_at,
+                    reason: Some("SANCTIONS_HIT".to_string()),
+                },
+                UserStatusChange {
+                    id: 3,
+                    old_status: UserStatus::Blacklisted,
+                    new_status: UserStatus::NoStatus,
+                    valid_until: None,
+                    agent: agent.clone(),
+                    timestamp: blacklisted_at,
+                    reason: None,
+                },
+            ]
+        );
+
+        // verify pagination
+        let page = query_history(Some(1), Some(1));
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].id, 2);
+    }
+
+    #[test]
+    fn test_user_status_history_of_unknown_user_is_empty() {
+        let owner: Addr = "owner".into_bech32();
+        let (app, staker_contract, _) = instantiate_staker(owner, "treasury".into_bech32());
+
+        let response: GetUserStatusHistoryResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &staker_contract,
+                &QueryMsg::GetUserStatusHistory {
+                    user: "user".into_bech32().to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert!(response.history.is_empty());
+    }
+
+    #[test]
+    fn test_status_change_with_too_long_reason_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let user: Addr = "user".into_bech32();
+        let (mut app, staker_contract, _) =
+            instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let response = app.execute(
+            owner,
+            wasm_execute_msg(
+                &staker_contract,
+                &ExecuteMsg::AddUserToBlacklist {
+                    user: user.to_string(),
+                    reason: Some("X".repeat(65)),
+                },
+            )
+            .into(),
+        );
+        assert_error(
+            response,
+            "Status change reason must be at most 64 characters",
+        );
+    }
 }
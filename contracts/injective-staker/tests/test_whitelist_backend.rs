@@ -0,0 +1,237 @@
+pub mod helpers;
+
+#[cfg(test)]
+mod whitelist_backend {
+    use cosmwasm_std::{Addr, Empty};
+    use cw_multi_test::{App, Contract, ContractWrapper, Executor, IntoBech32};
+    use injective_staker::{
+        msg::{ExecuteMsg, QueryMsg},
+        state::{UserStatus, WhitelistBackend},
+    };
+
+    use crate::helpers::{
+        assert_error, instantiate_staker, is_user_blacklisted, is_user_whitelisted, mint_inj,
+        query_user_status, stake, wasm_execute_msg, whitelist_user,
+    };
+    use injective_staker::constants::ONE_INJ;
+
+    fn registry_contract() -> Box<dyn Contract<Empty>> {
+        Box::new(ContractWrapper::new(
+            kyc_registry::contract::execute,
+            kyc_registry::contract::instantiate,
+            kyc_registry::contract::query,
+        ))
+    }
+
+    /// Instantiates a KYC registry owned by the given owner and sets it as the staker's whitelist backend.
+    fn use_registry(app: &mut App, staker_addr: &Addr, owner: &Addr) -> Addr {
+        let code_id = app.store_code(registry_contract());
+        let registry_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &kyc_registry::msg::InstantiateMsg {
+                    owner: owner.to_string(),
+                },
+                &[],
+                "kyc-registry",
+                None,
+            )
+            .unwrap();
+
+        app.execute(
+            owner.clone(),
+            wasm_execute_msg(
+                staker_addr,
+                &ExecuteMsg::SetWhitelistBackend {
+                    backend: WhitelistBackend::External {
+                        registry: registry_addr.clone(),
+                    },
+                },
+            )
+            .into(),
+        )
+        .unwrap();
+
+        registry_addr
+    }
+
+    fn set_registry_status(
+        app: &mut App,
+        registry_addr: &Addr,
+        owner: &Addr,
+        user: &Addr,
+        status: kyc_registry::state::UserStatus,
+    ) {
+        app.execute_contract(
+            owner.clone(),
+            registry_addr.clone(),
+            &kyc_registry::msg::ExecuteMsg::SetUserStatus {
+                user: user.to_string(),
+                status,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_set_whitelist_backend() {
+        let owner: Addr = "owner".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        let backend: WhitelistBackend = app
+            .wrap()
+            .query_wasm_smart(&staker_addr, &QueryMsg::GetWhitelistBackend {})
+            .unwrap();
+        assert_eq!(backend, WhitelistBackend::Local);
+
+        let registry_addr = use_registry(&mut app, &staker_addr, &owner);
+
+        let backend: WhitelistBackend = app
+            .wrap()
+            .query_wasm_smart(&staker_addr, &QueryMsg::GetWhitelistBackend {})
+            .unwrap();
+        assert_eq!(
+            backend,
+            WhitelistBackend::External {
+                registry: registry_addr
+            }
+        );
+    }
+
+    #[test]
+    fn test_set_whitelist_backend_by_non_owner_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let anyone: Addr = "anyone".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner, "treasury".into_bech32());
+
+        let response = app.execute(
+            anyone,
+            wasm_execute_msg(
+                &staker_addr,
+                &ExecuteMsg::SetWhitelistBackend {
+                    backend: WhitelistBackend::Local,
+                },
+            )
+            .into(),
+        );
+        assert_error(response, "Only the owner can call this method");
+    }
+
+    #[test]
+    fn test_user_status_is_read_from_external_registry() {
+        let owner: Addr = "owner".into_bech32();
+        let alice: Addr = "alice".into_bech32();
+        let bob: Addr = "bob".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        // a user whitelisted locally is not whitelisted once the registry is used
+        whitelist_user(&mut app, &staker_addr, &owner, &bob);
+        let registry_addr = use_registry(&mut app, &staker_addr, &owner);
+        assert!(!is_user_whitelisted(&app, &bob, &staker_addr));
+
+        set_registry_status(
+            &mut app,
+            &registry_addr,
+            &owner,
+            &alice,
+            kyc_registry::state::UserStatus::Whitelisted,
+        );
+        set_registry_status(
+            &mut app,
+            &registry_addr,
+            &owner,
+            &bob,
+            kyc_registry::state::UserStatus::Blacklisted,
+        );
+
+        assert!(is_user_whitelisted(&app, &alice, &staker_addr));
+        assert!(is_user_blacklisted(&app, &bob, &staker_addr));
+        assert_eq!(
+            query_user_status(&app, &alice, &staker_addr),
+            UserStatus::Whitelisted
+        );
+
+        // verify that staking checks the registry
+        mint_inj(&mut app, &alice, 10 * ONE_INJ);
+        assert!(stake(&mut app, &alice, &staker_addr, 10 * ONE_INJ).is_ok());
+
+        mint_inj(&mut app, &bob, 10 * ONE_INJ);
+        let response = stake(&mut app, &bob, &staker_addr, 10 * ONE_INJ);
+        assert_error(response, "User not whitelisted");
+    }
+
+    #[test]
+    fn test_update_local_whitelist_with_external_backend_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let user: Addr = "user".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+        use_registry(&mut app, &staker_addr, &owner);
+
+        let response = app.execute(
+            owner,
+            wasm_execute_msg(
+                &staker_addr,
+                &ExecuteMsg::AddUserToWhitelist {
+                    user: user.to_string(),
+                    valid_until: None,
+                    reason: None,
+                },
+            )
+            .into(),
+        );
+        assert_error(
+            response,
+            "User statuses are managed by an external KYC registry",
+        );
+    }
+
+    #[test]
+    fn test_local_whitelist_queries_with_external_backend_fail() {
+        let owner: Addr = "owner".into_bech32();
+        let user: Addr = "user".into_bech32();
+        let (mut app, staker_addr, _) = instantiate_staker(owner.clone(), "treasury".into_bech32());
+
+        // the user was whitelisted locally with an expiry before switching to the registry
+        app.execute(
+            owner.clone(),
+            wasm_execute_msg(
+                &staker_addr,
+                &ExecuteMsg::AddUserToWhitelist {
+                    user: user.to_string(),
+                    valid_until: Some(app.block_info().time.seconds() + 1000),
+                    reason: None,
+                },
+            )
+            .into(),
+        )
+        .unwrap();
+        use_registry(&mut app, &staker_addr, &owner);
+
+        let queries = [
+            QueryMsg::ListUsersByStatus {
+                status: UserStatus::Whitelisted,
+                start_after: None,
+                limit: None,
+            },
+            QueryMsg::CountUsersByStatus {
+                status: UserStatus::Whitelisted,
+            },
+            QueryMsg::GetExpiringWhitelist {
+                before: u64::MAX,
+                start_after: None,
+                limit: None,
+            },
+        ];
+        for query in queries {
+            let err = app
+                .wrap()
+                .query_wasm_smart::<Empty>(&staker_addr, &query)
+                .unwrap_err();
+            assert!(err
+                .to_string()
+                .contains("User statuses are managed by an external KYC registry"));
+        }
+    }
+}
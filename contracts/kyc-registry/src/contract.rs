@@ -0,0 +1,128 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    ensure, to_json_binary, Addr, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response,
+    StdResult,
+};
+use cw2::set_contract_version;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, IsAgentResponse, QueryMsg, UserStatusResponse};
+use crate::state::{UserStatus, AGENTS, OWNER, USER_STATUSES};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:kyc-registry";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Entry point to instantiate the contract.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(&msg.owner)?;
+    OWNER.save(deps.storage, &owner)?;
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new().add_event(Event::new("instantiated").add_attribute("owner", owner)))
+}
+
+/// Entry point to execute messages.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::AddAgent { agent } => add_agent(deps, info.sender, &agent),
+        ExecuteMsg::RemoveAgent { agent } => remove_agent(deps, info.sender, &agent),
+        ExecuteMsg::SetUserStatus { user, status } => {
+            set_user_status(deps, info.sender, &user, status)
+        }
+    }
+}
+
+/// Entry point to query the contract.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::UserStatus { user } => {
+            let user = deps.api.addr_validate(&user)?;
+            to_json_binary(&UserStatusResponse {
+                status: USER_STATUSES
+                    .may_load(deps.storage, &user)?
+                    .unwrap_or(UserStatus::NoStatus),
+            })
+        }
+        QueryMsg::IsAgent { agent } => {
+            let agent = deps.api.addr_validate(&agent)?;
+            to_json_binary(&IsAgentResponse {
+                is_agent: is_agent(deps, &agent)?,
+            })
+        }
+    }
+}
+
+/// Adds a KYC agent. Only the owner can add agents.
+fn add_agent(deps: DepsMut, caller: Addr, agent: &str) -> Result<Response, ContractError> {
+    check_owner(deps.as_ref(), &caller)?;
+
+    let agent = deps.api.addr_validate(agent)?;
+    AGENTS.save(deps.storage, &agent, &())?;
+
+    Ok(Response::new().add_event(Event::new("agent_added").add_attribute("agent", agent)))
+}
+
+/// Removes a KYC agent. Only the owner can remove agents.
+fn remove_agent(deps: DepsMut, caller: Addr, agent: &str) -> Result<Response, ContractError> {
+    check_owner(deps.as_ref(), &caller)?;
+
+    let agent = deps.api.addr_validate(agent)?;
+    AGENTS.remove(deps.storage, &agent);
+
+    Ok(Response::new().add_event(Event::new("agent_removed").add_attribute("agent", agent)))
+}
+
+/// Sets the KYC status of a user. Only agents and the owner can set statuses.
+fn set_user_status(
+    deps: DepsMut,
+    caller: Addr,
+    user: &str,
+    status: UserStatus,
+) -> Result<Response, ContractError> {
+    ensure!(
+        is_agent(deps.as_ref(), &caller)?,
+        ContractError::CallerIsNotAgent
+    );
+
+    let user = deps.api.addr_validate(user)?;
+    match status {
+        UserStatus::NoStatus => USER_STATUSES.remove(deps.storage, &user),
+        _ => USER_STATUSES.save(deps.storage, &user, &status)?,
+    }
+
+    Ok(Response::new().add_event(
+        Event::new("user_status_changed")
+            .add_attribute("user", user)
+            .add_attribute("status", status.to_string()),
+    ))
+}
+
+/// Checks that the caller is the owner of the contract.
+fn check_owner(deps: Deps, caller: &Addr) -> Result<(), ContractError> {
+    ensure!(
+        *caller == OWNER.load(deps.storage)?,
+        ContractError::OnlyOwner
+    );
+    Ok(())
+}
+
+/// Checks whether an address is an agent or the owner.
+fn is_agent(deps: Deps, addr: &Addr) -> StdResult<bool> {
+    Ok(*addr == OWNER.load(deps.storage)? || AGENTS.has(deps.storage, addr))
+}
@@ -0,0 +1,14 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Only the owner can call this method")]
+    OnlyOwner,
+
+    #[error("Caller is not an agent")]
+    CallerIsNotAgent,
+}
@@ -0,0 +1,34 @@
+use crate::state::UserStatus;
+use cosmwasm_schema::{cw_serde, QueryResponses};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub owner: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    AddAgent { agent: String },
+    RemoveAgent { agent: String },
+    SetUserStatus { user: String, status: UserStatus },
+}
+
+/// Vaults using this registry as their whitelist backend call the UserStatus query.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(UserStatusResponse)]
+    UserStatus { user: String },
+    #[returns(IsAgentResponse)]
+    IsAgent { agent: String },
+}
+
+#[cw_serde]
+pub struct UserStatusResponse {
+    pub status: UserStatus,
+}
+
+#[cw_serde]
+pub struct IsAgentResponse {
+    pub is_agent: bool,
+}
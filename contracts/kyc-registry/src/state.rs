@@ -0,0 +1,27 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
+use std::fmt;
+
+pub const OWNER: Item<Addr> = Item::new("owner");
+pub const AGENTS: Map<&Addr, ()> = Map::new("agents");
+pub const USER_STATUSES: Map<&Addr, UserStatus> = Map::new("user_statuses");
+
+/// The KYC status of a user, serialized as expected by the injective-staker whitelist backend.
+#[cw_serde]
+pub enum UserStatus {
+    NoStatus,
+    Whitelisted,
+    Blacklisted,
+}
+
+impl fmt::Display for UserStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status_str = match self {
+            Self::NoStatus => "no_status",
+            Self::Whitelisted => "whitelisted",
+            Self::Blacklisted => "blacklisted",
+        };
+        write!(f, "{}", status_str)
+    }
+}
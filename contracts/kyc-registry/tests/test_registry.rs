@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod registry {
+    use cosmwasm_std::{Addr, Empty};
+    use cw_multi_test::error::AnyError;
+    use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor, IntoBech32};
+    use kyc_registry::contract::{execute, instantiate, query};
+    use kyc_registry::msg::{
+        ExecuteMsg, InstantiateMsg, IsAgentResponse, QueryMsg, UserStatusResponse,
+    };
+    use kyc_registry::state::UserStatus;
+
+    fn registry_contract() -> Box<dyn Contract<Empty>> {
+        Box::new(ContractWrapper::new(execute, instantiate, query))
+    }
+
+    fn instantiate_registry(owner: &Addr) -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(registry_contract());
+        let registry_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: owner.to_string(),
+                },
+                &[],
+                "kyc-registry",
+                None,
+            )
+            .unwrap();
+        (app, registry_addr)
+    }
+
+    fn set_user_status(
+        app: &mut App,
+        registry_addr: &Addr,
+        sender: &Addr,
+        user: &Addr,
+        status: UserStatus,
+    ) -> Result<AppResponse, AnyError> {
+        app.execute_contract(
+            sender.clone(),
+            registry_addr.clone(),
+            &ExecuteMsg::SetUserStatus {
+                user: user.to_string(),
+                status,
+            },
+            &[],
+        )
+    }
+
+    fn query_user_status(app: &App, registry_addr: &Addr, user: &Addr) -> UserStatus {
+        let response: UserStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                registry_addr,
+                &QueryMsg::UserStatus {
+                    user: user.to_string(),
+                },
+            )
+            .unwrap();
+        response.status
+    }
+
+    #[test]
+    fn test_set_user_status() {
+        let owner: Addr = "owner".into_bech32();
+        let user: Addr = "user".into_bech32();
+        let (mut app, registry_addr) = instantiate_registry(&owner);
+
+        assert_eq!(
+            query_user_status(&app, &registry_addr, &user),
+            UserStatus::NoStatus
+        );
+
+        let response = set_user_status(
+            &mut app,
+            &registry_addr,
+            &owner,
+            &user,
+            UserStatus::Whitelisted,
+        )
+        .unwrap();
+        let event = response.events.last().unwrap();
+        assert_eq!(event.ty, "wasm-user_status_changed");
+        assert_eq!(event.attributes[2].value, "whitelisted");
+        assert_eq!(
+            query_user_status(&app, &registry_addr, &user),
+            UserStatus::Whitelisted
+        );
+
+        set_user_status(
+            &mut app,
+            &registry_addr,
+            &owner,
+            &user,
+            UserStatus::NoStatus,
+        )
+        .unwrap();
+        assert_eq!(
+            query_user_status(&app, &registry_addr, &user),
+            UserStatus::NoStatus
+        );
+    }
+
+    #[test]
+    fn test_agent_can_set_user_status() {
+        let owner: Addr = "owner".into_bech32();
+        let agent: Addr = "agent".into_bech32();
+        let user: Addr = "user".into_bech32();
+        let (mut app, registry_addr) = instantiate_registry(&owner);
+
+        app.execute_contract(
+            owner.clone(),
+            registry_addr.clone(),
+            &ExecuteMsg::AddAgent {
+                agent: agent.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let response: IsAgentResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &registry_addr,
+                &QueryMsg::IsAgent {
+                    agent: agent.to_string(),
+                },
+            )
+            .unwrap();
+        assert!(response.is_agent);
+
+        set_user_status(
+            &mut app,
+            &registry_addr,
+            &agent,
+            &user,
+            UserStatus::Blacklisted,
+        )
+        .unwrap();
+        assert_eq!(
+            query_user_status(&app, &registry_addr, &user),
+            UserStatus::Blacklisted
+        );
+    }
+
+    #[test]
+    fn test_set_user_status_by_non_agent_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let user: Addr = "user".into_bech32();
+        let (mut app, registry_addr) = instantiate_registry(&owner);
+
+        let response = set_user_status(
+            &mut app,
+            &registry_addr,
+            &user,
+            &user,
+            UserStatus::Whitelisted,
+        );
+        assert_eq!(
+            response.unwrap_err().root_cause().to_string(),
+            "Caller is not an agent"
+        );
+    }
+
+    #[test]
+    fn test_add_agent_by_non_owner_fails() {
+        let owner: Addr = "owner".into_bech32();
+        let agent: Addr = "agent".into_bech32();
+        let (mut app, registry_addr) = instantiate_registry(&owner);
+
+        let response = app.execute_contract(
+            agent.clone(),
+            registry_addr,
+            &ExecuteMsg::AddAgent {
+                agent: agent.to_string(),
+            },
+            &[],
+        );
+        assert_eq!(
+            response.unwrap_err().root_cause().to_string(),
+            "Only the owner can call this method"
+        );
+    }
+}